@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use gradle_dependency_health_checker::bundle_analyzer::find_dependency_bundles;
+use gradle_dependency_health_checker::parser::{Dependency, DependencyLocation, DependencySourceType};
+use std::hint::black_box;
+use std::path::PathBuf;
+
+const MODULE_COUNT: usize = 500;
+const STACK_COUNT: usize = 30;
+const STACK_SIZE: usize = 8;
+
+/// Deterministic pseudo-random numbers so every run measures the same fixture
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((self.0 >> 33) as usize) % bound
+    }
+}
+
+fn location(module: usize, group: &str, artifact: String, line_number: usize) -> DependencyLocation {
+    DependencyLocation {
+        dependency: Dependency {
+            group: group.to_string(),
+            artifact,
+            version: Some("1.0.0".to_string()),
+            classifier: None,
+            extension: None,
+        },
+        file_path: PathBuf::from(format!("modules/module{}/build.gradle.kts", module)),
+        line_number,
+        configuration: "implementation".to_string(),
+        source_type: DependencySourceType::Direct,
+    }
+}
+
+/// 500 modules built from a shared core, a few library stacks (sometimes missing a member)
+/// and module-specific dependencies, like a large Android or multiplatform project
+fn synthetic_project() -> Vec<DependencyLocation> {
+    let mut rng = Lcg(42);
+    let mut dependencies = Vec::new();
+
+    for module in 0..MODULE_COUNT {
+        let mut artifacts: Vec<(String, String)> = Vec::new();
+        if rng.next(10) < 8 {
+            artifacts.extend((0..5).map(|i| ("org.example.core".to_string(), format!("core{}", i))));
+        }
+        for _ in 0..3 {
+            let stack = rng.next(STACK_COUNT);
+            let missing = if rng.next(4) == 0 { Some(rng.next(STACK_SIZE)) } else { None };
+            artifacts.extend(
+                (0..STACK_SIZE)
+                    .filter(|&member| Some(member) != missing)
+                    .map(|member| (format!("org.example.stack{}", stack), format!("lib{}", member))),
+            );
+        }
+        artifacts.extend((0..4).map(|i| (format!("org.example.module{}", module), format!("own{}", i))));
+
+        artifacts.sort();
+        artifacts.dedup();
+        dependencies.extend(
+            artifacts
+                .into_iter()
+                .enumerate()
+                .map(|(line, (group, artifact))| location(module, &group, artifact, line + 1)),
+        );
+    }
+
+    dependencies
+}
+
+fn bench_bundle_analysis(c: &mut Criterion) {
+    let dependencies = synthetic_project();
+    c.bench_function("find_dependency_bundles_500_modules", |b| {
+        b.iter(|| find_dependency_bundles(black_box(&dependencies), 2, 2, false))
+    });
+}
+
+criterion_group!(benches, bench_bundle_analysis);
+criterion_main!(benches);
\ No newline at end of file
@@ -5,12 +5,28 @@
  * See LICENSE file in the project root for full license information.
  */
 
+use crate::config::plugin_patterns::{CLASSPATH_PLUGIN_IDS, PLUGIN_MARKER_SUFFIX};
+use crate::config::Config;
 use crate::error::{Result};
-use crate::parser::{DependencyLocation, PluginLocation, find_gradle_files, parse_dependencies_from_file, parse_plugins_from_file, load_version_catalogs};
+use crate::findings::{FindingsByFile, Severity};
+use crate::parser::{DependencyLocation, DependencySourceType, PluginLocation, PluginSourceType, find_gradle_files, find_settings_files, parse_dependencies_from_file, parse_plugins_from_file_with_config, load_version_catalogs};
+use crate::android_sdk::{analyze_android_modules, load_android_modules, AndroidAnalysis};
+use crate::jvm_target::{analyze_jvm_targets, load_jvm_modules, JvmTargetAnalysis};
+use crate::repository_analyzer::{analyze_repositories, RepositoryAnalysis};
+use crate::compatibility::{check_compatibility, load_build_tool_versions, CompatibilityAnalysis};
+use crate::dynamic_versions::{analyze_dynamic_versions, find_dynamic_catalog_versions, DynamicVersionAnalysis};
 use crate::bundle_analyzer::{find_dependency_bundles, BundleAnalysis};
+use crate::catalog_analyzer::{analyze_catalogs, find_catalog_usage, find_hardcoded_catalog_dependencies, CatalogAnalysis, CatalogUsage};
+use crate::convention_plugins::{apply_convention_plugins, load_convention_plugins};
+use crate::composite::{analyze_composite, discover_builds, CompositeAnalysis};
+use crate::cross_project::apply_cross_project_configuration;
+use crate::plugin_applications::{analyze_plugin_applications, PluginApplicationFindingKind};
+use crate::similarity::{analyze_module_similarity, SimilarityAnalysis};
+use crate::version::{compare_versions, first_differing_part};
+use crate::version_catalog::VersionCatalog;
 use serde::Serialize;
 use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Serialize, Clone)]
 pub struct DuplicateAnalysis {
@@ -20,76 +36,210 @@ pub struct DuplicateAnalysis {
 
 #[derive(Debug, Serialize, Clone)]
 pub struct PluginAnalysis {
-    pub duplicate_plugins: HashMap<String, Vec<PluginLocation>>,
+    pub duplicate_plugins: HashMap<String, Vec<PluginLocation>>, // applied more than once by the same build file
+    pub version_conflicts: HashMap<String, PluginVersionConflict>,
+    pub classpath_version_mismatches: HashMap<String, Vec<PluginLocation>>,
+    pub application_findings: FindingsByFile<PluginApplicationFindingKind, String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PluginVersionConflict {
+    /// How far apart the versions are: a different major, minor or later version part
+    pub severity: Severity,
+    pub versions: Vec<String>, // lowest first
+    /// A buildscript classpath or `pluginManagement` entry pins the version every module resolves
+    pub classpath_pinned: bool,
+    pub locations: Vec<PluginLocation>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct CompleteAnalysis {
     pub duplicate_analysis: DuplicateAnalysis,
+    pub jvm_target_analysis: JvmTargetAnalysis,
     pub plugin_analysis: PluginAnalysis,
     pub bundle_analysis: BundleAnalysis,
+    pub composite_analysis: CompositeAnalysis,
+    pub catalog_analysis: CatalogAnalysis,
+    pub similarity_analysis: SimilarityAnalysis,
+    pub android_analysis: AndroidAnalysis,
+    pub repository_analysis: RepositoryAnalysis,
+    pub compatibility_analysis: CompatibilityAnalysis,
+    pub dynamic_version_analysis: DynamicVersionAnalysis,
+}
+
+/// Thresholds of the analyses that take one
+#[derive(Debug, Clone)]
+pub struct AnalysisParameters {
+    pub min_bundle_size: usize,
+    pub min_bundle_modules: usize,
+    /// Treat api and implementation declarations of a dependency as one bundle member
+    pub merge_bundle_configurations: bool,
+    /// Module similarity is only computed when set
+    pub min_module_similarity: Option<f64>,
+}
+
+impl Default for AnalysisParameters {
+    fn default() -> Self {
+        AnalysisParameters {
+            min_bundle_size: 2,
+            min_bundle_modules: 2,
+            merge_bundle_configurations: false,
+            min_module_similarity: None,
+        }
+    }
 }
 
+/// Analysis with the default configuration
+#[allow(dead_code)]
 pub fn perform_complete_analysis(
     root_path: &Path,
     min_bundle_size: usize,
     min_bundle_modules: usize,
 ) -> Result<CompleteAnalysis> {
-    let all_dependencies = load_all_dependencies(root_path)?;
-    let all_plugins = load_all_plugins(root_path)?;
+    let parameters = AnalysisParameters {
+        min_bundle_size,
+        min_bundle_modules,
+        ..AnalysisParameters::default()
+    };
+    let config = Config::default();
+    perform_complete_analysis_with_config(root_path, &parameters, &config)
+}
+
+pub fn perform_complete_analysis_with_config(
+    root_path: &Path,
+    parameters: &AnalysisParameters,
+    config: &Config,
+) -> Result<CompleteAnalysis> {
+    let builds = discover_builds(root_path)?;
+    let mut all_dependencies = Vec::new();
+    let mut all_plugins = Vec::new();
+    let mut conventions = HashMap::new();
+    let mut catalog_analysis = CatalogAnalysis::default();
+    let mut android_modules = Vec::new();
+    let mut jvm_modules = Vec::new();
+    let mut repository_analysis = RepositoryAnalysis::default();
+    let mut build_tool_versions = Vec::new();
+    let mut dynamic_catalog_versions = HashMap::new();
+    let mut catalogs = HashMap::new();
+    let mut catalog_usage: HashMap<PathBuf, CatalogUsage> = HashMap::new();
+    
+    // Each build of a composite resolves against its own version catalogs
+    for build in &builds {
+        let version_catalogs = load_version_catalogs(&build.root_path)?;
+        let dependencies = load_all_dependencies(&build.root_path, &version_catalogs)?;
+        for (key, locations) in find_hardcoded_catalog_dependencies(&dependencies, &version_catalogs) {
+            catalog_analysis.hardcoded_dependencies.entry(key).or_default().extend(locations);
+        }
+        // Builds sharing a catalog file report its entries once
+        for (catalog_path, findings) in find_dynamic_catalog_versions(&version_catalogs, &dependencies, &config.dynamic_version_severities)? {
+            dynamic_catalog_versions.entry(catalog_path).or_insert(findings);
+        }
+        all_dependencies.extend(dependencies);
+        let plugins = load_all_plugins(&build.root_path, &version_catalogs, config)?;
+        build_tool_versions.push(load_build_tool_versions(&build.root_path, &plugins)?);
+        all_plugins.extend(plugins);
+        conventions.extend(load_convention_plugins(&build.root_path, &version_catalogs, config)?);
+        for (catalog_path, usage) in find_catalog_usage(&build.root_path, &version_catalogs)? {
+            catalog_usage.entry(catalog_path).or_default().merge(&usage);
+        }
+        android_modules.extend(load_android_modules(&build.root_path, &version_catalogs, config)?);
+        jvm_modules.extend(load_jvm_modules(&build.root_path, &version_catalogs, config)?);
+        let build_repositories = analyze_repositories(&build.root_path)?;
+        repository_analysis.repositories.extend(build_repositories.repositories);
+        repository_analysis.findings.extend(build_repositories.findings);
+        // Builds sharing a catalog file lint it once, against the references of all of them
+        for (catalog_path, catalog) in version_catalogs {
+            catalogs.entry(catalog_path).or_insert(catalog);
+        }
+    }
+    catalog_analysis.findings = analyze_catalogs(&catalogs, &catalog_usage)?;
+    
+    // Attribute subprojects/allprojects and convention plugin contents to the modules they configure
+    apply_cross_project_configuration(&builds, &mut all_dependencies, &mut all_plugins)?;
+    apply_convention_plugins(&conventions, &mut all_dependencies, &mut all_plugins);
     
     // Perform duplicate analysis
     let duplicate_analysis = analyze_duplicates(&all_dependencies);
     
+    // Perform JVM toolchain and target analysis
+    let jvm_target_analysis = analyze_jvm_targets(jvm_modules);
+    
     // Perform plugin analysis
     let plugin_analysis = analyze_plugins(&all_plugins);
     
     // Perform bundle analysis
-    let bundle_analysis = find_dependency_bundles(&all_dependencies, min_bundle_size, min_bundle_modules);
+    let bundle_analysis = find_dependency_bundles(&all_dependencies, parameters.min_bundle_size, parameters.min_bundle_modules, parameters.merge_bundle_configurations);
+    
+    // Perform cross-build analysis
+    let composite_analysis = analyze_composite(builds, &all_dependencies);
+    
+    // Similarity clustering compares every pair of modules, so it only runs when asked for
+    let similarity_analysis = parameters.min_module_similarity
+        .map(|min_similarity| analyze_module_similarity(&all_dependencies, min_similarity))
+        .unwrap_or_default();
+    
+    // Perform Android SDK consistency analysis
+    let android_analysis = analyze_android_modules(android_modules);
+    
+    // Check Gradle, AGP, Kotlin and JDK versions against the compatibility matrix
+    let compatibility_analysis = check_compatibility(build_tool_versions, &config.compatibility_matrix);
+    
+    // Report dynamic, ranged and snapshot versions
+    let dynamic_version_analysis = analyze_dynamic_versions(&all_dependencies, &all_plugins, dynamic_catalog_versions, &config.dynamic_version_severities);
     
     Ok(CompleteAnalysis {
         duplicate_analysis,
+        jvm_target_analysis,
         plugin_analysis,
         bundle_analysis,
+        composite_analysis,
+        catalog_analysis,
+        similarity_analysis,
+        android_analysis,
+        repository_analysis,
+        compatibility_analysis,
+        dynamic_version_analysis,
     })
 }
 
-fn load_all_dependencies(root_path: &Path) -> Result<Vec<DependencyLocation>> {
+fn load_all_dependencies(
+    root_path: &Path,
+    version_catalogs: &HashMap<PathBuf, VersionCatalog>,
+) -> Result<Vec<DependencyLocation>> {
     let gradle_files = find_gradle_files(root_path)?;
-    let version_catalogs = load_version_catalogs(root_path)?;
     let mut all_dependencies = Vec::new();
     
     for gradle_file in gradle_files {
-        let mut deps = parse_dependencies_from_file(&gradle_file, &version_catalogs)?;
+        let mut deps = parse_dependencies_from_file(&gradle_file, version_catalogs)?;
         all_dependencies.append(&mut deps);
     }
     
     Ok(all_dependencies)
 }
 
-fn load_all_plugins(root_path: &Path) -> Result<Vec<PluginLocation>> {
-    let gradle_files = find_gradle_files(root_path)?;
-    let version_catalogs = load_version_catalogs(root_path)?;
+fn load_all_plugins(
+    root_path: &Path,
+    version_catalogs: &HashMap<PathBuf, VersionCatalog>,
+    config: &Config,
+) -> Result<Vec<PluginLocation>> {
+    let mut gradle_files = find_gradle_files(root_path)?;
+    gradle_files.extend(find_settings_files(root_path)?);
     let mut all_plugins = Vec::new();
     
     for gradle_file in gradle_files {
-        let mut plugins = parse_plugins_from_file(&gradle_file, &version_catalogs)?;
+        let mut plugins = parse_plugins_from_file_with_config(&gradle_file, version_catalogs, config)?;
         all_plugins.append(&mut plugins);
     }
     
     Ok(all_plugins)
 }
 
-fn create_dependency_key(group: &str, artifact: &str) -> String {
-    format!("{}:{}", group, artifact)
-}
-
 fn analyze_duplicates(all_dependencies: &[DependencyLocation]) -> DuplicateAnalysis {
-    // Group dependencies by group:artifact (ignoring version)
+    // Group dependencies by group:artifact[:classifier] (ignoring version)
     let mut dependency_groups: HashMap<String, Vec<&DependencyLocation>> = HashMap::new();
     
     for dep_location in all_dependencies {
-        let key = create_dependency_key(&dep_location.dependency.group, &dep_location.dependency.artifact);
+        let key = dep_location.dependency.key();
         dependency_groups.entry(key).or_default().push(dep_location);
     }
     
@@ -125,11 +275,11 @@ fn analyze_dependency_group(locations: &[&DependencyLocation]) -> Option<(bool,
         return None;
     }
     
-    let mut unique_files = HashSet::new();
+    let mut declaration_sites = HashSet::new();
     let mut unique_versions = HashSet::new();
     
     for location in locations {
-        unique_files.insert(&location.file_path);
+        declaration_sites.insert(dependency_declaration_site(location));
         if let Some(version) = &location.dependency.version {
             unique_versions.insert(version);
         }
@@ -140,9 +290,8 @@ fn analyze_dependency_group(locations: &[&DependencyLocation]) -> Option<(bool,
     let locations_owned: Vec<DependencyLocation> = locations.iter().map(|&loc| loc.clone()).collect();
     let is_version_conflict = unique_versions.len() > 1;
 
-    // If multple files, it's definitely a duplicate
-    // If same file but multiple occurrences, also a duplicate
-    if unique_files.len() > 1 || locations.len() > 1 {
+    // A single declaration inherited by several modules is not a duplicate
+    if declaration_sites.len() > 1 {
         Some((is_version_conflict, locations_owned))
     } else {
         None
@@ -150,26 +299,152 @@ fn analyze_dependency_group(locations: &[&DependencyLocation]) -> Option<(bool,
 }
 
 fn analyze_plugins(all_plugins: &[PluginLocation]) -> PluginAnalysis {
-    let mut plugin_groups: HashMap<String, Vec<&PluginLocation>> = HashMap::new();
+    let mut plugin_groups: HashMap<(&Path, &str), Vec<&PluginLocation>> = HashMap::new();
     
-    // Group plugins by ID
+    // Every module applies its own plugins, so only a plugin applied twice by one build file is a duplicate;
+    // classpath, pluginManagement and `apply false` entries declare versions rather than apply plugins
     for plugin_location in all_plugins {
-        let key = plugin_location.plugin.id.clone();
+        if !plugin_location.apply || matches!(plugin_location.source_type, PluginSourceType::BuildscriptClasspath(_) | PluginSourceType::PluginManagement) {
+            continue;
+        }
+        let key = (plugin_location.file_path.as_path(), plugin_location.plugin.id.as_str());
         plugin_groups.entry(key).or_default().push(plugin_location);
     }
     
-    let mut duplicate_plugins = HashMap::new();
+    let mut duplicate_plugins: HashMap<String, Vec<PluginLocation>> = HashMap::new();
     
-    for (plugin_id, locations) in plugin_groups {
-        if locations.len() > 1 {
-            // Consider duplicates in same file OR different files
-            // Same file duplicates are also important
-            let locations_owned: Vec<PluginLocation> = locations.iter().map(|&loc| loc.clone()).collect();
-            duplicate_plugins.insert(plugin_id, locations_owned);
+    for ((_, plugin_id), locations) in plugin_groups {
+        let declaration_sites: HashSet<_> = locations.iter().map(|loc| plugin_declaration_site(loc)).collect();
+        if declaration_sites.len() > 1 {
+            duplicate_plugins
+                .entry(plugin_id.to_string())
+                .or_default()
+                .extend(locations.iter().map(|&loc| loc.clone()));
         }
     }
     
+    let version_groups = group_plugin_versions(all_plugins);
+    
     PluginAnalysis {
         duplicate_plugins,
+        version_conflicts: analyze_plugin_version_conflicts(&version_groups),
+        classpath_version_mismatches: analyze_classpath_version_mismatches(&version_groups),
+        application_findings: analyze_plugin_applications(all_plugins),
+    }
+}
+
+/// Plugins declared at different versions anywhere in the project, including catalog and classpath versions
+fn analyze_plugin_version_conflicts(plugin_groups: &HashMap<String, Vec<&PluginLocation>>) -> HashMap<String, PluginVersionConflict> {
+    let mut conflicts = HashMap::new();
+    
+    for (key, locations) in plugin_groups {
+        let mut versions: Vec<String> = locations.iter().filter_map(|loc| loc.plugin.version.clone()).collect();
+        versions.sort_by(|a, b| compare_versions(a, b));
+        versions.dedup_by(|a, b| compare_versions(a, b).is_eq());
+        if versions.len() < 2 {
+            continue;
+        }
+        
+        // The widest gap between any two versions decides, and it is always reached from the lowest one
+        let widest_gap = versions[1..]
+            .iter()
+            .filter_map(|version| first_differing_part(&versions[0], version))
+            .min();
+        let severity = match widest_gap {
+            Some(0) => Severity::High,
+            Some(1) => Severity::Medium,
+            _ => Severity::Low,
+        };
+        
+        conflicts.insert(key.clone(), PluginVersionConflict {
+            severity,
+            versions,
+            classpath_pinned: pins_plugin_version(locations),
+            locations: locations.iter().map(|&loc| loc.clone()).collect(),
+        });
+    }
+    
+    conflicts
+}
+
+/// Where a dependency is written down: the original line for inherited entries
+fn dependency_declaration_site(location: &DependencyLocation) -> (&Path, usize) {
+    match &location.source_type {
+        DependencySourceType::Inherited(origin) => (&origin.file_path, origin.line_number),
+        _ => (&location.file_path, location.line_number),
+    }
+}
+
+fn plugin_declaration_site(location: &PluginLocation) -> (&Path, usize) {
+    match &location.source_type {
+        PluginSourceType::Inherited(origin) => (&origin.file_path, origin.line_number),
+        _ => (&location.file_path, location.line_number),
+    }
+}
+
+/// Cross-checks versions pinned on the buildscript classpath or in settings `pluginManagement`
+/// against versions declared in `plugins { }` blocks and catalogs
+fn analyze_classpath_version_mismatches(plugin_groups: &HashMap<String, Vec<&PluginLocation>>) -> HashMap<String, Vec<PluginLocation>> {
+    let mut mismatches = HashMap::new();
+    
+    for (key, locations) in plugin_groups {
+        let unique_versions: HashSet<_> = locations.iter().map(|loc| &loc.plugin.version).collect();
+        
+        if pins_plugin_version(locations) && unique_versions.len() > 1 {
+            let locations_owned: Vec<PluginLocation> = locations.iter().map(|&loc| loc.clone()).collect();
+            mismatches.insert(key.clone(), locations_owned);
+        }
+    }
+    
+    mismatches
+}
+
+/// Versioned plugin declarations grouped by `plugin_version_key`,
+/// so every `com.android.*` plugin shares the Android Gradle Plugin version
+fn group_plugin_versions(all_plugins: &[PluginLocation]) -> HashMap<String, Vec<&PluginLocation>> {
+    let mut plugin_groups: HashMap<String, Vec<&PluginLocation>> = HashMap::new();
+    
+    for plugin_location in all_plugins {
+        // Versions interpolated from variables cannot be compared
+        let has_version = plugin_location.plugin.version
+            .as_ref()
+            .is_some_and(|v| !v.starts_with('$'));
+        if has_version {
+            plugin_groups.entry(plugin_version_key(plugin_location)).or_default().push(plugin_location);
+        }
     }
+    
+    plugin_groups
+}
+
+fn pins_plugin_version(locations: &[&PluginLocation]) -> bool {
+    locations.iter().any(|loc| matches!(
+        loc.source_type,
+        PluginSourceType::BuildscriptClasspath(_) | PluginSourceType::PluginManagement
+    ))
+}
+
+/// Key shared by a classpath artifact and the plugin ids it provides,
+/// e.g. `com.android.tools.build:gradle` for every `com.android.*` plugin
+pub(crate) fn plugin_version_key(plugin_location: &PluginLocation) -> String {
+    let plugin_id = match &plugin_location.source_type {
+        PluginSourceType::BuildscriptClasspath(coordinate) => {
+            if CLASSPATH_PLUGIN_IDS.iter().any(|(classpath, _)| classpath == coordinate) {
+                return coordinate.clone();
+            }
+            // Plugin marker artifacts carry the plugin id: <id>:<id>.gradle.plugin
+            match coordinate.split_once(':').and_then(|(_, artifact)| artifact.strip_suffix(PLUGIN_MARKER_SUFFIX)) {
+                Some(marker_id) => marker_id,
+                None => return coordinate.clone(),
+            }
+        }
+        _ => plugin_location.plugin.id.as_str(),
+    };
+    
+    CLASSPATH_PLUGIN_IDS
+        .iter()
+        .filter(|(_, prefix)| plugin_id.starts_with(prefix))
+        .max_by_key(|(_, prefix)| prefix.len())
+        .map(|(classpath, _)| classpath.to_string())
+        .unwrap_or_else(|| plugin_id.to_string())
 }
\ No newline at end of file
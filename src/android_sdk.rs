@@ -0,0 +1,320 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::config::plugin_patterns::{ANDROID_APPLICATION_PLUGIN_ID, ANDROID_PLUGIN_ID_PREFIX};
+use crate::config::{regex_patterns, Config};
+use crate::error::Result;
+use crate::findings::{FindingReporter, FindingsByFile};
+use crate::parser::{find_gradle_files, parse_plugins_from_file_with_config};
+use crate::version::majority_deviations;
+use crate::version_catalog::VersionCatalog;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum AndroidSetting {
+    CompileSdk,
+    MinSdk,
+    TargetSdk,
+    BuildToolsVersion,
+    Namespace,
+}
+
+impl fmt::Display for AndroidSetting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            AndroidSetting::CompileSdk => "compileSdk",
+            AndroidSetting::MinSdk => "minSdk",
+            AndroidSetting::TargetSdk => "targetSdk",
+            AndroidSetting::BuildToolsVersion => "buildToolsVersion",
+            AndroidSetting::Namespace => "namespace",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Settings expected to agree across the modules of a build
+const CONSISTENT_SETTINGS: &[AndroidSetting] = &[
+    AndroidSetting::CompileSdk,
+    AndroidSetting::MinSdk,
+    AndroidSetting::TargetSdk,
+    AndroidSetting::BuildToolsVersion,
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AndroidSettingValue {
+    /// Value as written without quotes, e.g. `34` or `libs.versions.compileSdk.get().toInt()`
+    pub value: String,
+    pub line_number: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AndroidModule {
+    pub build_file: PathBuf,
+    /// Gradle project path derived from the module directory, e.g. `:feature:login`
+    pub project_path: String,
+    pub is_application: bool,
+    pub compile_sdk: Option<AndroidSettingValue>,
+    pub min_sdk: Option<AndroidSettingValue>,
+    pub target_sdk: Option<AndroidSettingValue>,
+    pub build_tools_version: Option<AndroidSettingValue>,
+    pub namespace: Option<AndroidSettingValue>,
+    /// `project(...)` and `projects.` references of the dependencies block
+    pub project_dependencies: Vec<String>,
+}
+
+impl AndroidModule {
+    pub fn setting(&self, setting: AndroidSetting) -> Option<&AndroidSettingValue> {
+        match setting {
+            AndroidSetting::CompileSdk => self.compile_sdk.as_ref(),
+            AndroidSetting::MinSdk => self.min_sdk.as_ref(),
+            AndroidSetting::TargetSdk => self.target_sdk.as_ref(),
+            AndroidSetting::BuildToolsVersion => self.build_tools_version.as_ref(),
+            AndroidSetting::Namespace => self.namespace.as_ref(),
+        }
+    }
+
+    fn setting_mut(&mut self, setting: AndroidSetting) -> &mut Option<AndroidSettingValue> {
+        match setting {
+            AndroidSetting::CompileSdk => &mut self.compile_sdk,
+            AndroidSetting::MinSdk => &mut self.min_sdk,
+            AndroidSetting::TargetSdk => &mut self.target_sdk,
+            AndroidSetting::BuildToolsVersion => &mut self.build_tools_version,
+            AndroidSetting::Namespace => &mut self.namespace,
+        }
+    }
+
+    fn min_sdk_level(&self) -> Option<u32> {
+        self.min_sdk.as_ref().and_then(|min_sdk| min_sdk.value.parse().ok())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum AndroidFindingKind {
+    InconsistentSetting,
+    LibraryMinSdkAboveApp,
+    DuplicateNamespace,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AndroidAnalysis {
+    /// Modules applying an Android plugin or configuring `android { }`, by project path
+    pub modules: Vec<AndroidModule>,
+    /// About the setting they are reported at
+    pub findings: FindingsByFile<AndroidFindingKind, AndroidSetting>,
+}
+
+/// Android modules among the build files under `root_path`
+pub fn load_android_modules(
+    root_path: &Path,
+    version_catalogs: &HashMap<PathBuf, VersionCatalog>,
+    config: &Config,
+) -> Result<Vec<AndroidModule>> {
+    let setting_regex = Regex::new(regex_patterns::ANDROID_SETTING)?;
+    let block_regex = Regex::new(regex_patterns::BLOCK_NAME)?;
+    let project_regex = Regex::new(regex_patterns::PROJECT_REFERENCE)?;
+    let accessor_regex = Regex::new(regex_patterns::PROJECTS_ACCESSOR_REFERENCE)?;
+    let mut modules = Vec::new();
+
+    for build_file in find_gradle_files(root_path)? {
+        let plugin_ids: Vec<String> = parse_plugins_from_file_with_config(&build_file, version_catalogs, config)?
+            .into_iter()
+            .filter(|plugin| plugin.apply)
+            .map(|plugin| plugin.plugin.id)
+            .collect();
+        let mut module = AndroidModule {
+            project_path: project_path(root_path, &build_file),
+            build_file: build_file.clone(),
+            is_application: plugin_ids.iter().any(|id| id == ANDROID_APPLICATION_PLUGIN_ID),
+            compile_sdk: None,
+            min_sdk: None,
+            target_sdk: None,
+            build_tools_version: None,
+            namespace: None,
+            project_dependencies: Vec::new(),
+        };
+        let mut has_android_block = false;
+        // Names of the enclosing blocks; braces opened without a recognizable name push an empty one
+        let mut blocks: Vec<String> = Vec::new();
+
+        for (line_index, line) in fs::read_to_string(&build_file)?.lines().enumerate() {
+            // Braces and semicolons split the line, so one-line blocks like `defaultConfig { minSdk = 24 }` enclose their settings
+            let mut rest = line;
+            loop {
+                let segment_end = rest.find(['{', '}']).unwrap_or(rest.len());
+                let (segment, remainder) = rest.split_at(segment_end);
+                for statement in segment.split(';') {
+                    read_statement(statement, &blocks, &mut module, line_index + 1, &setting_regex, &project_regex, &accessor_regex);
+                }
+
+                match remainder.chars().next() {
+                    Some('{') => {
+                        let name = block_regex.captures(segment.rsplit(';').next().unwrap_or(segment)).map(|captures| captures[1].to_string()).unwrap_or_default();
+                        if blocks.is_empty() && name == regex_patterns::ANDROID_BLOCK {
+                            has_android_block = true;
+                        }
+                        blocks.push(name);
+                    }
+                    Some('}') => {
+                        blocks.pop();
+                    }
+                    _ => break,
+                }
+                rest = &remainder[1..];
+            }
+        }
+
+        if has_android_block || plugin_ids.iter().any(|id| id.starts_with(ANDROID_PLUGIN_ID_PREFIX)) {
+            modules.push(module);
+        }
+    }
+
+    modules.sort_by(|a, b| a.project_path.cmp(&b.project_path));
+    Ok(modules)
+}
+
+/// Setting or project dependencies of one statement nested in `blocks`
+fn read_statement(
+    statement: &str,
+    blocks: &[String],
+    module: &mut AndroidModule,
+    line_number: usize,
+    setting_regex: &Regex,
+    project_regex: &Regex,
+    accessor_regex: &Regex,
+) {
+    let in_android = blocks.first().is_some_and(|block| block == regex_patterns::ANDROID_BLOCK);
+    let settings_scope = in_android
+        && (blocks.len() == 1 || (blocks.len() == 2 && blocks[1] == regex_patterns::DEFAULT_CONFIG_BLOCK));
+
+    if settings_scope {
+        if let Some(captures) = setting_regex.captures(statement) {
+            let setting = match &captures[1] {
+                "compileSdk" | "compileSdkVersion" => AndroidSetting::CompileSdk,
+                "minSdk" | "minSdkVersion" => AndroidSetting::MinSdk,
+                "targetSdk" | "targetSdkVersion" => AndroidSetting::TargetSdk,
+                "buildToolsVersion" => AndroidSetting::BuildToolsVersion,
+                _ => AndroidSetting::Namespace,
+            };
+            *module.setting_mut(setting) = Some(AndroidSettingValue {
+                value: setting_value(&captures[2]),
+                line_number,
+            });
+        }
+    } else if blocks.len() == 1 && blocks[0] == regex_patterns::DEPENDENCIES_BLOCK {
+        module.project_dependencies.extend(project_regex.captures_iter(statement).map(|captures| captures[1].to_string()));
+        module.project_dependencies.extend(accessor_regex.captures_iter(statement).map(|captures| captures[1].replace('.', ":")));
+    }
+}
+
+/// `:feature:login` for `feature/login/build.gradle.kts` under the build root, `:` for the root project
+fn project_path(root_path: &Path, build_file: &Path) -> String {
+    let segments: Vec<String> = build_file
+        .parent()
+        .and_then(|dir| dir.strip_prefix(root_path).ok())
+        .map(|dir| dir.components().map(|component| component.as_os_str().to_string_lossy().into_owned()).collect())
+        .unwrap_or_default();
+    format!(":{}", segments.join(":"))
+}
+
+/// Type-safe project accessors camel-case directory names, so `projects.core.designSystem` is `:core:design-system`
+fn normalize_project_path(project_path: &str) -> String {
+    let segments: Vec<String> = project_path
+        .split(':')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| segment.chars().filter(|c| *c != '-' && *c != '_').collect::<String>().to_lowercase())
+        .collect();
+    format!(":{}", segments.join(":"))
+}
+
+/// `= 34`, ` 34`, `(34)` or `= "com.example"` without the assignment, call parentheses, quotes and trailing comment
+fn setting_value(raw: &str) -> String {
+    let raw = raw.split("//").next().unwrap_or(raw).trim();
+    let raw = if let Some(assigned) = raw.strip_prefix('=') {
+        assigned.trim()
+    } else if let Some(argument) = raw.strip_prefix('(') {
+        argument.strip_suffix(')').unwrap_or(argument).trim()
+    } else {
+        raw
+    };
+    raw.trim_matches(|c| c == '"' || c == '\'').to_string()
+}
+
+/// Settings out of line with the other modules, library `minSdk` above a depending app and shared namespaces
+pub fn analyze_android_modules(modules: Vec<AndroidModule>) -> AndroidAnalysis {
+    let mut findings = FindingReporter::new();
+    let mut report = |module: &AndroidModule, kind, setting: AndroidSetting, message| {
+        let line_number = module.setting(setting).map(|value| value.line_number).unwrap_or_default();
+        findings.report(&module.build_file, line_number, kind, setting, message);
+    };
+
+    for &setting in CONSISTENT_SETTINGS {
+        let deviations = majority_deviations(&modules, setting, |module| module.setting(setting).map(|value| value.value.as_str()));
+        for (module, message) in deviations {
+            report(module, AndroidFindingKind::InconsistentSetting, setting, message);
+        }
+    }
+
+    // Manifests of every library an app reaches through project dependencies are merged into the app
+    let modules_by_path: HashMap<String, &AndroidModule> = modules
+        .iter()
+        .map(|module| (normalize_project_path(&module.project_path), module))
+        .collect();
+    for app in modules.iter().filter(|module| module.is_application) {
+        let Some(app_min_sdk) = app.min_sdk_level() else { continue };
+        let mut visited = HashSet::new();
+        let mut pending: Vec<&AndroidModule> = vec![app];
+        while let Some(module) = pending.pop() {
+            for dependency in &module.project_dependencies {
+                let Some(library) = modules_by_path.get(&normalize_project_path(dependency)) else { continue };
+                if library.is_application || !visited.insert(library.build_file.clone()) {
+                    continue;
+                }
+                if let Some(library_min_sdk) = library.min_sdk_level().filter(|level| *level > app_min_sdk) {
+                    report(
+                        library,
+                        AndroidFindingKind::LibraryMinSdkAboveApp,
+                        AndroidSetting::MinSdk,
+                        format!(
+                            "minSdk {} is higher than minSdk {} of {}, which depends on it; the manifest merger rejects the library",
+                            library_min_sdk, app_min_sdk, app.project_path
+                        ),
+                    );
+                }
+                pending.push(library);
+            }
+        }
+    }
+
+    let mut namespaces: HashMap<&str, Vec<&AndroidModule>> = HashMap::new();
+    for module in &modules {
+        if let Some(namespace) = &module.namespace {
+            namespaces.entry(namespace.value.as_str()).or_default().push(module);
+        }
+    }
+    for (namespace, sharing_modules) in namespaces.iter().filter(|(_, sharing_modules)| sharing_modules.len() > 1) {
+        for module in sharing_modules {
+            let others: Vec<&str> = sharing_modules
+                .iter()
+                .filter(|other| other.build_file != module.build_file)
+                .map(|other| other.project_path.as_str())
+                .collect();
+            report(
+                module,
+                AndroidFindingKind::DuplicateNamespace,
+                AndroidSetting::Namespace,
+                format!("namespace `{}` is also used by {}; the manifest merger requires unique namespaces", namespace, others.join(", ")),
+            );
+        }
+    }
+
+    AndroidAnalysis { modules, findings: findings.finish() }
+}
\ No newline at end of file
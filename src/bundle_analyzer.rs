@@ -5,169 +5,215 @@
  * See LICENSE file in the project root for full license information.
  */
 
-use crate::config::Config;
+use crate::config::{BundleNamePatterns, Config};
 use crate::parser::DependencyLocation;
 use serde::Serialize;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::path::PathBuf;
 
+/// Configurations each declaring module uses for one (dependency, configuration) item
+type ModuleConfigurations = BTreeMap<usize, BTreeSet<String>>;
+
 #[derive(Debug, Clone, Serialize)]
 pub struct DependencyBundle {
-    pub dependencies: Vec<String>, // group:artifact format
+    pub dependencies: Vec<String>, // group:artifact[:classifier] format
+    pub members: Vec<BundleMember>, // one per dependency, in the same order
     pub modules: Vec<PathBuf>,
     pub bundle_size: usize,
-    pub module_count: usize,
-    pub configurations: HashSet<String>, // implementation, api, testImplementation, etc.
+    pub module_count: usize, // support: modules declaring every dependency of the bundle
+    pub configurations: HashSet<String>, // configurations the bundle's modules declare its members with
     pub priority_score: f64,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct BundleMember {
+    pub dependency: String,
+    pub configuration: String, // configuration the bundle should declare it with
+    pub declared_configurations: BTreeSet<String>, // configurations the bundle's modules declare it with today
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct BundleAnalysis {
     pub recommended_bundles: Vec<DependencyBundle>,
     pub total_bundles_found: usize,
 }
 
+/// What each module declares, keyed by build file in path order; `item` picks what of a declaration counts,
+/// e.g. its dependency key alone or together with its configuration
+pub fn module_dependency_sets<T: Ord>(
+    dependencies: &[DependencyLocation],
+    item: impl Fn(&DependencyLocation) -> T,
+) -> BTreeMap<PathBuf, BTreeSet<T>> {
+    let mut module_dep_sets: BTreeMap<PathBuf, BTreeSet<T>> = BTreeMap::new();
+    for dep in dependencies {
+        module_dep_sets.entry(dep.file_path.clone()).or_default().insert(item(dep));
+    }
+    module_dep_sets
+}
+
 pub fn find_dependency_bundles(
     dependencies: &[DependencyLocation],
     min_bundle_size: usize,
     min_module_count: usize,
+    merge_configurations: bool,
 ) -> BundleAnalysis {
     let config = Config::default();
-    find_dependency_bundles_with_config(dependencies, min_bundle_size, min_module_count, &config)
+    find_dependency_bundles_with_config(dependencies, min_bundle_size, min_module_count, merge_configurations, &config)
 }
 
+/// Bundles are mined over (configuration, dependency) pairs, so `testImplementation(junit)` and
+/// `implementation(junit)` are not shared. With `merge_configurations`, configurations that only
+/// differ in `api` versus `implementation` count as one and each member takes the configuration
+/// most of the bundle's modules use.
 pub fn find_dependency_bundles_with_config(
     dependencies: &[DependencyLocation],
     min_bundle_size: usize,
     min_module_count: usize,
+    merge_configurations: bool,
     config: &Config,
 ) -> BundleAnalysis {
-    // Group dependencies by module (file)
-    let mut module_dependencies: HashMap<PathBuf, Vec<&DependencyLocation>> = HashMap::new();
+    // Each module's (dependency, item configuration, declared configuration) triples
+    let module_dep_sets = module_dependency_sets(dependencies, |dep| {
+        let item_configuration = if merge_configurations {
+            merged_configuration(&dep.configuration)
+        } else {
+            dep.configuration.clone()
+        };
+        (dep.dependency.key(), item_configuration, dep.configuration.clone())
+    });
     
-    for dep in dependencies {
-        module_dependencies
-            .entry(dep.file_path.clone())
-            .or_default()
-            .push(dep);
+    // Index modules and dependency keys so itemsets can be mined over integer ids
+    let modules: Vec<PathBuf> = module_dep_sets.keys().cloned().collect();
+    
+    // Every (dependency, configuration) item with the modules declaring it and the configurations they use
+    let mut item_declarations: BTreeMap<(String, String), ModuleConfigurations> = BTreeMap::new();
+    for (module, dep_set) in module_dep_sets.into_values().enumerate() {
+        for (dependency, item_configuration, configuration) in dep_set {
+            item_declarations
+                .entry((dependency, item_configuration))
+                .or_default()
+                .entry(module)
+                .or_default()
+                .insert(configuration);
+        }
     }
     
-    // Convert to module -> set of dependencies for easier comparison
-    let module_dep_sets: HashMap<PathBuf, HashSet<String>> = module_dependencies
-        .into_iter()
-        .map(|(module, deps)| {
-            let dep_set = deps
-                .into_iter()
-                .map(|d| format!("{}:{}", d.dependency.group, d.dependency.artifact))
-                .collect();
-            (module, dep_set)
-        })
+    // A bundle needs at least two modules to be worth sharing, so rarer items are dropped up front
+    let min_support = min_module_count.max(2);
+    let items: Vec<(&(String, String), &ModuleConfigurations)> = item_declarations
+        .iter()
+        .filter(|(_, declarations)| declarations.len() >= min_support)
         .collect();
-    
-    // Find all possible dependency combinations that appear in multiple modules
-    let mut bundle_candidates: HashMap<Vec<String>, Vec<PathBuf>> = HashMap::new();
-    
-    // Get all modules as a vector for easier iteration
-    let modules: Vec<_> = module_dep_sets.keys().cloned().collect();
-    
-    // For each pair of modules, find common dependencies
-    for i in 0..modules.len() {
-        for j in i + 1..modules.len() {
-            let module1 = &modules[i];
-            let module2 = &modules[j];
-            
-            let deps1 = &module_dep_sets[module1];
-            let deps2 = &module_dep_sets[module2];
-            
-            let common: HashSet<_> = deps1.intersection(deps2).cloned().collect();
-            
-            if common.len() >= min_bundle_size {
-                // Generate all subsets of common dependencies that meet min_bundle_size
-                let common_vec: Vec<String> = common.into_iter().collect();
-                let subsets = generate_subsets(&common_vec, min_bundle_size);
-                
-                for subset in subsets {
-                    let mut sorted_subset = subset;
-                    sorted_subset.sort(); // Ensure consistent ordering
-                    
-                    // Find all modules that have this exact subset
-                    let mut modules_with_subset = Vec::new();
-                    for (module, deps) in &module_dep_sets {
-                        if sorted_subset.iter().all(|dep| deps.contains(dep)) {
-                            modules_with_subset.push(module.clone());
-                        }
-                    }
-                    
-                    if modules_with_subset.len() >= min_module_count {
-                        bundle_candidates.insert(sorted_subset, modules_with_subset);
-                    }
-                }
-            }
+    let mut module_items: Vec<Vec<usize>> = vec![Vec::new(); modules.len()];
+    for (item, (_, declarations)) in items.iter().enumerate() {
+        for &module in declarations.keys() {
+            module_items[module].push(item);
         }
     }
     
-    // Convert candidates to DependencyBundle and calculate priority scores
-    let bundles: Vec<DependencyBundle> = bundle_candidates
+    let miner = ClosedItemsetMiner { module_items: &module_items, item_count: items.len(), min_support };
+    let mut bundles: Vec<DependencyBundle> = miner
+        .mine(modules.len())
         .into_iter()
-        .map(|(deps, modules)| {
-            let configurations = get_configurations_for_bundle(&deps, dependencies);
-            let priority_score = calculate_priority_score(&deps, &modules, &configurations, config);
+        .filter(|itemset| !itemset.items.is_empty() && itemset.items.len() >= min_bundle_size)
+        .map(|itemset| {
+            let mut members = Vec::new();
+            let mut configurations = HashSet::new();
+            for &item in &itemset.items {
+                let ((dependency, _), declarations) = items[item];
+                let used: Vec<&String> = itemset
+                    .modules
+                    .iter()
+                    .flat_map(|module| &declarations[module])
+                    .collect();
+                configurations.extend(used.iter().map(|&configuration| configuration.clone()));
+                members.push(BundleMember {
+                    dependency: dependency.clone(),
+                    configuration: most_common_configuration(&used),
+                    declared_configurations: used.into_iter().cloned().collect(),
+                });
+            }
+            let deps: Vec<String> = members.iter().map(|m| m.dependency.clone()).collect();
+            let bundle_modules: Vec<PathBuf> = itemset.modules.iter().map(|&i| modules[i].clone()).collect();
+            let priority_score = calculate_priority_score(&deps, &bundle_modules, &configurations, config);
             
             DependencyBundle {
                 bundle_size: deps.len(),
-                module_count: modules.len(),
+                module_count: bundle_modules.len(),
                 dependencies: deps,
-                modules,
+                members,
+                modules: bundle_modules,
                 configurations,
                 priority_score,
             }
         })
         .collect();
     
-    // Remove subsets of larger bundles (if bundle A is subset of B and they share same modules, remove A)
-    let filtered_bundles = remove_subset_bundles(bundles);
-    
-    // Sort by priority score (highest first)
-    let mut sorted_bundles = filtered_bundles;
-    sorted_bundles.sort_by(|a, b| b.priority_score.partial_cmp(&a.priority_score).unwrap());
+    // Sort by priority score (highest first), ties in dependency order so output is stable
+    bundles.sort_by(|a, b| {
+        b.priority_score
+            .partial_cmp(&a.priority_score)
+            .unwrap()
+            .then_with(|| a.dependencies.cmp(&b.dependencies))
+    });
     
     BundleAnalysis {
-        total_bundles_found: sorted_bundles.len(),
-        recommended_bundles: sorted_bundles,
+        total_bundles_found: bundles.len(),
+        recommended_bundles: bundles,
     }
 }
 
-fn generate_subsets(items: &[String], min_size: usize) -> Vec<Vec<String>> {
-    let mut subsets = Vec::new();
-    let n = items.len();
-    
-    // Generate all possible subsets using bit manipulation
-    for mask in 1..(1 << n) {
-        let mut subset = Vec::new();
-        for i in 0..n {
-            if mask & (1 << i) != 0 {
-                subset.push(items[i].clone());
-            }
-        }
-        if subset.len() >= min_size {
-            subsets.push(subset);
-        }
+/// `api` and `implementation` variants of one source set only differ in whether the
+/// dependency leaks to consumers, so they are merged under the `implementation` name
+fn merged_configuration(configuration: &str) -> String {
+    if let Some((base, source_set)) = configuration.split_once('-') {
+        return format!("{}-{}", merged_configuration(base), source_set);
     }
     
-    subsets
+    if configuration == "api" {
+        "implementation".to_string()
+    } else if let Some(prefix) = configuration.strip_suffix("Api") {
+        format!("{}Implementation", prefix)
+    } else {
+        configuration.to_string()
+    }
 }
 
-fn get_configurations_for_bundle(bundle_deps: &[String], all_dependencies: &[DependencyLocation]) -> HashSet<String> {
-    let mut configurations = HashSet::new();
-    
-    for dep_location in all_dependencies {
-        let dep_key = format!("{}:{}", dep_location.dependency.group, dep_location.dependency.artifact);
-        if bundle_deps.contains(&dep_key) {
-            configurations.insert(dep_location.configuration.clone());
-        }
+/// Ties go to the alphabetically first configuration, which prefers `api` over `implementation`
+fn most_common_configuration(configurations: &[&String]) -> String {
+    let mut counts: BTreeMap<&String, usize> = BTreeMap::new();
+    for &configuration in configurations {
+        *counts.entry(configuration).or_insert(0) += 1;
     }
     
-    configurations
+    counts
+        .into_iter()
+        .max_by(|(config_a, count_a), (config_b, count_b)| count_a.cmp(count_b).then_with(|| config_b.cmp(config_a)))
+        .map(|(configuration, _)| configuration.clone())
+        .unwrap_or_default()
+}
+
+/// Name suggested for a bundle after the group most of its dependencies share
+pub fn generate_bundle_name(dependencies: &[String], patterns: &BundleNamePatterns) -> String {
+    let most_common_group = find_most_common_group(dependencies);
+    patterns.find_bundle_name(&most_common_group)
+}
+
+fn find_most_common_group(dependencies: &[String]) -> String {
+    let common_groups: HashMap<String, usize> = dependencies
+        .iter()
+        .map(|dep| dep.split(':').next().unwrap_or("unknown").to_string())
+        .fold(HashMap::new(), |mut acc, group| {
+            *acc.entry(group).or_insert(0) += 1;
+            acc
+        });
+    
+    // Ties go to the alphabetically first group so names are stable between runs
+    common_groups
+        .iter()
+        .max_by(|(group_a, count_a), (group_b, count_b)| count_a.cmp(count_b).then_with(|| group_b.cmp(group_a)))
+        .map(|(group, _)| group.clone())
+        .unwrap_or_else(|| "common".to_string())
 }
 
 fn calculate_priority_score(
@@ -195,23 +241,76 @@ fn calculate_priority_score(
     bundle_size_score + module_count_score + config_score
 }
 
-fn remove_subset_bundles(mut bundles: Vec<DependencyBundle>) -> Vec<DependencyBundle> {
-    bundles.sort_by_key(|b| std::cmp::Reverse(b.bundle_size)); // Largest first
-    
-    let mut filtered = Vec::new();
-    
-    for bundle in bundles {
-        let is_subset = filtered.iter().any(|existing: &DependencyBundle| {
-            // Check if current bundle is a subset of an existing one with same modules
-            bundle.modules == existing.modules &&
-            bundle.dependencies.iter().all(|dep| existing.dependencies.contains(dep)) &&
-            bundle.dependencies.len() < existing.dependencies.len()
-        });
+/// Dependencies shared by exactly `modules`: no further dependency is common to all of them
+struct ClosedItemset {
+    items: Vec<usize>,
+    modules: Vec<usize>,
+}
+
+/// LCM-style enumeration of closed frequent itemsets over module dependency sets.
+///
+/// Each closed itemset is reached once through a prefix-preserving closure extension,
+/// so the work grows with the number of bundles found rather than with `2^n` subsets
+/// of the dependencies two modules have in common.
+struct ClosedItemsetMiner<'a> {
+    module_items: &'a [Vec<usize>], // sorted item indices declared by each module
+    item_count: usize,
+    min_support: usize,
+}
+
+impl ClosedItemsetMiner<'_> {
+    fn mine(&self, module_count: usize) -> Vec<ClosedItemset> {
+        let mut itemsets = Vec::new();
+        if module_count < self.min_support {
+            return itemsets;
+        }
         
-        if !is_subset {
-            filtered.push(bundle);
+        let all_modules: Vec<usize> = (0..module_count).collect();
+        let root_items = self.closure(&all_modules);
+        self.expand(root_items, all_modules, 0, &mut itemsets);
+        itemsets
+    }
+
+    fn expand(&self, items: Vec<usize>, modules: Vec<usize>, first_extension: usize, itemsets: &mut Vec<ClosedItemset>) {
+        // Deliver every module to the extension items it declares, giving each extension's modules in one pass
+        let mut occurrences: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for &module in &modules {
+            for &item in &self.module_items[module] {
+                if item >= first_extension && items.binary_search(&item).is_err() {
+                    occurrences.entry(item).or_default().push(module);
+                }
+            }
         }
+        
+        for (item, extended_modules) in occurrences {
+            if extended_modules.len() < self.min_support {
+                continue;
+            }
+            
+            // Skip closures that pull in an earlier item; they are reached from another branch
+            let closure = self.closure(&extended_modules);
+            let preserves_prefix = closure
+                .iter()
+                .take_while(|&&other| other < item)
+                .eq(items.iter().take_while(|&&other| other < item));
+            if preserves_prefix {
+                self.expand(closure, extended_modules, item + 1, itemsets);
+            }
+        }
+        
+        itemsets.push(ClosedItemset { items, modules });
+    }
+
+    /// Items declared by every one of `modules`, in ascending order
+    fn closure(&self, modules: &[usize]) -> Vec<usize> {
+        let Some((&first, rest)) = modules.split_first() else {
+            return (0..self.item_count).collect();
+        };
+        let mut closure = self.module_items[first].clone();
+        for &module in rest {
+            let declared = &self.module_items[module];
+            closure.retain(|item| declared.binary_search(item).is_ok());
+        }
+        closure
     }
-    
-    filtered
 }
\ No newline at end of file
@@ -0,0 +1,414 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::bundle_analyzer::{generate_bundle_name, BundleMember, DependencyBundle};
+use crate::catalog_migration::{
+    find_root_build_files, insert_toml_entries, library_alias_candidates, locate_default_catalog,
+    sanitize_alias, unsupported_reason, AliasAllocator, VersionKeyAllocator,
+};
+use crate::config::{file_patterns, regex_patterns, BundleNamePatterns};
+use crate::error::Result;
+use crate::parser::{load_version_catalogs, parse_dependencies_from_file, DependencyLocation, DependencySourceType};
+use crate::rewrite::FileChange;
+use crate::version_catalog::{normalize_alias, parse_version_catalog};
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// group and artifact of a library
+type Coordinate = (String, String);
+
+/// A recommended bundle expressed as a `[bundles]` entry of the `libs` catalog
+#[derive(Debug, Clone)]
+pub struct CatalogBundleSuggestion {
+    /// Accessor modules use instead of the individual declarations, e.g. `libs.bundles.androidx`
+    pub reference: String,
+    /// group:artifact of each member, in bundle order
+    pub dependencies: Vec<String>,
+    /// Build files whose member declarations are replaced by the bundle
+    pub rewritten_modules: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SkippedBundle {
+    pub dependencies: Vec<String>,
+    pub reason: String,
+}
+
+/// A module of a bundle that keeps its individual declarations
+#[derive(Debug, Clone)]
+pub struct SkippedBundleModule {
+    pub bundle_reference: String,
+    pub module: PathBuf,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CatalogBundlePlan {
+    pub catalog_path: PathBuf,
+    /// `[versions]` lines created for the versions of new libraries
+    pub version_entries: Vec<String>,
+    /// `[libraries]` lines created for members the catalog does not declare yet
+    pub library_entries: Vec<String>,
+    pub bundle_entries: Vec<String>,
+    pub bundles: Vec<CatalogBundleSuggestion>,
+    pub skipped_bundles: Vec<SkippedBundle>,
+    pub skipped_modules: Vec<SkippedBundleModule>,
+    /// The catalog first, then rewritten build files in path order
+    pub changes: Vec<FileChange>,
+}
+
+/// Member declarations of one module and how the bundle declaration replacing them is written
+struct ModuleRewrite {
+    member_lines: Vec<usize>,
+    indent: String,
+    configuration: String,
+    parenthesized: bool,
+}
+
+impl ModuleRewrite {
+    fn declaration(&self, reference: &str) -> String {
+        if self.parenthesized {
+            format!("{}{}({})", self.indent, self.configuration, reference)
+        } else {
+            format!("{}{} {}", self.indent, self.configuration, reference)
+        }
+    }
+}
+
+/// Catalog library a bundle member maps to
+enum MemberAlias {
+    Existing(String),
+    New { coordinate: Coordinate, version: Option<String> },
+}
+
+/// Plans `[bundles]` entries for the recommended bundles and the module rewrites to `libs.bundles.<alias>`
+pub fn plan_catalog_bundles(root_path: &Path, bundles: &[DependencyBundle]) -> Result<CatalogBundlePlan> {
+    let catalog_path = locate_default_catalog(root_path)?;
+    let (catalog_content, catalog) = if catalog_path.is_file() {
+        (fs::read_to_string(&catalog_path)?, Some(parse_version_catalog(&catalog_path)?))
+    } else {
+        (String::new(), None)
+    };
+    let module_declarations = load_module_declarations(root_path, bundles)?;
+
+    // Coordinates the catalog already declares keep their alias
+    let mut existing_libraries: HashMap<Coordinate, (String, Option<String>)> = HashMap::new();
+    if let Some(catalog) = &catalog {
+        let mut aliases: Vec<&String> = catalog.libraries.iter().flat_map(|libraries| libraries.keys()).collect();
+        aliases.sort();
+        for alias in aliases {
+            if let Some(coordinate) = catalog.libraries.as_ref().and_then(|libraries| libraries[alias].coordinate()) {
+                let version = catalog.resolve_library_version(alias).map(|(_, _, version)| version);
+                existing_libraries.entry(coordinate).or_insert((alias.clone(), version));
+            }
+        }
+    }
+
+    let mut version_keys = VersionKeyAllocator::new(catalog.as_ref());
+    let mut library_aliases = AliasAllocator::new(catalog.iter().flat_map(|catalog| catalog.libraries.iter().flat_map(|libraries| libraries.keys())));
+    let mut bundle_aliases = AliasAllocator::new(catalog.iter().flat_map(|catalog| catalog.bundles.iter().flat_map(|bundles| bundles.keys())));
+    let bundle_name_patterns = BundleNamePatterns::default();
+    let declaration_regex = Regex::new(regex_patterns::DECLARATION_CONFIGURATION)?;
+
+    let mut created_libraries: HashMap<Coordinate, (String, Option<String>)> = HashMap::new();
+    let mut version_entries = Vec::new();
+    let mut library_entries = Vec::new();
+    let mut bundle_entries = Vec::new();
+    let mut suggestions = Vec::new();
+    let mut skipped_bundles = Vec::new();
+    let mut skipped_modules = Vec::new();
+    let mut claimed_lines: HashSet<(PathBuf, usize)> = HashSet::new();
+    let mut emitted_references: HashMap<Vec<String>, String> = HashMap::new();
+    let mut line_rewrites: BTreeMap<PathBuf, BTreeMap<usize, Option<String>>> = BTreeMap::new();
+
+    for bundle in bundles.iter().flat_map(split_by_configuration) {
+        let declarations: Vec<&DependencyLocation> = bundle.modules.iter()
+            .filter_map(|module| module_declarations.get(module))
+            .flatten()
+            .collect();
+        let member_aliases = match resolve_members(&bundle, &declarations, &existing_libraries, &created_libraries) {
+            Ok(member_aliases) => member_aliases,
+            Err(reason) => {
+                skipped_bundles.push(SkippedBundle { dependencies: bundle.dependencies.clone(), reason });
+                continue;
+            }
+        };
+
+        // A bundle is only worth emitting when some module can switch to it
+        let mut module_rewrites = Vec::new();
+        let mut module_skips = Vec::new();
+        for module in &bundle.modules {
+            let rewrite = match module_declarations.get(module) {
+                Some(declarations) => plan_module_rewrite(&bundle, module, declarations, &claimed_lines, &declaration_regex),
+                None => Err("belongs to another build that cannot use the root catalog".to_string()),
+            };
+            match rewrite {
+                Ok(rewrite) => module_rewrites.push((module.clone(), rewrite)),
+                Err(reason) => module_skips.push((module.clone(), reason)),
+            }
+        }
+        if module_rewrites.is_empty() {
+            let reason = module_skips.first().map(|(_, reason)| reason.as_str()).unwrap_or("no module declares it");
+            skipped_bundles.push(SkippedBundle {
+                dependencies: bundle.dependencies.clone(),
+                reason: format!("no module can use the bundle: {}", reason),
+            });
+            continue;
+        }
+
+        // Bundles split from different recommendations can end up with the same members
+        let mut member_set = bundle.dependencies.clone();
+        member_set.sort();
+        let reference = match emitted_references.get(&member_set) {
+            Some(reference) => reference.clone(),
+            None => {
+                let mut members = Vec::new();
+                for member_alias in member_aliases {
+                    match member_alias {
+                        MemberAlias::Existing(alias) => members.push(format!("\"{}\"", alias)),
+                        MemberAlias::New { coordinate, version } => {
+                            let (group, artifact) = &coordinate;
+                            let alias = library_aliases.allocate(&library_alias_candidates(group, artifact));
+                            library_entries.push(match &version {
+                                Some(version) => {
+                                    let key = version_keys.allocate(std::slice::from_ref(&alias), version, &mut version_entries);
+                                    format!("{} = {{ module = \"{}:{}\", version.ref = \"{}\" }}", alias, group, artifact, key)
+                                }
+                                None => format!("{} = {{ module = \"{}:{}\" }}", alias, group, artifact),
+                            });
+                            members.push(format!("\"{}\"", alias));
+                            created_libraries.insert(coordinate, (alias, version));
+                        }
+                    }
+                }
+
+                let bundle_name = generate_bundle_name(&bundle.dependencies, &bundle_name_patterns);
+                let alias = bundle_aliases.allocate(&[sanitize_alias(bundle_name.trim_end_matches("-bundle"))]);
+                bundle_entries.push(format!("{} = [{}]", alias, members.join(", ")));
+                let reference = format!("{}.bundles.{}", file_patterns::DEFAULT_CATALOG_ACCESSOR, normalize_alias(&alias));
+                emitted_references.insert(member_set, reference.clone());
+                reference
+            }
+        };
+
+        let mut rewritten_modules = Vec::new();
+        for (module, rewrite) in module_rewrites {
+            let file_rewrites = line_rewrites.entry(module.clone()).or_default();
+            // The first member line becomes the bundle declaration, the others are removed
+            for (index, line_number) in rewrite.member_lines.iter().enumerate() {
+                claimed_lines.insert((module.clone(), *line_number));
+                file_rewrites.insert(*line_number, (index == 0).then(|| rewrite.declaration(&reference)));
+            }
+            rewritten_modules.push(module);
+        }
+        rewritten_modules.sort();
+        skipped_modules.extend(module_skips.into_iter().map(|(module, reason)| SkippedBundleModule {
+            bundle_reference: reference.clone(),
+            module,
+            reason,
+        }));
+
+        suggestions.push(CatalogBundleSuggestion {
+            reference,
+            dependencies: bundle.dependencies.clone(),
+            rewritten_modules,
+        });
+    }
+
+    // Recommendations split into the same members are skipped for the same reason
+    let mut seen_skips = HashSet::new();
+    skipped_bundles.retain(|skipped| seen_skips.insert((skipped.dependencies.clone(), skipped.reason.clone())));
+
+    let mut changes = Vec::new();
+    let updated_catalog = insert_toml_entries(&catalog_content, "versions", &version_entries, &["libraries", "bundles", "plugins"])?;
+    let updated_catalog = insert_toml_entries(&updated_catalog, "libraries", &library_entries, &["bundles", "plugins"])?;
+    let updated_catalog = insert_toml_entries(&updated_catalog, "bundles", &bundle_entries, &["plugins"])?;
+    if updated_catalog != catalog_content {
+        changes.push(FileChange {
+            path: catalog_path.clone(),
+            original: catalog_content,
+            updated: updated_catalog,
+        });
+    }
+
+    for (module, rewrites) in line_rewrites {
+        let original = fs::read_to_string(&module)?;
+        let mut updated = original.lines()
+            .enumerate()
+            .filter_map(|(index, line)| match rewrites.get(&(index + 1)) {
+                Some(replacement) => replacement.clone(),
+                None => Some(line.to_string()),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        if original.ends_with('\n') {
+            updated.push('\n');
+        }
+        changes.push(FileChange { path: module, original, updated });
+    }
+
+    Ok(CatalogBundlePlan {
+        catalog_path,
+        version_entries,
+        library_entries,
+        bundle_entries,
+        bundles: suggestions,
+        skipped_bundles,
+        skipped_modules,
+        changes,
+    })
+}
+
+/// One bundle per configuration the members are declared with, since a `libs.bundles.x` declaration
+/// has a single configuration; members alone in their configuration keep their own declaration
+fn split_by_configuration(bundle: &DependencyBundle) -> Vec<DependencyBundle> {
+    let mut groups: BTreeMap<&str, Vec<&BundleMember>> = BTreeMap::new();
+    for member in &bundle.members {
+        groups.entry(member.configuration.as_str()).or_default().push(member);
+    }
+    if groups.len() == 1 {
+        return vec![bundle.clone()];
+    }
+
+    groups
+        .into_values()
+        .filter(|members| members.len() > 1)
+        .map(|members| DependencyBundle {
+            dependencies: members.iter().map(|member| member.dependency.clone()).collect(),
+            configurations: members.iter().flat_map(|member| member.declared_configurations.iter().cloned()).collect(),
+            bundle_size: members.len(),
+            members: members.into_iter().cloned().collect(),
+            modules: bundle.modules.clone(),
+            module_count: bundle.module_count,
+            priority_score: bundle.priority_score,
+        })
+        .collect()
+}
+
+/// Declarations of every root build module a bundle touches; modules of other builds are left out
+pub fn load_module_declarations(root_path: &Path, bundles: &[DependencyBundle]) -> Result<HashMap<PathBuf, Vec<DependencyLocation>>> {
+    let version_catalogs = load_version_catalogs(root_path)?;
+    let root_build_files: HashSet<PathBuf> = find_root_build_files(root_path)?.into_iter().collect();
+    let mut module_declarations = HashMap::new();
+
+    for module in bundles.iter().flat_map(|bundle| &bundle.modules) {
+        if root_build_files.contains(module) && !module_declarations.contains_key(module) {
+            module_declarations.insert(module.clone(), parse_dependencies_from_file(module, &version_catalogs)?);
+        }
+    }
+
+    Ok(module_declarations)
+}
+
+/// Maps each member to a catalog library; members must be declared at one version across the bundle's modules
+fn resolve_members(
+    bundle: &DependencyBundle,
+    declarations: &[&DependencyLocation],
+    existing_libraries: &HashMap<Coordinate, (String, Option<String>)>,
+    created_libraries: &HashMap<Coordinate, (String, Option<String>)>,
+) -> std::result::Result<Vec<MemberAlias>, String> {
+    let mut member_aliases = Vec::new();
+
+    for member in &bundle.members {
+        let key = &member.dependency;
+        let member_declarations: Vec<&&DependencyLocation> = declarations.iter()
+            .filter(|declaration| declaration.dependency.key() == *key && member.declared_configurations.contains(&declaration.configuration))
+            .collect();
+        if let Some(reason) = member_declarations.iter().find_map(|declaration| unsupported_reason(declaration)) {
+            return Err(format!("{}: {}", key, reason));
+        }
+        let coordinate = match key.split(':').collect::<Vec<_>>().as_slice() {
+            [group, artifact] => (group.to_string(), artifact.to_string()),
+            _ => return Err(format!("{}: classifier cannot be expressed by a catalog accessor", key)),
+        };
+
+        let mut versions: Vec<Option<&String>> = member_declarations.iter().map(|declaration| declaration.dependency.version.as_ref()).collect();
+        versions.sort();
+        versions.dedup();
+        if versions.len() > 1 {
+            let declared: Vec<&str> = versions.iter().map(|version| version.map_or("none", |version| version.as_str())).collect();
+            return Err(format!("{} is declared with different versions ({}); resolve the conflict first", key, declared.join(", ")));
+        }
+        let version = versions.first().copied().flatten().cloned();
+
+        match existing_libraries.get(&coordinate).or_else(|| created_libraries.get(&coordinate)) {
+            // Members only inherited from elsewhere take whatever the catalog declares
+            Some((alias, _)) if member_declarations.is_empty() => member_aliases.push(MemberAlias::Existing(alias.clone())),
+            Some((alias, catalog_version)) if *catalog_version == version => member_aliases.push(MemberAlias::Existing(alias.clone())),
+            Some((alias, catalog_version)) => {
+                return Err(format!(
+                    "catalog entry `{}` declares version {} but modules use {}",
+                    alias,
+                    catalog_version.as_deref().unwrap_or("none"),
+                    version.as_deref().unwrap_or("none")
+                ));
+            }
+            None => member_aliases.push(MemberAlias::New { coordinate, version }),
+        }
+    }
+
+    Ok(member_aliases)
+}
+
+/// Lines declaring the bundle's members in a module, which must all use one configuration
+fn plan_module_rewrite(
+    bundle: &DependencyBundle,
+    module: &Path,
+    declarations: &[DependencyLocation],
+    claimed_lines: &HashSet<(PathBuf, usize)>,
+    declaration_regex: &Regex,
+) -> std::result::Result<ModuleRewrite, String> {
+    let content = fs::read_to_string(module).map_err(|e| e.to_string())?;
+    let lines: Vec<&str> = content.lines().collect();
+    // Indentation, configuration and call style of each member line
+    let mut declaration_styles: Vec<(usize, String, String, bool)> = Vec::new();
+
+    for member in &bundle.members {
+        let key = &member.dependency;
+        let member_declarations: Vec<&DependencyLocation> = declarations.iter()
+            .filter(|declaration| declaration.dependency.key() == *key && member.declared_configurations.contains(&declaration.configuration))
+            .collect();
+        let declaration = match member_declarations.as_slice() {
+            [declaration] => *declaration,
+            [] => return Err(format!("{} is not declared in this build file", key)),
+            _ => return Err(format!("{} is declared {} times", key, member_declarations.len())),
+        };
+        if let DependencySourceType::CatalogBundle(reference) = &declaration.source_type {
+            return Err(format!("{} is already provided by {}", key, reference));
+        }
+
+        let line_number = declaration.line_number;
+        if claimed_lines.contains(&(module.to_path_buf(), line_number)) {
+            return Err(format!("{} was already replaced by another bundle", key));
+        }
+        if declarations.iter().filter(|other| other.line_number == line_number).count() > 1 {
+            return Err(format!("{} shares its line with other declarations", key));
+        }
+        let line = lines.get(line_number - 1).copied().unwrap_or_default();
+        if line.contains('{') {
+            return Err(format!("{} has a configuration block", key));
+        }
+
+        let Some(captures) = declaration_regex.captures(line) else {
+            return Err(format!("{} could not be located on its line", key));
+        };
+        if let Some((_, _, configuration, _)) = declaration_styles.first() {
+            if *configuration != captures[2] {
+                return Err(format!("members use different configurations ({}, {})", configuration, &captures[2]));
+            }
+        }
+        declaration_styles.push((line_number, captures[1].to_string(), captures[2].to_string(), captures.get(3).is_some()));
+    }
+
+    // The bundle takes the place and style of the first member line
+    declaration_styles.sort();
+    let member_lines = declaration_styles.iter().map(|(line_number, ..)| *line_number).collect();
+    let (_, indent, configuration, parenthesized) = declaration_styles.into_iter().next().ok_or_else(|| "bundle has no members".to_string())?;
+    Ok(ModuleRewrite { member_lines, indent, configuration, parenthesized })
+}
\ No newline at end of file
@@ -0,0 +1,352 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::bundle_analyzer::{generate_bundle_name, DependencyBundle};
+use crate::bundle_catalog::{load_module_declarations, SkippedBundle};
+use crate::catalog_migration::{sanitize_alias, unsupported_reason};
+use crate::config::{file_patterns, BundleNamePatterns};
+use crate::error::Result;
+use crate::parser::{load_version_catalogs, precompiled_script_plugin_name, DependencyLocation, DependencySourceType};
+use crate::rewrite::FileChange;
+use crate::settings::{load_build_settings, resolve_settings_path};
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Suffix of generated plugin ids, e.g. `androidx-dependencies`
+const PLUGIN_ID_SUFFIX: &str = "-dependencies";
+
+/// A convention plugin is only generated when at least this many modules can apply it
+const MIN_PLUGIN_MODULES: usize = 2;
+
+/// A module that should apply a generated convention plugin
+#[derive(Debug, Clone)]
+pub struct ConventionPluginModule {
+    pub build_file: PathBuf,
+    /// Member declarations the plugin makes redundant
+    pub removable_lines: Vec<usize>,
+}
+
+/// A recommended bundle expressed as a precompiled script plugin
+#[derive(Debug, Clone)]
+pub struct ConventionPluginSuggestion {
+    pub plugin_id: String,
+    pub path: PathBuf,
+    pub dependencies: Vec<String>,
+    pub modules: Vec<ConventionPluginModule>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConventionPluginPlan {
+    pub build_logic_path: PathBuf,
+    pub plugins: Vec<ConventionPluginSuggestion>,
+    pub skipped_bundles: Vec<SkippedBundle>,
+    /// Generated plugins, then the build-logic scaffolding when it does not exist yet
+    pub changes: Vec<FileChange>,
+    /// Whether the root settings still need `includeBuild("build-logic")`
+    pub include_build_missing: bool,
+}
+
+/// How a member is written in the generated plugin
+enum MemberNotation {
+    /// `libs.findLibrary("alias").get()` on the named catalog
+    CatalogLookup { accessor: String, alias: String },
+    Coordinate(String),
+}
+
+struct PluginMember {
+    configuration: String,
+    notation: MemberNotation,
+}
+
+/// Plans a precompiled script plugin in `build-logic/convention` for each recommended bundle
+pub fn plan_convention_plugins(root_path: &Path, bundles: &[DependencyBundle]) -> Result<ConventionPluginPlan> {
+    let build_logic_path = root_path.join(file_patterns::BUILD_LOGIC_DIR);
+    let convention_path = build_logic_path.join(file_patterns::CONVENTION_PROJECT);
+    let source_path = convention_path.join(file_patterns::KOTLIN_SOURCE_DIR);
+    let module_declarations = load_module_declarations(root_path, bundles)?;
+
+    // Generated ids must not clash with the plugins build-logic already has
+    let mut taken_ids: HashSet<String> = HashSet::new();
+    if source_path.is_dir() {
+        for entry in fs::read_dir(&source_path)? {
+            if let Some(name) = precompiled_script_plugin_name(&entry?.path()) {
+                taken_ids.insert(name);
+            }
+        }
+    }
+
+    let bundle_name_patterns = BundleNamePatterns::default();
+    let mut plugins = Vec::new();
+    let mut skipped_bundles = Vec::new();
+    let mut changes = Vec::new();
+    let mut used_accessors: HashSet<String> = HashSet::new();
+    // Plugin providing each (module, member) pair, so no two plugins add the same dependency to a module
+    let mut providers: HashMap<(PathBuf, String), String> = HashMap::new();
+
+    // Higher-priority bundles claim their modules first; later overlapping bundles lose those modules
+    let mut prioritized: Vec<&DependencyBundle> = bundles.iter().collect();
+    prioritized.sort_by(|a, b| b.priority_score.partial_cmp(&a.priority_score).unwrap_or(Ordering::Equal));
+
+    for bundle in prioritized {
+        let mut overlapping_plugins = BTreeSet::new();
+        let free_modules: Vec<PathBuf> = bundle.modules.iter()
+            .filter(|module| {
+                let module_providers: Vec<&String> = bundle.members.iter()
+                    .filter_map(|member| providers.get(&((*module).clone(), member.dependency.clone())))
+                    .collect();
+                overlapping_plugins.extend(module_providers.iter().map(|plugin_id| plugin_id.to_string()));
+                module_providers.is_empty()
+            })
+            .cloned()
+            .collect();
+        if free_modules.len() < MIN_PLUGIN_MODULES {
+            let plugin_ids: Vec<String> = overlapping_plugins.into_iter().map(|plugin_id| format!("id(\"{}\")", plugin_id)).collect();
+            skipped_bundles.push(SkippedBundle {
+                dependencies: bundle.dependencies.clone(),
+                reason: format!("its modules already get members from {}", plugin_ids.join(", ")),
+            });
+            continue;
+        }
+        let bundle = &DependencyBundle { module_count: free_modules.len(), modules: free_modules, ..bundle.clone() };
+
+        let declarations: Vec<&DependencyLocation> = bundle.modules.iter()
+            .filter_map(|module| module_declarations.get(module))
+            .flatten()
+            .collect();
+        let members = match resolve_plugin_members(bundle, &declarations) {
+            Ok(members) => members,
+            Err(reason) => {
+                skipped_bundles.push(SkippedBundle { dependencies: bundle.dependencies.clone(), reason });
+                continue;
+            }
+        };
+
+        let bundle_name = generate_bundle_name(&bundle.dependencies, &bundle_name_patterns);
+        let base_id = format!("{}{}", sanitize_alias(bundle_name.trim_end_matches("-bundle")), PLUGIN_ID_SUFFIX);
+        let plugin_id = (1..)
+            .map(|index| if index == 1 { base_id.clone() } else { format!("{}{}", base_id, index) })
+            .find(|id| !taken_ids.contains(id))
+            .unwrap_or(base_id);
+        taken_ids.insert(plugin_id.clone());
+
+        let mut modules = Vec::new();
+        for module in &bundle.modules {
+            let Some(module_declarations) = module_declarations.get(module) else {
+                continue;
+            };
+            let mut removable_lines: Vec<usize> = module_declarations.iter()
+                .filter(|declaration| is_rewritable_source(&declaration.source_type))
+                .filter(|declaration| bundle.members.iter().any(|member| {
+                    member.dependency == declaration.dependency.key() && member.declared_configurations.contains(&declaration.configuration)
+                }))
+                .map(|declaration| declaration.line_number)
+                .collect();
+            removable_lines.sort();
+            removable_lines.dedup();
+            modules.push(ConventionPluginModule { build_file: module.clone(), removable_lines });
+        }
+        modules.sort_by(|a, b| a.build_file.cmp(&b.build_file));
+        for module in &modules {
+            for member in &bundle.members {
+                providers.insert((module.build_file.clone(), member.dependency.clone()), plugin_id.clone());
+            }
+        }
+
+        for member in &members {
+            if let MemberNotation::CatalogLookup { accessor, .. } = &member.notation {
+                used_accessors.insert(accessor.clone());
+            }
+        }
+
+        let path = source_path.join(format!("{}.gradle.kts", plugin_id));
+        changes.push(FileChange {
+            path: path.clone(),
+            original: String::new(),
+            updated: render_plugin(root_path, &plugin_id, &members, &modules),
+        });
+        plugins.push(ConventionPluginSuggestion {
+            plugin_id,
+            path,
+            dependencies: bundle.dependencies.clone(),
+            modules,
+        });
+    }
+
+    if !plugins.is_empty() {
+        changes.extend(plan_build_logic_scaffold(root_path, &build_logic_path, &convention_path, &used_accessors)?);
+    }
+
+    let settings = load_build_settings(root_path)?;
+    let include_build_missing = !plugins.is_empty()
+        && !settings.included_builds.iter().any(|path| resolve_settings_path(root_path, path) == build_logic_path);
+
+    Ok(ConventionPluginPlan {
+        build_logic_path,
+        plugins,
+        skipped_bundles,
+        changes,
+        include_build_missing,
+    })
+}
+
+/// Declarations the module writes itself, as opposed to ones it inherits or gets from a bundle
+fn is_rewritable_source(source_type: &DependencySourceType) -> bool {
+    matches!(source_type, DependencySourceType::Direct | DependencySourceType::VersionCatalog(_))
+}
+
+/// Each member must be declared at one version across the bundle's modules and is added with the configuration the bundle recommends
+fn resolve_plugin_members(bundle: &DependencyBundle, declarations: &[&DependencyLocation]) -> std::result::Result<Vec<PluginMember>, String> {
+    let mut members = Vec::new();
+
+    for member in &bundle.members {
+        let key = &member.dependency;
+        let member_declarations: Vec<&&DependencyLocation> = declarations.iter()
+            .filter(|declaration| declaration.dependency.key() == *key && member.declared_configurations.contains(&declaration.configuration))
+            .filter(|declaration| is_rewritable_source(&declaration.source_type))
+            .collect();
+        let Some(first) = member_declarations.first() else {
+            return Err(format!("{} is not declared in the modules' build files", key));
+        };
+        if let Some(reason) = member_declarations.iter().find_map(|declaration| unsupported_reason(declaration)) {
+            return Err(format!("{}: {}", key, reason));
+        }
+        if first.dependency.classifier.is_some() {
+            return Err(format!("{}: classifier cannot be expressed by a plain dependency notation", key));
+        }
+
+        // Source set configurations are tagged implementation-commonMain and live in sourceSets blocks
+        if member.configuration.contains('-') {
+            return Err(format!("{} is declared in a source set ({})", key, member.configuration));
+        }
+
+        let versions: HashSet<Option<&String>> = member_declarations.iter().map(|declaration| declaration.dependency.version.as_ref()).collect();
+        if versions.len() > 1 {
+            let mut declared: Vec<&str> = versions.iter().map(|version| version.map_or("none", |version| version.as_str())).collect();
+            declared.sort();
+            return Err(format!("{} is declared with different versions ({}); resolve the conflict first", key, declared.join(", ")));
+        }
+
+        // Reuse the catalog accessor when every module agrees on it
+        let references: HashSet<Option<&String>> = member_declarations.iter()
+            .map(|declaration| match &declaration.source_type {
+                DependencySourceType::VersionCatalog(reference) => Some(reference),
+                _ => None,
+            })
+            .collect();
+        let notation = match references.into_iter().collect::<Vec<_>>().as_slice() {
+            [Some(reference)] if reference.contains('.') => {
+                let (accessor, alias) = reference.split_once('.').unwrap_or_default();
+                MemberNotation::CatalogLookup { accessor: accessor.to_string(), alias: alias.replace('.', "-") }
+            }
+            _ => {
+                let dependency = &first.dependency;
+                MemberNotation::Coordinate(match &dependency.version {
+                    Some(version) => format!("{}:{}:{}", dependency.group, dependency.artifact, version),
+                    None => format!("{}:{}", dependency.group, dependency.artifact),
+                })
+            }
+        };
+
+        members.push(PluginMember { configuration: member.configuration.clone(), notation });
+    }
+
+    Ok(members)
+}
+
+fn render_plugin(root_path: &Path, plugin_id: &str, members: &[PluginMember], modules: &[ConventionPluginModule]) -> String {
+    let accessors: BTreeSet<&str> = members.iter()
+        .filter_map(|member| match &member.notation {
+            MemberNotation::CatalogLookup { accessor, .. } => Some(accessor.as_str()),
+            MemberNotation::Coordinate(_) => None,
+        })
+        .collect();
+    let module_names: Vec<String> = modules.iter()
+        .map(|module| {
+            let module_dir = module.build_file.parent().unwrap_or(root_path);
+            module_dir.strip_prefix(root_path).unwrap_or(module_dir).display().to_string()
+        })
+        .collect();
+
+    let mut content = String::new();
+    if !accessors.is_empty() {
+        content.push_str("import org.gradle.api.artifacts.VersionCatalogsExtension\n\n");
+    }
+    content.push_str(&format!("// Dependencies shared by {}\n", module_names.join(", ")));
+    content.push_str(&format!("// Apply with id(\"{}\") in place of the declarations it provides\n\n", plugin_id));
+    for accessor in &accessors {
+        content.push_str(&format!("val {} = the<VersionCatalogsExtension>().named(\"{}\")\n", accessor, accessor));
+    }
+    if !accessors.is_empty() {
+        content.push('\n');
+    }
+
+    content.push_str("dependencies {\n");
+    for member in members {
+        let notation = match &member.notation {
+            MemberNotation::CatalogLookup { accessor, alias } => format!("{}.findLibrary(\"{}\").get()", accessor, alias),
+            MemberNotation::Coordinate(coordinate) => format!("\"{}\"", coordinate),
+        };
+        content.push_str(&format!("    add(\"{}\", {})\n", member.configuration, notation));
+    }
+    content.push_str("}\n");
+    content
+}
+
+/// Settings and build file of the build-logic build when they are missing
+fn plan_build_logic_scaffold(
+    root_path: &Path,
+    build_logic_path: &Path,
+    convention_path: &Path,
+    used_accessors: &HashSet<String>,
+) -> Result<Vec<FileChange>> {
+    let mut changes = Vec::new();
+    let has_file = |dir: &Path, names: &[&str]| names.iter().any(|name| dir.join(name).is_file());
+
+    if !has_file(build_logic_path, file_patterns::GRADLE_SETTINGS_FILES) {
+        // Catalog lookups need the catalogs declared in the build-logic build too
+        let mut catalog_declarations: Vec<(String, String)> = load_version_catalogs(root_path)?
+            .into_iter()
+            .flat_map(|(path, catalog)| {
+                let relative = path.strip_prefix(root_path).map(|path| Path::new("..").join(path)).unwrap_or(path);
+                let relative = relative.display().to_string().replace('\\', "/");
+                catalog.accessors
+                    .into_iter()
+                    .filter(|accessor| used_accessors.contains(accessor))
+                    .map(move |accessor| (accessor, relative.clone()))
+            })
+            .collect();
+        catalog_declarations.sort();
+        catalog_declarations.dedup_by(|a, b| a.0 == b.0);
+
+        let mut settings = String::from("dependencyResolutionManagement {\n    repositories {\n        google()\n        mavenCentral()\n    }\n");
+        if !catalog_declarations.is_empty() {
+            settings.push_str("    versionCatalogs {\n");
+            for (accessor, path) in &catalog_declarations {
+                settings.push_str(&format!("        create(\"{}\") {{\n            from(files(\"{}\"))\n        }}\n", accessor, path));
+            }
+            settings.push_str("    }\n");
+        }
+        settings.push_str(&format!(
+            "}}\n\nrootProject.name = \"{}\"\ninclude(\":{}\")\n",
+            file_patterns::BUILD_LOGIC_DIR,
+            file_patterns::CONVENTION_PROJECT
+        ));
+        changes.push(FileChange { path: build_logic_path.join("settings.gradle.kts"), original: String::new(), updated: settings });
+    }
+
+    if !has_file(convention_path, file_patterns::GRADLE_BUILD_FILES) {
+        changes.push(FileChange {
+            path: convention_path.join("build.gradle.kts"),
+            original: String::new(),
+            updated: "plugins {\n    `kotlin-dsl`\n}\n".to_string(),
+        });
+    }
+
+    Ok(changes)
+}
\ No newline at end of file
@@ -0,0 +1,395 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::config::{file_patterns, regex_patterns};
+use crate::error::Result;
+use crate::findings::{FindingReporter, FindingsByFile};
+use crate::parser::{precompiled_script_plugin_name, DependencyLocation, DependencySourceType};
+use crate::settings::walk_build;
+use crate::version_catalog::{catalog_entry_lines, find_alias, normalize_alias, VersionCatalog};
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Provider methods that can follow a catalog accessor, e.g. `libs.versions.kotlin.get()`
+const CATALOG_PROVIDER_METHODS: &[&str] = &["get", "asProvider", "orNull", "getOrNull", "getOrElse", "map", "flatMap", "isPresent", "forUseAtConfigurationTime", "toString"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum CatalogFindingKind {
+    UnusedLibrary,
+    UnusedPlugin,
+    UnusedBundle,
+    UnusedVersion,
+    UndefinedVersionReference,
+    UndefinedBundleMember,
+    DuplicateCoordinate,
+}
+
+/// A dependency declared with string coordinates although the catalog already provides it
+#[derive(Debug, Clone, Serialize)]
+pub struct HardcodedCatalogDependency {
+    #[serde(flatten)]
+    pub location: DependencyLocation,
+    /// Catalog accessor to use instead, e.g. `libs.ktor.client.core`
+    pub catalog_reference: String,
+    pub catalog_version: Option<String>,
+    pub version_differs: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CatalogAnalysis {
+    /// About the alias of the offending entry in its TOML section
+    pub findings: FindingsByFile<CatalogFindingKind, String>,
+    pub hardcoded_dependencies: HashMap<String, Vec<HardcodedCatalogDependency>>,
+}
+
+/// Normalized aliases build files reference from one catalog
+#[derive(Debug, Default)]
+pub struct CatalogUsage {
+    libraries: HashSet<String>,
+    plugins: HashSet<String>,
+    bundles: HashSet<String>,
+    versions: HashSet<String>,
+}
+
+impl CatalogUsage {
+    pub fn merge(&mut self, other: &CatalogUsage) {
+        self.libraries.extend(other.libraries.iter().cloned());
+        self.plugins.extend(other.plugins.iter().cloned());
+        self.bundles.extend(other.bundles.iter().cloned());
+        self.versions.extend(other.versions.iter().cloned());
+    }
+
+    fn record(&mut self, reference: &str) {
+        let mut segments: Vec<&str> = reference.split('.').collect();
+        while segments.len() > 1 && CATALOG_PROVIDER_METHODS.contains(segments.last().unwrap()) {
+            segments.pop();
+        }
+        
+        let (entries, alias) = match segments.as_slice() {
+            ["plugins", rest @ ..] if !rest.is_empty() => (&mut self.plugins, rest.join(".")),
+            ["bundles", rest @ ..] if !rest.is_empty() => (&mut self.bundles, rest.join(".")),
+            ["versions", rest @ ..] if !rest.is_empty() => (&mut self.versions, rest.join(".")),
+            _ => (&mut self.libraries, segments.join(".")),
+        };
+        entries.insert(normalize_alias(&alias));
+    }
+
+    fn record_lookup(&mut self, kind: &str, alias: &str) {
+        let entries = match kind {
+            "Plugin" => &mut self.plugins,
+            "Bundle" => &mut self.bundles,
+            "Version" => &mut self.versions,
+            _ => &mut self.libraries,
+        };
+        entries.insert(normalize_alias(alias));
+    }
+}
+
+/// References the build files of the build rooted at `root_path` make to each of its catalogs, through any of their accessors
+pub fn find_catalog_usage(
+    root_path: &Path,
+    version_catalogs: &HashMap<PathBuf, VersionCatalog>,
+) -> Result<HashMap<PathBuf, CatalogUsage>> {
+    let accessors: HashSet<&str> = version_catalogs
+        .values()
+        .flat_map(|catalog| catalog.accessors.iter().map(String::as_str))
+        .collect();
+    let accessor_usage = collect_catalog_usage(root_path, &accessors)?;
+    let mut usage = HashMap::new();
+    
+    for (catalog_path, catalog) in version_catalogs {
+        let mut catalog_usage = CatalogUsage::default();
+        for accessor in &catalog.accessors {
+            if let Some(references) = accessor_usage.get(accessor) {
+                catalog_usage.merge(references);
+            }
+        }
+        usage.insert(catalog_path.clone(), catalog_usage);
+    }
+    
+    Ok(usage)
+}
+
+/// Lints every version catalog against the references of every build that resolves it
+pub fn analyze_catalogs(
+    version_catalogs: &HashMap<PathBuf, VersionCatalog>,
+    usage: &HashMap<PathBuf, CatalogUsage>,
+) -> Result<FindingsByFile<CatalogFindingKind, String>> {
+    let empty_usage = CatalogUsage::default();
+    let mut findings = FindingReporter::new();
+    
+    for (catalog_path, catalog) in version_catalogs {
+        let content = fs::read_to_string(catalog_path)?;
+        let entry_lines = catalog_entry_lines(&content)?;
+        let catalog_usage = usage.get(catalog_path).unwrap_or(&empty_usage);
+        
+        lint_catalog(catalog_path, catalog, catalog_usage, &entry_lines, &mut findings);
+    }
+    
+    Ok(findings.finish())
+}
+
+/// Finds `Direct` dependencies whose group:artifact is declared in one of the build's catalogs
+pub fn find_hardcoded_catalog_dependencies(
+    dependencies: &[DependencyLocation],
+    version_catalogs: &HashMap<PathBuf, VersionCatalog>,
+) -> HashMap<String, Vec<HardcodedCatalogDependency>> {
+    let catalog_coordinates = catalog_coordinates(version_catalogs);
+    let mut hardcoded: HashMap<String, Vec<HardcodedCatalogDependency>> = HashMap::new();
+    
+    for location in dependencies {
+        if !matches!(location.source_type, DependencySourceType::Direct) {
+            continue;
+        }
+        let coordinate = (location.dependency.group.clone(), location.dependency.artifact.clone());
+        let (catalog_reference, catalog_version) = match catalog_coordinates.get(&coordinate) {
+            Some(entry) => entry,
+            None => continue,
+        };
+        
+        let version_differs = match (&location.dependency.version, catalog_version) {
+            (Some(version), Some(catalog_version)) => version != catalog_version,
+            _ => false,
+        };
+        hardcoded.entry(location.dependency.key()).or_default().push(HardcodedCatalogDependency {
+            location: location.clone(),
+            catalog_reference: catalog_reference.clone(),
+            catalog_version: catalog_version.clone(),
+            version_differs,
+        });
+    }
+    
+    hardcoded
+}
+
+/// Catalog reference and version of every library coordinate; the first alias in path and alias order wins
+fn catalog_coordinates(version_catalogs: &HashMap<PathBuf, VersionCatalog>) -> HashMap<(String, String), (String, Option<String>)> {
+    let mut catalogs: Vec<(&PathBuf, &VersionCatalog)> = version_catalogs.iter().collect();
+    catalogs.sort_by_key(|(path, _)| *path);
+    let mut coordinates = HashMap::new();
+    
+    for (_, catalog) in catalogs {
+        let libraries = match &catalog.libraries {
+            Some(libraries) => libraries,
+            None => continue,
+        };
+        let mut aliases: Vec<&String> = libraries.keys().collect();
+        aliases.sort();
+        
+        for alias in aliases {
+            if let Some(coordinate) = libraries[alias].coordinate() {
+                let version = catalog.resolve_library_version(alias).map(|(_, _, version)| version);
+                coordinates
+                    .entry(coordinate)
+                    .or_insert_with(|| (format!("{}.{}", catalog.primary_accessor(), normalize_alias(alias)), version));
+            }
+        }
+    }
+    
+    coordinates
+}
+
+fn lint_catalog(
+    catalog_path: &Path,
+    catalog: &VersionCatalog,
+    usage: &CatalogUsage,
+    entry_lines: &HashMap<(String, String), usize>,
+    findings: &mut FindingReporter<CatalogFindingKind, String>,
+) {
+    let empty_versions = HashMap::new();
+    let versions = catalog.versions.as_ref().unwrap_or(&empty_versions);
+    let line_of = |section: &str, alias: &str| entry_lines.get(&(section.to_string(), alias.to_string())).copied().unwrap_or(0);
+    let mut referenced_versions: HashSet<&str> = HashSet::new();
+    let mut bundled_libraries: HashSet<String> = HashSet::new();
+    
+    if let Some(bundles) = &catalog.bundles {
+        for (alias, members) in bundles {
+            let bundle_used = usage.bundles.contains(&normalize_alias(alias));
+            if !bundle_used {
+                findings.report(
+                    catalog_path,
+                    line_of("bundles", alias),
+                    CatalogFindingKind::UnusedBundle,
+                    alias.clone(),
+                    format!("bundle `{}` is not referenced from any build file", alias),
+                );
+            }
+            
+            for member in members {
+                let defined = catalog.libraries.as_ref().and_then(|libraries| find_alias(libraries, member)).is_some();
+                if !defined {
+                    findings.report(
+                        catalog_path,
+                        line_of("bundles", alias),
+                        CatalogFindingKind::UndefinedBundleMember,
+                        alias.clone(),
+                        format!("bundle `{}` references `{}`, which is not declared in [libraries]", alias, member),
+                    );
+                } else if bundle_used {
+                    bundled_libraries.insert(normalize_alias(member));
+                }
+            }
+        }
+    }
+    
+    if let Some(libraries) = &catalog.libraries {
+        let mut coordinates: HashMap<(String, String), Vec<&String>> = HashMap::new();
+        
+        for (alias, library) in libraries {
+            let normalized = normalize_alias(alias);
+            if !usage.libraries.contains(&normalized) && !bundled_libraries.contains(&normalized) {
+                findings.report(
+                    catalog_path,
+                    line_of("libraries", alias),
+                    CatalogFindingKind::UnusedLibrary,
+                    alias.clone(),
+                    format!("library `{}` is not referenced from any build file", alias),
+                );
+            }
+            
+            if let Some(reference) = library.version_reference() {
+                referenced_versions.insert(reference);
+                if !versions.contains_key(reference) {
+                    findings.report(
+                        catalog_path,
+                        line_of("libraries", alias),
+                        CatalogFindingKind::UndefinedVersionReference,
+                        alias.clone(),
+                        format!("library `{}` refers to version `{}`, which is not declared in [versions]", alias, reference),
+                    );
+                }
+            }
+            
+            if let Some(coordinate) = library.coordinate() {
+                coordinates.entry(coordinate).or_default().push(alias);
+            }
+        }
+        
+        for ((group, artifact), mut aliases) in coordinates {
+            if aliases.len() <= 1 {
+                continue;
+            }
+            aliases.sort_by_key(|alias| (line_of("libraries", alias), alias.to_string()));
+            let first = aliases[0];
+            for alias in &aliases[1..] {
+                findings.report(
+                    catalog_path,
+                    line_of("libraries", alias),
+                    CatalogFindingKind::DuplicateCoordinate,
+                    alias.to_string(),
+                    format!("{}:{} is already declared as `{}`", group, artifact, first),
+                );
+            }
+        }
+    }
+    
+    if let Some(plugins) = &catalog.plugins {
+        for (alias, plugin) in plugins {
+            if !usage.plugins.contains(&normalize_alias(alias)) {
+                findings.report(
+                    catalog_path,
+                    line_of("plugins", alias),
+                    CatalogFindingKind::UnusedPlugin,
+                    alias.clone(),
+                    format!("plugin `{}` is not referenced from any build file", alias),
+                );
+            }
+            
+            if let Some(reference) = plugin.version_reference() {
+                referenced_versions.insert(reference);
+                if !versions.contains_key(reference) {
+                    findings.report(
+                        catalog_path,
+                        line_of("plugins", alias),
+                        CatalogFindingKind::UndefinedVersionReference,
+                        alias.clone(),
+                        format!("plugin `{}` refers to version `{}`, which is not declared in [versions]", alias, reference),
+                    );
+                }
+            }
+        }
+    }
+    
+    for alias in versions.keys() {
+        if !referenced_versions.contains(alias.as_str()) && !usage.versions.contains(&normalize_alias(alias)) {
+            findings.report(
+                catalog_path,
+                line_of("versions", alias),
+                CatalogFindingKind::UnusedVersion,
+                alias.clone(),
+                format!("version `{}` is not used by any library, plugin or build file", alias),
+            );
+        }
+    }
+}
+
+/// Collects catalog references (`libs.xxx`, `libs.findLibrary("xxx")`) per accessor
+fn collect_catalog_usage(root_path: &Path, accessors: &HashSet<&str>) -> Result<HashMap<String, CatalogUsage>> {
+    let reference_regex = Regex::new(regex_patterns::CATALOG_REFERENCE)?;
+    let lookup_regex = Regex::new(regex_patterns::CATALOG_LOOKUP)?;
+    let mut usage: HashMap<String, CatalogUsage> = HashMap::new();
+    
+    for consumer_file in find_catalog_consumer_files(root_path)? {
+        let content = fs::read_to_string(&consumer_file)?;
+        
+        for captures in reference_regex.captures_iter(&content) {
+            if accessors.contains(&captures[1]) {
+                usage.entry(captures[1].to_string()).or_default().record(&captures[2]);
+            }
+        }
+        
+        // Lookups usually go through a local variable, so an unknown receiver counts for every catalog
+        for captures in lookup_regex.captures_iter(&content) {
+            let receivers: Vec<&str> = if accessors.contains(&captures[1]) {
+                vec![&captures[1]]
+            } else {
+                accessors.iter().copied().collect()
+            };
+            for receiver in receivers {
+                usage.entry(receiver.to_string()).or_default().record_lookup(&captures[2], &captures[3]);
+            }
+        }
+    }
+    
+    Ok(usage)
+}
+
+/// Build and settings scripts, precompiled script plugins and Kotlin convention plugin sources
+fn find_catalog_consumer_files(root_path: &Path) -> Result<Vec<PathBuf>> {
+    let mut consumer_files = Vec::new();
+    
+    for entry in walk_build(root_path)? {
+        let entry = entry?;
+        let path = entry.path();
+        
+        if !path.is_file() {
+            continue;
+        }
+        let filename = match path.file_name().and_then(|n| n.to_str()) {
+            Some(filename) => filename,
+            None => continue,
+        };
+        
+        let is_build_script = file_patterns::GRADLE_BUILD_FILES.contains(&filename)
+            || file_patterns::GRADLE_SETTINGS_FILES.contains(&filename);
+        let path_str = path.to_string_lossy().replace('\\', "/");
+        let in_source_dir = file_patterns::PRECOMPILED_SCRIPT_DIRS
+            .iter()
+            .any(|dir| path_str.contains(&format!("/{}/", dir)));
+        let is_convention_source = in_source_dir
+            && (precompiled_script_plugin_name(path).is_some() || filename.ends_with(file_patterns::KOTLIN_SOURCE_EXTENSION));
+        
+        if is_build_script || is_convention_source {
+            consumer_files.push(path.to_path_buf());
+        }
+    }
+    
+    Ok(consumer_files)
+}
\ No newline at end of file
@@ -0,0 +1,447 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::config::{file_patterns, regex_patterns};
+use crate::error::Result;
+use crate::parser::{
+    DependencyLocation, DependencySourceType, TEST_FIXTURES_CLASSIFIER,
+    find_gradle_files, load_version_catalogs, parse_dependencies_from_file,
+};
+use crate::rewrite::FileChange;
+use crate::settings::{load_build_settings, resolve_settings_path};
+use crate::version_catalog::{normalize_alias, parse_version_catalog, VersionCatalog};
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// group and artifact of a library
+type Coordinate = (String, String);
+
+/// Names Gradle rejects as the first segment of a catalog alias
+const RESERVED_ALIAS_SEGMENTS: &[&str] = &["bundles", "versions", "plugins", "extensions", "class", "convention"];
+
+#[derive(Debug, Clone)]
+pub struct MigratedDependency {
+    pub location: DependencyLocation,
+    /// Accessor the declaration was rewritten to, e.g. `libs.ktor.client.core`
+    pub catalog_reference: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct SkippedDependency {
+    pub location: DependencyLocation,
+    pub reason: String,
+}
+
+/// Planned move of hardcoded dependencies into the `libs` catalog
+#[derive(Debug, Clone)]
+pub struct CatalogMigration {
+    pub catalog_path: PathBuf,
+    /// The catalog first, then rewritten build files in path order
+    pub changes: Vec<FileChange>,
+    pub migrated: Vec<MigratedDependency>,
+    pub skipped: Vec<SkippedDependency>,
+}
+
+/// A hardcoded declaration together with the part of its line to replace by the accessor
+struct MigrationCandidate {
+    location: DependencyLocation,
+    replace_start: usize,
+    replace_end: usize,
+}
+
+/// Hands out catalog aliases that neither clash with nor nest under aliases already taken
+pub struct AliasAllocator {
+    taken: HashSet<String>,
+}
+
+impl AliasAllocator {
+    pub fn new<'a>(existing: impl IntoIterator<Item = &'a String>) -> Self {
+        Self { taken: existing.into_iter().map(|alias| normalize_alias(alias)).collect() }
+    }
+
+    pub fn is_available(&self, alias: &str) -> bool {
+        if is_reserved_alias(alias) {
+            return false;
+        }
+        let normalized = normalize_alias(alias);
+
+        // `okhttp` next to `okhttp-bom` would turn libs.okhttp into a group accessor
+        !self.taken.iter().any(|taken| {
+            *taken == normalized
+                || taken.starts_with(&format!("{}.", normalized))
+                || normalized.starts_with(&format!("{}.", taken))
+        })
+    }
+
+    /// Takes the first available candidate, or the last one with a numeric suffix
+    pub fn allocate(&mut self, candidates: &[String]) -> String {
+        let alias = candidates
+            .iter()
+            .find(|candidate| self.is_available(candidate))
+            .cloned()
+            .unwrap_or_else(|| {
+                let base = candidates.last().cloned().unwrap_or_else(|| "lib".to_string());
+                let base = if is_reserved_alias(&base) { format!("lib-{}", base) } else { base };
+                // A suffix without separator keeps the alias from nesting under the taken one
+                (2..)
+                    .map(|suffix| format!("{}{}", base, suffix))
+                    .find(|candidate| self.is_available(candidate))
+                    .unwrap_or(base)
+            });
+        self.taken.insert(normalize_alias(&alias));
+        alias
+    }
+}
+
+fn is_reserved_alias(alias: &str) -> bool {
+    let normalized = normalize_alias(alias);
+    RESERVED_ALIAS_SEGMENTS.contains(&normalized.split('.').next().unwrap_or_default())
+}
+
+/// Lowercase, dash-separated alias segment for a group or artifact name
+pub fn sanitize_alias(name: &str) -> String {
+    let mut alias = String::new();
+    for c in name.to_lowercase().chars() {
+        if c.is_ascii_alphanumeric() {
+            alias.push(c);
+        } else if !alias.is_empty() && !alias.ends_with('-') {
+            alias.push('-');
+        }
+    }
+    let alias = alias.trim_end_matches('-').to_string();
+
+    match alias.chars().next() {
+        None => "lib".to_string(),
+        Some(c) if c.is_ascii_digit() => format!("lib-{}", alias),
+        Some(_) => alias,
+    }
+}
+
+/// Alias candidates for a library, shortest first: `okhttp`, `squareup-okhttp`, `com-squareup-okhttp`
+pub fn library_alias_candidates(group: &str, artifact: &str) -> Vec<String> {
+    let artifact_alias = sanitize_alias(artifact);
+    let group_alias = sanitize_alias(group);
+    let last_group_segment = sanitize_alias(group.rsplit('.').next().unwrap_or(group));
+    let mut candidates = vec![artifact_alias.clone()];
+
+    if !artifact_alias.starts_with(&last_group_segment) {
+        candidates.push(format!("{}-{}", last_group_segment, artifact_alias));
+    }
+    candidates.push(format!("{}-{}", group_alias, artifact_alias));
+    candidates
+}
+
+/// Inserts lines at the end of a TOML table, creating the table before the first of `followed_by` when missing
+pub fn insert_toml_entries(content: &str, section: &str, entries: &[String], followed_by: &[&str]) -> Result<String> {
+    if entries.is_empty() {
+        return Ok(content.to_string());
+    }
+
+    let header_regex = Regex::new(regex_patterns::TOML_TABLE_HEADER)?;
+    let mut lines: Vec<String> = content.lines().map(|line| line.to_string()).collect();
+    let header_index = |lines: &[String], name: &str| {
+        lines.iter().position(|line| header_regex.captures(line).is_some_and(|captures| &captures[1] == name))
+    };
+
+    if let Some(header) = header_index(&lines, section) {
+        let mut end = lines
+            .iter()
+            .enumerate()
+            .skip(header + 1)
+            .find(|(_, line)| header_regex.is_match(line))
+            .map(|(index, _)| index)
+            .unwrap_or(lines.len());
+        while end > header + 1 && lines[end - 1].trim().is_empty() {
+            end -= 1;
+        }
+        lines.splice(end..end, entries.iter().cloned());
+    } else {
+        let mut table = vec![format!("[{}]", section)];
+        table.extend(entries.iter().cloned());
+
+        match followed_by.iter().find_map(|next| header_index(&lines, next)) {
+            Some(next_header) => {
+                table.push(String::new());
+                lines.splice(next_header..next_header, table);
+            }
+            None => {
+                if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+                    lines.push(String::new());
+                }
+                lines.extend(table);
+            }
+        }
+    }
+
+    let mut updated = lines.join("\n");
+    if content.is_empty() || content.ends_with('\n') {
+        updated.push('\n');
+    }
+    Ok(updated)
+}
+
+/// The `libs` catalog of the build: declared in settings, else `gradle/libs.versions.toml`
+pub fn locate_default_catalog(root_path: &Path) -> Result<PathBuf> {
+    let settings = load_build_settings(root_path)?;
+    let declared = settings
+        .version_catalogs
+        .iter()
+        .find(|declaration| declaration.accessor == file_patterns::DEFAULT_CATALOG_ACCESSOR)
+        .map(|declaration| resolve_settings_path(root_path, &declaration.path));
+
+    Ok(declared.unwrap_or_else(|| root_path.join(file_patterns::DEFAULT_CATALOG_PATH)))
+}
+
+/// Build files of the root build only; buildSrc and nested included builds cannot use its `libs` accessor
+pub fn find_root_build_files(root_path: &Path) -> Result<Vec<PathBuf>> {
+    let mut build_files = find_gradle_files(root_path)?;
+    build_files.retain(|build_file| {
+        build_file
+            .ancestors()
+            .skip(1)
+            .take_while(|dir| *dir != root_path)
+            .all(|dir| {
+                dir.file_name().and_then(|name| name.to_str()) != Some(file_patterns::BUILD_SRC_DIR)
+                    && !file_patterns::GRADLE_SETTINGS_FILES.iter().any(|settings| dir.join(settings).is_file())
+            })
+    });
+    build_files.sort();
+    Ok(build_files)
+}
+
+pub fn plan_catalog_migration(root_path: &Path) -> Result<CatalogMigration> {
+    let catalog_path = locate_default_catalog(root_path)?;
+    let (catalog_content, catalog) = if catalog_path.is_file() {
+        (fs::read_to_string(&catalog_path)?, Some(parse_version_catalog(&catalog_path)?))
+    } else {
+        (String::new(), None)
+    };
+    let version_catalogs = load_version_catalogs(root_path)?;
+
+    let mut skipped = Vec::new();
+    let mut candidates: BTreeMap<Coordinate, Vec<MigrationCandidate>> = BTreeMap::new();
+    let mut file_contents: BTreeMap<PathBuf, String> = BTreeMap::new();
+
+    for build_file in find_root_build_files(root_path)? {
+        let content = fs::read_to_string(&build_file)?;
+        let lines: Vec<&str> = content.lines().collect();
+
+        for location in parse_dependencies_from_file(&build_file, &version_catalogs)? {
+            if !matches!(location.source_type, DependencySourceType::Direct) {
+                continue;
+            }
+            let line = lines.get(location.line_number - 1).copied().unwrap_or_default();
+            let span = match unsupported_reason(&location) {
+                Some(reason) => Err(reason),
+                None => declaration_span(&location, line)?.ok_or("declaration could not be located on its line"),
+            };
+            match span {
+                Ok((replace_start, replace_end)) => {
+                    let coordinate = (location.dependency.group.clone(), location.dependency.artifact.clone());
+                    candidates.entry(coordinate).or_default().push(MigrationCandidate { location, replace_start, replace_end });
+                }
+                Err(reason) => skipped.push(SkippedDependency { location, reason: reason.to_string() }),
+            }
+        }
+        file_contents.insert(build_file, content);
+    }
+
+    // Coordinates the catalog already declares keep their alias
+    let mut existing_libraries: HashMap<Coordinate, (String, Option<String>)> = HashMap::new();
+    if let Some(libraries) = catalog.as_ref().and_then(|catalog| catalog.libraries.as_ref()) {
+        let mut aliases: Vec<&String> = libraries.keys().collect();
+        aliases.sort();
+        for alias in aliases {
+            if let Some(coordinate) = libraries[alias].coordinate() {
+                let version = catalog.as_ref().and_then(|catalog| catalog.resolve_library_version(alias)).map(|(_, _, version)| version);
+                existing_libraries.entry(coordinate).or_insert((alias.clone(), version));
+            }
+        }
+    }
+
+    let mut library_aliases = AliasAllocator::new(catalog.as_ref().and_then(|catalog| catalog.libraries.as_ref()).into_iter().flat_map(|libraries| libraries.keys()));
+    let mut version_keys = VersionKeyAllocator::new(catalog.as_ref());
+    let mut library_entries = Vec::new();
+    let mut version_entries = Vec::new();
+    let mut rewrites: Vec<(MigrationCandidate, String)> = Vec::new();
+    let mut planned: Vec<(Coordinate, Option<String>, Vec<MigrationCandidate>)> = Vec::new();
+
+    for ((group, artifact), coordinate_candidates) in candidates {
+        let mut versions: Vec<&String> = coordinate_candidates.iter().filter_map(|candidate| candidate.location.dependency.version.as_ref()).collect();
+        versions.sort();
+        versions.dedup();
+        let unversioned = coordinate_candidates.iter().any(|candidate| candidate.location.dependency.version.is_none());
+
+        if versions.len() > 1 || (unversioned && !versions.is_empty()) {
+            let declared: Vec<&str> = versions.iter().map(|version| version.as_str()).collect();
+            let reason = format!("declared with different versions ({}); resolve the conflict first", declared.join(", "));
+            skipped.extend(coordinate_candidates.into_iter().map(|candidate| SkippedDependency { location: candidate.location, reason: reason.clone() }));
+            continue;
+        }
+        let version = versions.first().map(|version| version.to_string());
+
+        if let Some((alias, catalog_version)) = existing_libraries.get(&(group.clone(), artifact.clone())) {
+            if *catalog_version == version {
+                let reference = format!("{}.{}", file_patterns::DEFAULT_CATALOG_ACCESSOR, normalize_alias(alias));
+                rewrites.extend(coordinate_candidates.into_iter().map(|candidate| (candidate, reference.clone())));
+            } else {
+                let reason = format!("catalog entry `{}` declares version {}", alias, catalog_version.as_deref().unwrap_or("none"));
+                skipped.extend(coordinate_candidates.into_iter().map(|candidate| SkippedDependency { location: candidate.location, reason: reason.clone() }));
+            }
+            continue;
+        }
+
+        planned.push(((group, artifact), version, coordinate_candidates));
+    }
+
+    // Artifacts of one group released together share a version key
+    let mut group_versions: HashMap<&str, HashSet<Option<&String>>> = HashMap::new();
+    let mut group_sizes: HashMap<&str, usize> = HashMap::new();
+    for ((group, _), version, _) in &planned {
+        group_versions.entry(group.as_str()).or_default().insert(version.as_ref());
+        *group_sizes.entry(group.as_str()).or_insert(0) += 1;
+    }
+    let shared_version_groups: HashSet<String> = group_versions
+        .iter()
+        .filter(|(group, versions)| versions.len() == 1 && group_sizes[*group] > 1)
+        .map(|(group, _)| group.to_string())
+        .collect();
+    let mut shared_version_keys: HashMap<String, String> = HashMap::new();
+
+    for ((group, artifact), version, coordinate_candidates) in planned {
+        let alias = library_aliases.allocate(&library_alias_candidates(&group, &artifact));
+        let library_entry = match &version {
+            Some(version) => {
+                let key = if shared_version_groups.contains(&group) {
+                    match shared_version_keys.get(&group) {
+                        Some(key) => key.clone(),
+                        None => {
+                            let last_group_segment = sanitize_alias(group.rsplit('.').next().unwrap_or(&group));
+                            let key = version_keys.allocate(&[last_group_segment, sanitize_alias(&group)], version, &mut version_entries);
+                            shared_version_keys.insert(group.clone(), key.clone());
+                            key
+                        }
+                    }
+                } else {
+                    version_keys.allocate(std::slice::from_ref(&alias), version, &mut version_entries)
+                };
+                format!("{} = {{ module = \"{}:{}\", version.ref = \"{}\" }}", alias, group, artifact, key)
+            }
+            None => format!("{} = {{ module = \"{}:{}\" }}", alias, group, artifact),
+        };
+        library_entries.push(library_entry);
+
+        let reference = format!("{}.{}", file_patterns::DEFAULT_CATALOG_ACCESSOR, normalize_alias(&alias));
+        rewrites.extend(coordinate_candidates.into_iter().map(|candidate| (candidate, reference.clone())));
+    }
+
+    let mut changes = Vec::new();
+    let updated_catalog = insert_toml_entries(&catalog_content, "versions", &version_entries, &["libraries", "bundles", "plugins"])?;
+    let updated_catalog = insert_toml_entries(&updated_catalog, "libraries", &library_entries, &["bundles", "plugins"])?;
+    if updated_catalog != catalog_content {
+        changes.push(FileChange {
+            path: catalog_path.clone(),
+            original: catalog_content,
+            updated: updated_catalog,
+        });
+    }
+
+    let mut migrated = Vec::new();
+    let mut file_rewrites: BTreeMap<PathBuf, Vec<(MigrationCandidate, String)>> = BTreeMap::new();
+    for (candidate, reference) in rewrites {
+        file_rewrites.entry(candidate.location.file_path.clone()).or_default().push((candidate, reference));
+    }
+    for (build_file, file_candidates) in file_rewrites {
+        let original = file_contents.remove(&build_file).unwrap_or_default();
+        let mut lines: Vec<String> = original.lines().map(|line| line.to_string()).collect();
+
+        for (candidate, reference) in file_candidates {
+            let line = &mut lines[candidate.location.line_number - 1];
+            line.replace_range(candidate.replace_start..candidate.replace_end, &reference);
+            migrated.push(MigratedDependency { location: candidate.location, catalog_reference: reference });
+        }
+
+        let mut updated = lines.join("\n");
+        if original.ends_with('\n') {
+            updated.push('\n');
+        }
+        changes.push(FileChange { path: build_file, original, updated });
+    }
+
+    migrated.sort_by(|a, b| (&a.location.file_path, a.location.line_number).cmp(&(&b.location.file_path, b.location.line_number)));
+
+    Ok(CatalogMigration {
+        catalog_path,
+        changes,
+        migrated,
+        skipped,
+    })
+}
+
+/// Why a declaration cannot be expressed as a plain catalog accessor
+pub fn unsupported_reason(location: &DependencyLocation) -> Option<&'static str> {
+    let dependency = &location.dependency;
+
+    if dependency.version.as_deref().is_some_and(|version| version.contains('$')) {
+        Some("version is an interpolated variable")
+    } else if dependency.extension.is_some() {
+        Some("artifact type cannot be expressed by a catalog accessor")
+    } else if dependency.classifier.as_deref().is_some_and(|classifier| classifier != TEST_FIXTURES_CLASSIFIER) {
+        Some("classifier cannot be expressed by a catalog accessor")
+    } else {
+        None
+    }
+}
+
+/// Byte range of the string or map notation on the declaring line
+fn declaration_span(location: &DependencyLocation, line: &str) -> Result<Option<(usize, usize)>> {
+    let dependency = &location.dependency;
+
+    // "group:artifact[:version]" string notation
+    let string_regex = Regex::new(&format!(
+        r#"["']{}:{}(?::[^"':@]*)?["']"#,
+        regex::escape(&dependency.group),
+        regex::escape(&dependency.artifact)
+    ))?;
+    if let Some(found) = string_regex.find(line) {
+        return Ok(Some((found.start(), found.end())));
+    }
+
+    // group: 'g', name: 'a', version: 'v' map notation
+    let entry_regex = Regex::new(regex_patterns::MAP_DEPENDENCY_ENTRY)?;
+    let entries: Vec<_> = entry_regex.find_iter(line).collect();
+    match (entries.first(), entries.last()) {
+        (Some(first), Some(last)) => Ok(Some((first.start(), last.end()))),
+        _ => Ok(None),
+    }
+}
+
+/// Hands out `[versions]` keys, reusing existing keys that already hold the same version
+pub struct VersionKeyAllocator {
+    existing: HashMap<String, String>,
+    aliases: AliasAllocator,
+}
+
+impl VersionKeyAllocator {
+    pub fn new(catalog: Option<&VersionCatalog>) -> Self {
+        let existing = catalog.and_then(|catalog| catalog.versions.clone()).unwrap_or_default();
+        let aliases = AliasAllocator::new(existing.keys());
+        Self { existing, aliases }
+    }
+
+    pub fn allocate(&mut self, candidates: &[String], version: &str, new_entries: &mut Vec<String>) -> String {
+        if let Some(key) = candidates.iter().find(|key| self.existing.get(*key).is_some_and(|existing| existing == version)) {
+            return key.clone();
+        }
+
+        let key = self.aliases.allocate(candidates);
+        new_entries.push(format!("{} = \"{}\"", key, version));
+        key
+    }
+}
\ No newline at end of file
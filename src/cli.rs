@@ -5,9 +5,12 @@
  * See LICENSE file in the project root for full license information.
  */
 
+use crate::analyzer::AnalysisParameters;
 use crate::config::Config;
+use crate::conflict_fix::VersionStrategy;
 use crate::error::{AnalysisError, Result};
-use clap::{Parser, Subcommand};
+use crate::findings::Severity;
+use clap::{Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
 #[derive(Parser)]
@@ -26,6 +29,9 @@ pub struct Args {
     
     #[arg(short, long, global = true, help = "Suppress all output messages (useful with --output)")]
     pub silent: bool,
+    
+    #[arg(long, global = true, help = "TOML compatibility matrix to use instead of the bundled one")]
+    pub matrix: Option<PathBuf>,
 }
 
 #[derive(Subcommand)]
@@ -49,6 +55,9 @@ pub enum Commands {
         
         #[arg(long, help = "Maximum number of bundle recommendations to display")]
         max_bundle_recommendations: Option<usize>,
+        
+        #[arg(long, help = "Treat api and implementation declarations of a dependency as the same bundle member")]
+        merge_configurations: bool,
     },
     /// Check for version conflicts only
     Conflicts {
@@ -83,16 +92,150 @@ pub enum Commands {
         
         #[arg(long, help = "Maximum number of bundle recommendations to display")]
         max_bundle_recommendations: Option<usize>,
+        
+        #[arg(long, help = "Treat api and implementation declarations of a dependency as the same bundle member")]
+        merge_configurations: bool,
+        
+        #[arg(long, value_enum, help = "Emit the recommendations as ready-to-use declarations instead of suggestions")]
+        emit: Option<BundleEmitFormat>,
+        
+        #[arg(long, requires = "emit", conflicts_with = "dry_run", help = "Write the emitted declarations and rewrite the modules to use them")]
+        apply: bool,
+        
+        #[arg(long, requires = "emit", help = "Print a unified diff of the changes --apply would make")]
+        dry_run: bool,
+    },
+    /// Group modules with similar dependency sets as candidates for a shared convention plugin or base module
+    Similarity {
+        #[arg(long, help = "Minimum Jaccard similarity between two modules of a group (0 to 1)")]
+        min_similarity: Option<f64>,
+    },
+    /// Lint version catalogs for unused entries, undefined references and duplicate coordinates
+    Catalog,
+    /// Audit repositories { } declarations for deprecated, insecure, rejected and duplicated repositories
+    Repositories,
+    /// Check JVM toolchains, Kotlin jvmTarget and Java compatibility for consistency across modules
+    JvmTarget,
+    /// Check Android SDK levels, build tools and namespaces for consistency across modules
+    Android,
+    /// Check the Gradle wrapper, AGP, Kotlin Gradle plugin and JDK versions against a compatibility matrix
+    Compatibility,
+    /// Report `+`, `latest.*`, range and SNAPSHOT versions in build files and version catalogs
+    DynamicVersions {
+        #[arg(long, value_enum, help = "Only report dynamic versions of at least this severity")]
+        min_severity: Option<Severity>,
+    },
+    /// Move hardcoded dependencies into the version catalog and rewrite build files to use it
+    MigrateToCatalog {
+        #[arg(long, help = "Print a unified diff of the changes instead of writing files")]
+        dry_run: bool,
+    },
+    /// Move `apply plugin` statements into the plugins block of their build file
+    MigrateToPluginsDsl {
+        #[arg(long, help = "Print a unified diff of the changes instead of writing files")]
+        dry_run: bool,
+    },
+    /// Rewrite declarations to resolve reported problems
+    Fix {
+        #[command(subcommand)]
+        target: FixTarget,
+    },
+}
+
+/// Form in which bundle recommendations are emitted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum BundleEmitFormat {
+    /// `[bundles]` entries in libs.versions.toml, used through libs.bundles.<name>
+    Catalog,
+    /// Precompiled script plugins in build-logic/convention that modules apply instead
+    ConventionPlugin,
+}
+
+#[derive(Subcommand)]
+pub enum FixTarget {
+    /// Align every version conflict on one version
+    Conflicts {
+        #[arg(long, default_value = "highest", help = "Version to align on: highest, lowest, catalog, or an explicit version")]
+        strategy: VersionStrategy,
+        
+        #[arg(long, help = "Only fix the conflict of this group:artifact")]
+        dependency: Option<String>,
+        
+        #[arg(long, help = "Print a unified diff of the changes instead of writing files")]
+        dry_run: bool,
     },
 }
 
 pub struct AnalysisOptions {
+    pub parameters: AnalysisParameters,
     pub min_version_conflicts: usize,
     pub min_duplicate_dependencies: usize,
     pub min_duplicate_plugins: usize,
-    pub min_bundle_size: usize,
-    pub min_bundle_modules: usize,
     pub max_bundle_recommendations: usize,
+    pub show_plugin_version_conflicts: bool,
+    pub show_classpath_mismatches: bool,
+    pub show_plugin_application_findings: bool,
+    pub show_cross_build_conflicts: bool,
+    pub show_catalog_findings: bool,
+    pub show_android_findings: bool,
+    pub show_jvm_target_findings: bool,
+    pub show_repository_findings: bool,
+    pub show_compatibility_findings: bool,
+    pub show_dynamic_version_findings: bool,
+}
+
+impl AnalysisOptions {
+    /// Options showing nothing; each command enables the sections it reports
+    pub fn none() -> Self {
+        AnalysisOptions {
+            parameters: AnalysisParameters {
+                min_bundle_size: usize::MAX,
+                min_bundle_modules: usize::MAX,
+                merge_bundle_configurations: false,
+                min_module_similarity: None,
+            },
+            min_version_conflicts: usize::MAX,
+            min_duplicate_dependencies: usize::MAX,
+            min_duplicate_plugins: usize::MAX,
+            max_bundle_recommendations: 0,
+            show_plugin_version_conflicts: false,
+            show_classpath_mismatches: false,
+            show_plugin_application_findings: false,
+            show_cross_build_conflicts: false,
+            show_catalog_findings: false,
+            show_android_findings: false,
+            show_jvm_target_findings: false,
+            show_repository_findings: false,
+            show_compatibility_findings: false,
+            show_dynamic_version_findings: false,
+        }
+    }
+    
+    /// Options showing every section with the configured thresholds
+    pub fn all(config: &Config) -> Self {
+        AnalysisOptions {
+            parameters: AnalysisParameters {
+                min_bundle_size: config.default_min_bundle_size,
+                min_bundle_modules: config.default_min_bundle_modules,
+                merge_bundle_configurations: false,
+                min_module_similarity: None,
+            },
+            min_version_conflicts: config.default_min_version_conflicts,
+            min_duplicate_dependencies: config.default_min_duplicate_dependencies,
+            min_duplicate_plugins: config.default_min_duplicate_plugins,
+            max_bundle_recommendations: config.default_max_bundle_recommendations,
+            show_plugin_version_conflicts: true,
+            show_classpath_mismatches: true,
+            show_plugin_application_findings: true,
+            show_cross_build_conflicts: true,
+            show_catalog_findings: true,
+            show_android_findings: true,
+            show_jvm_target_findings: true,
+            show_repository_findings: true,
+            show_compatibility_findings: true,
+            show_dynamic_version_findings: true,
+        }
+    }
 }
 
 impl Args {
@@ -104,78 +247,82 @@ impl Args {
                 min_duplicate_plugins, 
                 min_bundle_size, 
                 min_bundle_modules, 
-                max_bundle_recommendations 
+                max_bundle_recommendations,
+                merge_configurations
             }) => {
                 AnalysisOptions {
+                    parameters: AnalysisParameters {
+                        min_bundle_size: min_bundle_size.unwrap_or(config.default_min_bundle_size),
+                        min_bundle_modules: min_bundle_modules.unwrap_or(config.default_min_bundle_modules),
+                        merge_bundle_configurations: *merge_configurations,
+                        min_module_similarity: None,
+                    },
                     min_version_conflicts: min_version_conflicts.unwrap_or(config.default_min_version_conflicts),
                     min_duplicate_dependencies: min_duplicate_dependencies.unwrap_or(config.default_min_duplicate_dependencies),
                     min_duplicate_plugins: min_duplicate_plugins.unwrap_or(config.default_min_duplicate_plugins),
-                    min_bundle_size: min_bundle_size.unwrap_or(config.default_min_bundle_size),
-                    min_bundle_modules: min_bundle_modules.unwrap_or(config.default_min_bundle_modules),
                     max_bundle_recommendations: max_bundle_recommendations.unwrap_or(config.default_max_bundle_recommendations),
+                    ..AnalysisOptions::all(config)
                 }
             }
             Some(Commands::Conflicts { min_version_conflicts }) => {
                 AnalysisOptions {
                     min_version_conflicts: min_version_conflicts.unwrap_or(config.default_min_version_conflicts),
-                    min_duplicate_dependencies: usize::MAX,
-                    min_duplicate_plugins: usize::MAX,
-                    min_bundle_size: usize::MAX,
-                    min_bundle_modules: usize::MAX,
-                    max_bundle_recommendations: 0,
+                    show_plugin_version_conflicts: true,
+                    show_classpath_mismatches: true,
+                    show_cross_build_conflicts: true,
+                    ..AnalysisOptions::none()
                 }
             }
             Some(Commands::Dependencies { min_duplicate_dependencies }) => {
                 AnalysisOptions {
-                    min_version_conflicts: usize::MAX,
                     min_duplicate_dependencies: min_duplicate_dependencies.unwrap_or(config.default_min_duplicate_dependencies),
-                    min_duplicate_plugins: usize::MAX,
-                    min_bundle_size: usize::MAX,
-                    min_bundle_modules: usize::MAX,
-                    max_bundle_recommendations: 0,
+                    ..AnalysisOptions::none()
                 }
             }
             Some(Commands::Plugins { min_duplicate_plugins }) => {
                 AnalysisOptions {
-                    min_version_conflicts: usize::MAX,
-                    min_duplicate_dependencies: usize::MAX,
                     min_duplicate_plugins: min_duplicate_plugins.unwrap_or(config.default_min_duplicate_plugins),
-                    min_bundle_size: usize::MAX,
-                    min_bundle_modules: usize::MAX,
-                    max_bundle_recommendations: 0,
+                    show_plugin_version_conflicts: true,
+                    show_classpath_mismatches: true,
+                    show_plugin_application_findings: true,
+                    ..AnalysisOptions::none()
                 }
             }
             Some(Commands::Duplicates { min_duplicate_dependencies, min_duplicate_plugins }) => {
                 AnalysisOptions {
-                    min_version_conflicts: usize::MAX,
                     min_duplicate_dependencies: min_duplicate_dependencies.unwrap_or(config.default_min_duplicate_dependencies),
                     min_duplicate_plugins: min_duplicate_plugins.unwrap_or(config.default_min_duplicate_plugins),
-                    min_bundle_size: usize::MAX,
-                    min_bundle_modules: usize::MAX,
-                    max_bundle_recommendations: 0,
+                    show_classpath_mismatches: true,
+                    ..AnalysisOptions::none()
                 }
             }
-            Some(Commands::Bundles { min_bundle_size, min_bundle_modules, max_bundle_recommendations }) => {
+            Some(Commands::Bundles { min_bundle_size, min_bundle_modules, max_bundle_recommendations, merge_configurations, .. }) => {
                 AnalysisOptions {
-                    min_version_conflicts: usize::MAX,
-                    min_duplicate_dependencies: usize::MAX,
-                    min_duplicate_plugins: usize::MAX,
-                    min_bundle_size: min_bundle_size.unwrap_or(config.default_min_bundle_size),
-                    min_bundle_modules: min_bundle_modules.unwrap_or(config.default_min_bundle_modules),
+                    parameters: AnalysisParameters {
+                        min_bundle_size: min_bundle_size.unwrap_or(config.default_min_bundle_size),
+                        min_bundle_modules: min_bundle_modules.unwrap_or(config.default_min_bundle_modules),
+                        merge_bundle_configurations: *merge_configurations,
+                        min_module_similarity: None,
+                    },
                     max_bundle_recommendations: max_bundle_recommendations.unwrap_or(config.default_max_bundle_recommendations),
+                    ..AnalysisOptions::none()
                 }
             }
-            None => {
-                // Default behavior: run all checks
-                AnalysisOptions {
-                    min_version_conflicts: config.default_min_version_conflicts,
-                    min_duplicate_dependencies: config.default_min_duplicate_dependencies,
-                    min_duplicate_plugins: config.default_min_duplicate_plugins,
-                    min_bundle_size: config.default_min_bundle_size,
-                    min_bundle_modules: config.default_min_bundle_modules,
-                    max_bundle_recommendations: config.default_max_bundle_recommendations,
-                }
+            // Migrations and fixes report their own changes instead of analysis results
+            Some(Commands::MigrateToCatalog { .. }) | Some(Commands::MigrateToPluginsDsl { .. }) | Some(Commands::Fix { .. }) => AnalysisOptions::none(),
+            Some(Commands::Similarity { min_similarity }) => {
+                let mut options = AnalysisOptions::none();
+                options.parameters.min_module_similarity = Some(min_similarity.unwrap_or(config.default_min_module_similarity));
+                options
             }
+            Some(Commands::Catalog) => AnalysisOptions { show_catalog_findings: true, ..AnalysisOptions::none() },
+            Some(Commands::Android) => AnalysisOptions { show_android_findings: true, ..AnalysisOptions::none() },
+            Some(Commands::JvmTarget) => AnalysisOptions { show_jvm_target_findings: true, ..AnalysisOptions::none() },
+            Some(Commands::Repositories) => AnalysisOptions { show_repository_findings: true, ..AnalysisOptions::none() },
+            Some(Commands::Compatibility) => AnalysisOptions { show_compatibility_findings: true, ..AnalysisOptions::none() },
+            Some(Commands::DynamicVersions { .. }) => AnalysisOptions { show_dynamic_version_findings: true, ..AnalysisOptions::none() },
+            // Default behavior: run all checks
+            None => AnalysisOptions::all(config),
         }
     }
 }
@@ -190,7 +337,8 @@ pub fn validate_args(args: &Args, config: &Config) -> Result<()> {
             min_duplicate_plugins, 
             min_bundle_size, 
             min_bundle_modules, 
-            max_bundle_recommendations 
+            max_bundle_recommendations,
+            ..
         }) => {
             validate_threshold("--min-version-conflicts", *min_version_conflicts, min_threshold)?;
             validate_threshold("--min-duplicate-dependencies", *min_duplicate_dependencies, min_threshold)?;
@@ -218,7 +366,7 @@ pub fn validate_args(args: &Args, config: &Config) -> Result<()> {
             validate_threshold("--min-duplicate-dependencies", *min_duplicate_dependencies, min_threshold)?;
             validate_threshold("--min-duplicate-plugins", *min_duplicate_plugins, min_threshold)?;
         }
-        Some(Commands::Bundles { min_bundle_size, min_bundle_modules, max_bundle_recommendations }) => {
+        Some(Commands::Bundles { min_bundle_size, min_bundle_modules, max_bundle_recommendations, .. }) => {
             validate_threshold("--min-bundle-size", *min_bundle_size, min_threshold)?;
             validate_threshold("--min-bundle-modules", *min_bundle_modules, min_threshold)?;
             if let Some(value) = max_bundle_recommendations {
@@ -229,8 +377,22 @@ pub fn validate_args(args: &Args, config: &Config) -> Result<()> {
                 }
             }
         }
-        None => {
-            // No validation needed for default behavior
+        Some(Commands::Similarity { min_similarity: Some(value) }) => {
+            if !(*value > 0.0 && *value <= 1.0) {
+                return Err(AnalysisError::Validation(
+                    "--min-similarity must be greater than 0 and at most 1".to_string()
+                ));
+            }
+        }
+        Some(Commands::Fix { target: FixTarget::Conflicts { strategy, dependency, .. } }) => {
+            if matches!(strategy, VersionStrategy::Explicit(_)) && dependency.is_none() {
+                return Err(AnalysisError::Validation(
+                    "an explicit --strategy version requires --dependency".to_string()
+                ));
+            }
+        }
+        Some(Commands::Similarity { min_similarity: None }) | Some(Commands::Catalog) | Some(Commands::Android) | Some(Commands::JvmTarget) | Some(Commands::Repositories) | Some(Commands::Compatibility) | Some(Commands::DynamicVersions { .. }) | Some(Commands::MigrateToCatalog { .. }) | Some(Commands::MigrateToPluginsDsl { .. }) | None => {
+            // No thresholds to validate
         }
     }
     
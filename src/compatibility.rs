@@ -0,0 +1,227 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::analyzer::plugin_version_key;
+use crate::config::plugin_patterns::{AGP_CLASSPATH_ARTIFACT, KOTLIN_CLASSPATH_ARTIFACT};
+use crate::config::{file_patterns, regex_patterns, CompatibilityMatrix};
+use crate::error::Result;
+use crate::findings::{FindingReporter, FindingsByFile};
+use crate::parser::PluginLocation;
+use crate::version::compare_versions;
+use regex::Regex;
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeclaredVersion {
+    pub version: String,
+    pub file_path: PathBuf,
+    pub line_number: usize,
+}
+
+/// Gradle, JDK, AGP and Kotlin Gradle plugin versions a build declares
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildToolVersions {
+    pub root_path: PathBuf,
+    /// Distribution of gradle/wrapper/gradle-wrapper.properties
+    pub gradle: Option<DeclaredVersion>,
+    /// Java feature release of the Gradle daemon JVM criteria, .java-version or .tool-versions
+    pub jdk: Option<DeclaredVersion>,
+    /// Each distinct AGP version, at its first declaration
+    pub agp: Vec<DeclaredVersion>,
+    /// Each distinct Kotlin Gradle plugin version, at its first declaration
+    pub kotlin: Vec<DeclaredVersion>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum CompatibilityFindingKind {
+    GradleTooOldForAgp,
+    GradleTooOldForKotlin,
+    AgpTooOldForKotlin,
+    JdkTooOldForAgp,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionRequirement {
+    /// Version declared at the finding's line
+    pub found_version: String,
+    /// Lowest version that satisfies the requirement
+    pub required_version: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CompatibilityAnalysis {
+    pub builds: Vec<BuildToolVersions>,
+    /// Keyed by the file declaring the version that has to be raised
+    pub findings: FindingsByFile<CompatibilityFindingKind, VersionRequirement>,
+}
+
+/// Tool versions of the build rooted at `root_path`, given the plugins its build and settings files declare
+pub fn load_build_tool_versions(root_path: &Path, plugins: &[PluginLocation]) -> Result<BuildToolVersions> {
+    let mut sorted_plugins: Vec<&PluginLocation> = plugins.iter().collect();
+    sorted_plugins.sort_by(|a, b| a.file_path.cmp(&b.file_path).then(a.line_number.cmp(&b.line_number)));
+
+    let mut agp: Vec<DeclaredVersion> = Vec::new();
+    let mut kotlin: Vec<DeclaredVersion> = Vec::new();
+    for plugin in sorted_plugins {
+        let Some(version) = &plugin.plugin.version else { continue };
+        let versions = match plugin_version_key(plugin).as_str() {
+            AGP_CLASSPATH_ARTIFACT => &mut agp,
+            KOTLIN_CLASSPATH_ARTIFACT => &mut kotlin,
+            _ => continue,
+        };
+        if !versions.iter().any(|declared| &declared.version == version) {
+            versions.push(DeclaredVersion {
+                version: version.clone(),
+                file_path: plugin.file_path.clone(),
+                line_number: plugin.line_number,
+            });
+        }
+    }
+
+    Ok(BuildToolVersions {
+        root_path: root_path.to_path_buf(),
+        gradle: find_declared_version(
+            &root_path.join(file_patterns::GRADLE_WRAPPER_PROPERTIES),
+            &Regex::new(regex_patterns::GRADLE_DISTRIBUTION_URL)?,
+        )?,
+        jdk: find_jdk_version(root_path)?,
+        agp,
+        kotlin,
+    })
+}
+
+/// First capture of `regex` in the file, if the file exists
+fn find_declared_version(file_path: &Path, regex: &Regex) -> Result<Option<DeclaredVersion>> {
+    if !file_path.is_file() {
+        return Ok(None);
+    }
+
+    Ok(fs::read_to_string(file_path)?.lines().enumerate().find_map(|(line_index, line)| {
+        regex.captures(line).map(|captures| DeclaredVersion {
+            version: captures[1].to_string(),
+            file_path: file_path.to_path_buf(),
+            line_number: line_index + 1,
+        })
+    }))
+}
+
+/// JDK the build runs on, from the first of the daemon JVM criteria, .java-version and .tool-versions
+fn find_jdk_version(root_path: &Path) -> Result<Option<DeclaredVersion>> {
+    let sources = [
+        (file_patterns::DAEMON_JVM_PROPERTIES, regex_patterns::DAEMON_JVM_TOOLCHAIN_VERSION),
+        (file_patterns::JAVA_VERSION_FILE, regex_patterns::JAVA_VERSION_LINE),
+        (file_patterns::TOOL_VERSIONS_FILE, regex_patterns::TOOL_VERSIONS_JAVA),
+    ];
+    let version_regex = Regex::new(regex_patterns::JAVA_VERSION_NUMBER)?;
+
+    for (file_name, pattern) in sources {
+        let Some(mut declared) = find_declared_version(&root_path.join(file_name), &Regex::new(pattern)?)? else { continue };
+        // temurin-17.0.2 and 17.0.2 name Java 17, 1.8 names Java 8
+        let Some(captures) = version_regex.captures(&declared.version) else { continue };
+        declared.version = match (&captures[1], captures.get(2)) {
+            ("1", Some(feature_release)) => feature_release.as_str().to_string(),
+            (major, _) => major.to_string(),
+        };
+        return Ok(Some(declared));
+    }
+
+    Ok(None)
+}
+
+/// Combinations of Gradle, AGP, Kotlin Gradle plugin and JDK versions that `matrix` rules out
+pub fn check_compatibility(builds: Vec<BuildToolVersions>, matrix: &CompatibilityMatrix) -> CompatibilityAnalysis {
+    let mut findings = FindingReporter::new();
+    let mut report = |declared: &DeclaredVersion, kind, required_version: &str, message| {
+        let requirement = VersionRequirement {
+            found_version: declared.version.clone(),
+            required_version: required_version.to_string(),
+        };
+        findings.report(&declared.file_path, declared.line_number, kind, requirement, message);
+    };
+
+    for build in &builds {
+        for agp in &build.agp {
+            let Some(requirements) = release_line(&matrix.agp, &agp.version, |entry| &entry.version) else { continue };
+
+            if let (Some(gradle), Some(min_gradle)) = (&build.gradle, &requirements.min_gradle) {
+                if is_below(&gradle.version, min_gradle) {
+                    report(
+                        gradle,
+                        CompatibilityFindingKind::GradleTooOldForAgp,
+                        min_gradle,
+                        format!(
+                            "Gradle {} is too old for Android Gradle plugin {} ({}:{}); it requires Gradle {} or newer",
+                            gradle.version, agp.version, agp.file_path.display(), agp.line_number, min_gradle
+                        ),
+                    );
+                }
+            }
+
+            if let (Some(jdk), Some(min_jdk)) = (&build.jdk, &requirements.min_jdk) {
+                if is_below(&jdk.version, min_jdk) {
+                    report(
+                        jdk,
+                        CompatibilityFindingKind::JdkTooOldForAgp,
+                        min_jdk,
+                        format!(
+                            "JDK {} is too old for Android Gradle plugin {} ({}:{}); it requires JDK {} or newer",
+                            jdk.version, agp.version, agp.file_path.display(), agp.line_number, min_jdk
+                        ),
+                    );
+                }
+            }
+        }
+
+        for kotlin in &build.kotlin {
+            let Some(requirements) = release_line(&matrix.kotlin, &kotlin.version, |entry| &entry.version) else { continue };
+
+            if let (Some(gradle), Some(min_gradle)) = (&build.gradle, &requirements.min_gradle) {
+                if is_below(&gradle.version, min_gradle) {
+                    report(
+                        gradle,
+                        CompatibilityFindingKind::GradleTooOldForKotlin,
+                        min_gradle,
+                        format!(
+                            "Gradle {} is too old for Kotlin Gradle plugin {} ({}:{}); it requires Gradle {} or newer",
+                            gradle.version, kotlin.version, kotlin.file_path.display(), kotlin.line_number, min_gradle
+                        ),
+                    );
+                }
+            }
+
+            let Some(min_agp) = &requirements.min_agp else { continue };
+            for agp in build.agp.iter().filter(|agp| is_below(&agp.version, min_agp)) {
+                report(
+                    agp,
+                    CompatibilityFindingKind::AgpTooOldForKotlin,
+                    min_agp,
+                    format!(
+                        "Android Gradle plugin {} is too old for Kotlin Gradle plugin {} ({}:{}); it requires Android Gradle plugin {} or newer",
+                        agp.version, kotlin.version, kotlin.file_path.display(), kotlin.line_number, min_agp
+                    ),
+                );
+            }
+        }
+    }
+
+    CompatibilityAnalysis { builds, findings: findings.finish() }
+}
+
+/// Entry of the release line `version` belongs to: the highest entry not above it
+fn release_line<'a, T>(entries: &'a [T], version: &str, entry_version: impl Fn(&T) -> &String) -> Option<&'a T> {
+    entries
+        .iter()
+        .filter(|entry| compare_versions(version, entry_version(entry)) != Ordering::Less)
+        .max_by(|a, b| compare_versions(entry_version(a), entry_version(b)))
+}
+
+fn is_below(version: &str, minimum: &str) -> bool {
+    compare_versions(version, minimum) == Ordering::Less
+}
\ No newline at end of file
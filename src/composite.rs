@@ -0,0 +1,114 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::error::Result;
+use crate::parser::DependencyLocation;
+use crate::settings::{load_build_settings, resolve_settings_path};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A Gradle build taking part in a composite build
+#[derive(Debug, Clone, Serialize)]
+pub struct GradleBuild {
+    pub name: String,
+    pub root_path: PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildDependencyLocation {
+    pub build: String,
+    #[serde(flatten)]
+    pub location: DependencyLocation,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CompositeAnalysis {
+    pub builds: Vec<GradleBuild>,
+    pub cross_build_conflicts: HashMap<String, Vec<BuildDependencyLocation>>,
+}
+
+/// Discovers the root build followed by every build reachable through `includeBuild(...)`
+pub fn discover_builds(root_path: &Path) -> Result<Vec<GradleBuild>> {
+    let mut builds = Vec::new();
+    let mut visited = HashSet::new();
+    let mut pending = VecDeque::from([root_path.to_path_buf()]);
+    
+    while let Some(build_root) = pending.pop_front() {
+        let canonical_root = canonical_path(&build_root);
+        if !visited.insert(canonical_root.clone()) {
+            continue;
+        }
+        
+        let settings = load_build_settings(&build_root)?;
+        for included_build in &settings.included_builds {
+            let included_root = resolve_settings_path(&build_root, included_build);
+            // Missing builds are left for Gradle to report
+            if included_root.is_dir() {
+                pending.push_back(included_root);
+            }
+        }
+        
+        let name = settings.root_project_name.unwrap_or_else(|| {
+            canonical_root
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("root")
+                .to_string()
+        });
+        builds.push(GradleBuild { name, root_path: build_root });
+    }
+    
+    Ok(builds)
+}
+
+/// Reports dependencies declared with different versions in more than one build
+pub fn analyze_composite(builds: Vec<GradleBuild>, all_dependencies: &[DependencyLocation]) -> CompositeAnalysis {
+    let mut dependency_groups: HashMap<String, Vec<BuildDependencyLocation>> = HashMap::new();
+    
+    if builds.len() > 1 {
+        let canonical_roots: Vec<(PathBuf, &str)> = builds
+            .iter()
+            .map(|build| (canonical_path(&build.root_path), build.name.as_str()))
+            .collect();
+        
+        for dep_location in all_dependencies {
+            // A file belongs to the build with the deepest root containing it
+            let file_path = canonical_path(&dep_location.file_path);
+            let owner = canonical_roots
+                .iter()
+                .filter(|(root, _)| file_path.starts_with(root))
+                .max_by_key(|(root, _)| root.components().count());
+            
+            if let Some((_, build_name)) = owner {
+                dependency_groups.entry(dep_location.dependency.key()).or_default().push(BuildDependencyLocation {
+                    build: build_name.to_string(),
+                    location: dep_location.clone(),
+                });
+            }
+        }
+    }
+    
+    let cross_build_conflicts = dependency_groups
+        .into_iter()
+        .filter(|(_, locations)| {
+            let unique_builds: HashSet<_> = locations.iter().map(|loc| &loc.build).collect();
+            let unique_versions: HashSet<_> = locations.iter().filter_map(|loc| loc.location.dependency.version.as_ref()).collect();
+            unique_builds.len() > 1 && unique_versions.len() > 1
+        })
+        .collect();
+    
+    CompositeAnalysis {
+        builds,
+        cross_build_conflicts,
+    }
+}
+
+fn canonical_path(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
\ No newline at end of file
@@ -5,6 +5,14 @@
  * See LICENSE file in the project root for full license information.
  */
 
+use crate::error::Result;
+use crate::findings::Severity;
+use crate::version::DynamicVersionKind;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
 /// Application configuration constants
 pub struct Config {
     pub default_min_version_conflicts: usize,
@@ -13,9 +21,13 @@ pub struct Config {
     pub default_min_bundle_size: usize,
     pub default_min_bundle_modules: usize,
     pub default_max_bundle_recommendations: usize,
+    pub default_min_module_similarity: f64,
     pub min_threshold_value: usize,
     pub priority_weights: PriorityWeights,
     pub configuration_scores: ConfigurationScores,
+    pub plugin_id_aliases: PluginIdAliases,
+    pub compatibility_matrix: CompatibilityMatrix,
+    pub dynamic_version_severities: DynamicVersionSeverities,
 }
 
 pub struct PriorityWeights {
@@ -42,6 +54,7 @@ impl Default for Config {
             default_min_bundle_size: 2,
             default_min_bundle_modules: 2,
             default_max_bundle_recommendations: 5,
+            default_min_module_similarity: 0.8,
             min_threshold_value: 2,
             priority_weights: PriorityWeights {
                 bundle_size: 10.0,
@@ -56,10 +69,156 @@ impl Default for Config {
                 test_compile_only: 0.5,
                 default: 1.0,
             },
+            plugin_id_aliases: PluginIdAliases::default(),
+            compatibility_matrix: CompatibilityMatrix::default(),
+            dynamic_version_severities: DynamicVersionSeverities::default(),
+        }
+    }
+}
+
+/// Legacy and shorthand plugin ids mapped to the canonical id of the same plugin
+pub struct PluginIdAliases {
+    pub aliases: Vec<(String, String)>, // (alias, canonical_id); later entries take precedence
+}
+
+impl Default for PluginIdAliases {
+    fn default() -> Self {
+        Self {
+            aliases: plugin_patterns::LEGACY_PLUGIN_IDS
+                .iter()
+                .map(|(alias, canonical_id)| (alias.to_string(), canonical_id.to_string()))
+                .collect(),
         }
     }
 }
 
+impl PluginIdAliases {
+    pub fn canonical_id(&self, plugin_id: &str) -> String {
+        self.aliases
+            .iter()
+            .rev()
+            .find(|(alias, _)| alias == plugin_id)
+            .map(|(_, canonical_id)| canonical_id.clone())
+            .unwrap_or_else(|| plugin_id.to_string())
+    }
+}
+
+/// How seriously each dynamic version notation is reported, overridable per configuration
+pub struct DynamicVersionSeverities {
+    /// Notations without an entry are not reported; later entries take precedence
+    pub patterns: Vec<(DynamicVersionKind, Severity)>,
+    /// (configuration glob, notation, severity or `None` to allow); later entries take precedence
+    pub configuration_overrides: Vec<(String, DynamicVersionKind, Option<Severity>)>,
+    /// Findings below it are not reported
+    pub min_severity: Severity,
+}
+
+impl Default for DynamicVersionSeverities {
+    fn default() -> Self {
+        Self {
+            patterns: vec![
+                (DynamicVersionKind::Plus, Severity::High),
+                (DynamicVersionKind::LatestRelease, Severity::High),
+                (DynamicVersionKind::LatestIntegration, Severity::High),
+                (DynamicVersionKind::Range, Severity::Medium),
+                (DynamicVersionKind::Snapshot, Severity::Medium),
+            ],
+            // Snapshots of the code under test are common in test configurations, e.g. testImplementation, androidTestApi
+            configuration_overrides: vec![
+                ("test*".to_string(), DynamicVersionKind::Snapshot, None),
+                ("*Test*".to_string(), DynamicVersionKind::Snapshot, None),
+            ],
+            min_severity: Severity::Low,
+        }
+    }
+}
+
+impl DynamicVersionSeverities {
+    /// Severity of `kind` declared in `configuration`, `None` when it is allowed there
+    pub fn severity(&self, kind: DynamicVersionKind, configuration: Option<&str>) -> Option<Severity> {
+        let overridden = configuration.and_then(|configuration| {
+            self.configuration_overrides
+                .iter()
+                .rev()
+                .find(|(pattern, overridden_kind, _)| *overridden_kind == kind && matches_glob(pattern, configuration))
+        });
+        
+        let severity = match overridden {
+            Some((_, _, severity)) => *severity,
+            None => self.patterns.iter().rev().find(|(pattern_kind, _)| *pattern_kind == kind).map(|(_, severity)| *severity),
+        };
+        severity.filter(|severity| *severity >= self.min_severity)
+    }
+}
+
+/// `*` matches any run of characters, everything else matches itself
+fn matches_glob(pattern: &str, text: &str) -> bool {
+    let mut segments = pattern.split('*');
+    let first = segments.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else { return false };
+    let segments: Vec<&str> = segments.collect();
+    
+    let Some((last, middle)) = segments.split_last() else { return rest.is_empty() };
+    for segment in middle {
+        match rest.find(segment) {
+            Some(index) => rest = &rest[index + segment.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+/// Compatibility matrix compiled into the binary, see data/compatibility-matrix.toml
+const BUNDLED_COMPATIBILITY_MATRIX: &str = include_str!("../data/compatibility-matrix.toml");
+
+/// Minimum Gradle, AGP and JDK versions required by Android Gradle plugin and Kotlin Gradle plugin releases
+#[derive(Debug, Clone, Deserialize)]
+pub struct CompatibilityMatrix {
+    #[serde(default)]
+    pub agp: Vec<AgpRequirements>,
+    #[serde(default)]
+    pub kotlin: Vec<KotlinRequirements>,
+}
+
+/// Requirements of the AGP releases from `version` up to the next entry
+#[derive(Debug, Clone, Deserialize)]
+pub struct AgpRequirements {
+    pub version: String,
+    pub min_gradle: Option<String>,
+    pub min_jdk: Option<String>,
+}
+
+/// Requirements of the Kotlin Gradle plugin releases from `version` up to the next entry
+#[derive(Debug, Clone, Deserialize)]
+pub struct KotlinRequirements {
+    pub version: String,
+    pub min_gradle: Option<String>,
+    pub min_agp: Option<String>,
+}
+
+impl Default for CompatibilityMatrix {
+    fn default() -> Self {
+        Self::bundled().clone()
+    }
+}
+
+impl CompatibilityMatrix {
+    /// The bundled matrix, parsed once however many configurations are created
+    fn bundled() -> &'static CompatibilityMatrix {
+        static BUNDLED: OnceLock<CompatibilityMatrix> = OnceLock::new();
+        BUNDLED.get_or_init(|| Self::from_toml(BUNDLED_COMPATIBILITY_MATRIX).expect("bundled compatibility matrix is valid"))
+    }
+    
+    pub fn from_toml(content: &str) -> Result<Self> {
+        Ok(toml::from_str(content)?)
+    }
+    
+    /// Reads a matrix replacing the bundled one
+    pub fn from_file(path: &Path) -> Result<Self> {
+        Self::from_toml(&fs::read_to_string(path)?)
+    }
+}
+
 /// Bundle name patterns for common dependency groups
 pub struct BundleNamePatterns {
     patterns: Vec<(String, String)>, // (pattern, suggested_name)
@@ -92,39 +251,195 @@ impl BundleNamePatterns {
         }
         
         // Fallback to generic name based on last part of group
-        format!("{}-bundle", most_common_group.split('.').last().unwrap_or("common"))
+        format!("{}-bundle", most_common_group.split('.').next_back().unwrap_or("common"))
     }
 }
 
 /// File patterns for Gradle projects
 pub mod file_patterns {
     pub const GRADLE_BUILD_FILES: &[&str] = &["build.gradle", "build.gradle.kts"];
+    pub const GRADLE_SETTINGS_FILES: &[&str] = &["settings.gradle", "settings.gradle.kts"];
+    
+    // Precompiled script plugins, e.g. buildSrc/src/main/kotlin/myorg.android-library.gradle.kts
+    pub const PRECOMPILED_SCRIPT_DIRS: &[&str] = &["src/main/kotlin", "src/main/groovy"];
+    pub const PRECOMPILED_SCRIPT_EXTENSIONS: &[&str] = &[".gradle.kts", ".gradle"];
     pub const VERSION_CATALOG_FILES: &[&str] = &["libs.versions.toml", "versions.toml"];
+    
+    // Catalogs are accessed by file name prefix (androidx.versions.toml -> androidx), libs by default
+    pub const VERSION_CATALOG_EXTENSION: &str = ".versions.toml";
+    pub const DEFAULT_CATALOG_ACCESSOR: &str = "libs";
+    pub const DEFAULT_CATALOG_PATH: &str = "gradle/libs.versions.toml";
+    // Catalogs in a build's gradle/ directory are visible to the whole build
+    pub const CATALOG_DIR: &str = "gradle";
+    
+    // Kotlin convention plugin sources look catalog entries up by name
+    pub const KOTLIN_SOURCE_EXTENSION: &str = ".kt";
+    pub const BUILD_SRC_DIR: &str = "buildSrc";
+    
+    // Where generated convention plugins are scaffolded
+    pub const BUILD_LOGIC_DIR: &str = "build-logic";
+    pub const CONVENTION_PROJECT: &str = "convention";
+    pub const KOTLIN_SOURCE_DIR: &str = "src/main/kotlin";
+    
+    // Where a build declares the Gradle version and the JDK Gradle runs on
+    pub const GRADLE_WRAPPER_PROPERTIES: &str = "gradle/wrapper/gradle-wrapper.properties";
+    pub const DAEMON_JVM_PROPERTIES: &str = "gradle/gradle-daemon-jvm.properties";
+    pub const JAVA_VERSION_FILE: &str = ".java-version";
+    pub const TOOL_VERSIONS_FILE: &str = ".tool-versions";
 }
 
 /// Regex patterns for dependency parsing
 pub mod regex_patterns {
-    pub const STRING_DEPENDENCY: &str = r#"^\s*(\w+)\s*[\(\s]?\s*["']([^"':]+):([^"':]+)(?::([^"']+))?["']\s*[\)\s]?.*$"#;
-    pub const MAP_DEPENDENCY_1: &str = r#"^\s*(\w+)\s*\(\s*group\s*:\s*["']([^"']+)["']\s*,\s*name\s*:\s*["']([^"']+)["']\s*,\s*version\s*:\s*["']([^"']+)["']\s*\).*$"#;
-    pub const MAP_DEPENDENCY_2: &str = r#"^\s*(\w+)\s*\(\s*name\s*:\s*["']([^"']+)["']\s*,\s*group\s*:\s*["']([^"']+)["']\s*,\s*version\s*:\s*["']([^"']+)["']\s*\).*$"#;
-    pub const LIBS_DEPENDENCY: &str = r#"^\s*(\w+)\s+libs\.(.+)$"#;
-    pub const VERSION_CATALOG_DEPENDENCY: &str = r#"^\s*(\w+)\s*[\(\s]\s*([a-zA-Z0-9]+)\.([a-zA-Z0-9.\-_]+)\s*[\)\s]?.*$"#;
+    // group:artifact[:version[:classifier]][@extension], optionally wrapped in testFixtures(...)
+    pub const STRING_DEPENDENCY: &str = r#"^\s*(\w+)\s*[\(\s]?\s*(?:testFixtures\s*\(\s*)?["']([^"':@]+):([^"':@]+)(?::([^"':@]+))?(?::([^"':@]+))?(?:@([^"'@]+))?["']\s*\)?\s*[\)\s]?.*$"#;
+    pub const MAP_DEPENDENCY: &str = r#"^\s*(\w+)\s*[\(\s]\s*(?:group|name)\s*[:=]\s*["']"#;
+    pub const MAP_DEPENDENCY_ENTRY: &str = r#"\b(group|name|version|classifier|ext)\s*[:=]\s*["']([^"']*)["']"#;
+    pub const CATALOG_BUNDLE_DEPENDENCY: &str = r#"^\s*(\w+)\s*[\(\s]\s*([a-zA-Z]\w*)\.bundles\.([a-zA-Z0-9.\-_]+)"#;
+    pub const VERSION_CATALOG_DEPENDENCY: &str = r#"^\s*(\w+)\s*[\(\s]\s*(?:(?:testFixtures|variantOf)\s*\(\s*)?([a-zA-Z0-9]+)\.([a-zA-Z0-9.\-_]+)\s*[\)\s]?.*$"#;
+    
+    // Catalog lookups used by precompiled script plugins:
+    // implementation(libs.findLibrary("x").get()) or add("implementation", libs.findLibrary("x").get())
+    pub const FIND_LIBRARY_DEPENDENCY: &str = r#"^\s*(?:add\s*\(\s*["'](\w+)["']\s*,|(\w+)\s*\()\s*(\w+)\.findLibrary\s*\(\s*["']([^"']+)["']\s*\)"#;
+    pub const PACKAGE_DECLARATION: &str = r#"^\s*package\s+([\w.]+)"#;
+    
+    // Variant wrappers around a dependency notation
+    pub const TEST_FIXTURES_WRAPPER: &str = r#"\btestFixtures\s*\("#;
+    pub const VARIANT_OF_WRAPPER: &str = r#"\bvariantOf\s*\("#;
+    pub const VARIANT_CLASSIFIER: &str = r#"\bclassifier\s*\(?\s*["']([^"']+)["']"#;
+    pub const VARIANT_ARTIFACT_TYPE: &str = r#"\bartifactType\s*\(?\s*["']([^"']+)["']"#;
     
     // Project dependency patterns (these should be ignored)
-    pub const PROJECT_DEPENDENCY: &str = r#"^\s*\w+\s*[\(\s]\s*project\s*\(\s*["'][^"']*["']\s*\)\s*[\)\s]?.*$"#;
-    pub const PROJECTS_ACCESSOR_DEPENDENCY: &str = r#"^\s*\w+\s*[\(\s]\s*projects\.([a-zA-Z0-9.\-_]+)\s*[\)\s]?.*$"#;
+    pub const PROJECT_DEPENDENCY: &str = r#"^\s*\w+\s*[\(\s]\s*(?:testFixtures\s*\(\s*)?project\s*\(\s*["'][^"']*["']\s*\)\s*[\)\s]?.*$"#;
+    pub const PROJECTS_ACCESSOR_DEPENDENCY: &str = r#"^\s*\w+\s*[\(\s]\s*(?:testFixtures\s*\(\s*)?projects\.([a-zA-Z0-9.\-_]+)\s*[\)\s]?.*$"#;
     
     pub const DEPENDENCIES_BLOCK: &str = r"dependencies";
+    pub const REPOSITORIES_BLOCK: &str = r"repositories";
+    pub const PUBLISHING_BLOCK: &str = r"publishing";
+    pub const DEPENDENCY_RESOLUTION_MANAGEMENT_BLOCK: &str = r"dependencyResolutionManagement";
+    pub const BUILDSCRIPT_BLOCK: &str = r"buildscript";
+    pub const PLUGIN_MANAGEMENT_BLOCK: &str = r"pluginManagement";
+    
+    // Repository declarations inside repositories { }
+    pub const REPOSITORY_DECLARATION: &str = r"\b(google|mavenCentral|jcenter|mavenLocal|gradlePluginPortal|maven|ivy|flatDir)\s*[({]";
+    pub const REPOSITORY_URL: &str = r#"["'](\w+://[^"']*)["']"#;
+    
+    // Buildscript classpath patterns
+    pub const CLASSPATH_DEPENDENCY: &str = r#"^\s*classpath\s*\(?\s*["']([^"':@]+):([^"':@]+)(?::([^"':@]+))?["']"#;
+    pub const CLASSPATH_CATALOG_DEPENDENCY: &str = r#"^\s*classpath\s*\(?\s*([a-zA-Z]\w*)\.([a-zA-Z0-9.\-_]+)"#;
     
     // Plugin patterns
     pub const PLUGINS_BLOCK: &str = r"plugins";
     pub const PLUGIN_ID_VERSION: &str = r#"^\s*id\s+["']([^"']+)["']\s+version\s+["']([^"']+)["'].*$"#;
-    pub const PLUGIN_ID_ONLY: &str = r#"^\s*id\s+["']([^"']+)["']\s*$"#;
+    pub const PLUGIN_ID_ONLY: &str = r#"^\s*id\s+["']([^"']+)["']\s*(?:apply\s*\(?\s*(?:true|false)\s*\)?\s*)?$"#;
     pub const PLUGIN_KOTLIN_DSL_ID_VERSION: &str = r#"^\s*id\s*\(\s*["']([^"']+)["']\s*\)\s+version\s+["']([^"']+)["'].*$"#;
-    pub const PLUGIN_KOTLIN_DSL_ID_ONLY: &str = r#"^\s*id\s*\(\s*["']([^"']+)["']\s*\)\s*$"#;
+    pub const PLUGIN_KOTLIN_DSL_ID_ONLY: &str = r#"^\s*id\s*\(\s*["']([^"']+)["']\s*\)\s*(?:apply\s*\(?\s*(?:true|false)\s*\)?\s*)?$"#;
     pub const PLUGIN_KOTLIN_SHORTHAND_VERSION: &str = r#"^\s*kotlin\s*\(\s*["']([^"']+)["']\s*\)\s+version\s+["']([^"']+)["'].*$"#;
-    pub const PLUGIN_KOTLIN_SHORTHAND_ONLY: &str = r#"^\s*([a-zA-Z\-]+)\s*$"#;
+    pub const PLUGIN_KOTLIN_SHORTHAND_ID_ONLY: &str = r#"^\s*kotlin\s*\(\s*["']([^"']+)["']\s*\)\s*(?:apply\s*\(?\s*(?:true|false)\s*\)?\s*)?$"#;
+    pub const PLUGIN_KOTLIN_SHORTHAND_ONLY: &str = r#"^\s*`?([a-zA-Z\-]+)`?\s*$"#;
     pub const APPLY_PLUGIN: &str = r#"^\s*apply\s*\(\s*plugin\s*=\s*["']([^"']+)["']\s*\).*$"#;
     pub const APPLY_PLUGIN_GROOVY: &str = r#"^\s*apply\s+plugin\s*:\s*["']([^"']+)["'].*$"#;
-    pub const LIBS_PLUGIN: &str = r#"^\s*alias\s*\(\s*libs\.plugins\.([a-zA-Z0-9\.\-_]+)\s*\).*$"#;
+    // A whole line holding nothing but one apply plugin statement
+    pub const APPLY_PLUGIN_STATEMENT: &str = r#"^\s*apply\s*\(\s*plugin\s*=\s*["'][^"']+["']\s*\)\s*;?\s*$"#;
+    pub const APPLY_PLUGIN_GROOVY_STATEMENT: &str = r#"^\s*apply\s+plugin\s*:\s*["'][^"']+["']\s*;?\s*$"#;
+    pub const PLUGIN_APPLY_FALSE: &str = r#"\bapply\s*\(?\s*false\s*\)?\s*$"#;
+    pub const LIBS_PLUGIN: &str = r#"^\s*alias\s*\(\s*(\w+)\.plugins\.([a-zA-Z0-9\.\-_]+)\s*\).*$"#;
+    
+    // Settings patterns
+    pub const INCLUDE_BUILD: &str = r#"^\s*includeBuild\s*\(?\s*["']([^"']+)["']"#;
+    pub const ROOT_PROJECT_NAME: &str = r#"^\s*rootProject\.name\s*=\s*["']([^"']+)["']"#;
+    pub const INCLUDE_PROJECTS: &str = r#"^\s*include\s*\(?\s*["']"#;
+    pub const REPOSITORIES_MODE: &str = r"\brepositoriesMode\b.*\bRepositoriesMode\.(\w+)";
+    pub const QUOTED_STRING: &str = r#"["']([^"']+)["']"#;
+    pub const VERSION_CATALOGS_BLOCK: &str = r"^\s*versionCatalogs\s*\{";
+    pub const VERSION_CATALOG_CREATE: &str = r#"^\s*(?:(?:create|register)\s*\(\s*["'](\w+)["']\s*\)|(\w+))\s*\{"#;
+    pub const VERSION_CATALOG_FROM_FILES: &str = r#"from\s*\(\s*files\s*\(\s*["']([^"']+)["']"#;
+    
+    // Cross-project configuration blocks in the root build file
+    pub const CROSS_PROJECT_BLOCK: &str = r#"^\s*(allprojects|subprojects)\s*\{"#;
+    pub const CONFIGURE_BLOCK: &str = r#"^\s*configure\s*\((.*)\)\s*\{"#;
+    pub const PROJECT_REFERENCE: &str = r#"project\s*\(\s*["']([^"']+)["']\s*\)"#;
+    pub const PROJECTS_ACCESSOR_REFERENCE: &str = r"\bprojects\.([a-zA-Z]\w*(?:\.[a-zA-Z]\w*)*)";
+    
+    // Android extension settings, read from `android { }` and its `defaultConfig { }`
+    pub const ANDROID_BLOCK: &str = r"android";
+    pub const DEFAULT_CONFIG_BLOCK: &str = r"defaultConfig";
+    // Text in front of an opening brace that names the block, e.g. `defaultConfig ` of `defaultConfig {`
+    pub const BLOCK_NAME: &str = r"^\s*([\w.]+)\s*$";
+    pub const ANDROID_SETTING: &str = r#"^\s*(compileSdkVersion|compileSdk|minSdkVersion|minSdk|targetSdkVersion|targetSdk|buildToolsVersion|namespace)\s*([=(].*|\s.*)$"#;
+    
+    // JVM toolchain, Kotlin jvmTarget and Java compatibility settings, wherever they are configured
+    pub const JVM_TOOLCHAIN: &str = r"\b(?:jvmToolchain|JavaLanguageVersion\.of)\s*(\(.*)$";
+    pub const KOTLIN_JVM_TARGET: &str = r"\bjvmTarget(?:\.set)?\s*([=(].*)$";
+    pub const JAVA_COMPATIBILITY: &str = r"\b(sourceCompatibility|targetCompatibility)(?:\.set)?\s*([=(].*|\s+\S.*)$";
+    pub const JAVA_VERSION_NUMBER: &str = r"(\d+)(?:[._](\d+))?";
+    
+    // Gradle wrapper distribution and JDK selection
+    pub const GRADLE_DISTRIBUTION_URL: &str = r"^\s*distributionUrl\s*=.*gradle-([^/\\]+?)-(?:bin|all)\.zip";
+    pub const DAEMON_JVM_TOOLCHAIN_VERSION: &str = r"^\s*toolchainVersion\s*=\s*(\S+)";
+    pub const JAVA_VERSION_LINE: &str = r"^\s*(\S+)";
+    pub const TOOL_VERSIONS_JAVA: &str = r"^\s*java\s+(\S+)";
+    
+    // Version catalog usage and TOML layout
+    pub const CATALOG_REFERENCE: &str = r"\b([a-zA-Z]\w*)\.([a-zA-Z][\w\-]*(?:\.[a-zA-Z][\w\-]*)*)";
+    pub const CATALOG_LOOKUP: &str = r#"\b(\w+)\.find(Library|Plugin|Bundle|Version)\s*\(\s*["']([^"']+)["']"#;
+    pub const TOML_TABLE_HEADER: &str = r#"^\s*\[\s*([\w.\-"]+)\s*\]"#;
+    pub const TOML_KEY: &str = r#"^\s*("[^"]+"|[\w\-]+)\s*="#;
+    
+    // Indentation, configuration and call style of a single-line dependency declaration
+    pub const DECLARATION_CONFIGURATION: &str = r"^(\s*)(\w+)\s*(\()?";
+}
+
+/// Mapping between buildscript classpath artifacts, legacy plugin ids and the plugins they provide
+pub mod plugin_patterns {
+    /// (classpath group:artifact, plugin id prefix provided by that artifact)
+    pub const CLASSPATH_PLUGIN_IDS: &[(&str, &str)] = &[
+        ("com.android.tools.build:gradle", "com.android."),
+        ("org.jetbrains.kotlin:kotlin-gradle-plugin", "org.jetbrains.kotlin."),
+        ("com.google.gms:google-services", "com.google.gms.google-services"),
+        ("com.google.dagger:hilt-android-gradle-plugin", "com.google.dagger.hilt.android"),
+        ("com.google.firebase:firebase-crashlytics-gradle", "com.google.firebase.crashlytics"),
+        ("androidx.navigation:navigation-safe-args-gradle-plugin", "androidx.navigation.safeargs"),
+        ("com.google.devtools.ksp:symbol-processing-gradle-plugin", "com.google.devtools.ksp"),
+    ];
+    
+    /// (legacy or shorthand id, canonical plugin id)
+    pub const LEGACY_PLUGIN_IDS: &[(&str, &str)] = &[
+        ("android", "com.android.application"),
+        ("android-library", "com.android.library"),
+        ("kotlin", "org.jetbrains.kotlin.jvm"),
+        ("kotlin-android", "org.jetbrains.kotlin.android"),
+        ("kotlin-android-extensions", "org.jetbrains.kotlin.android.extensions"),
+        ("kotlin-kapt", "org.jetbrains.kotlin.kapt"),
+        ("kotlin-multiplatform", "org.jetbrains.kotlin.multiplatform"),
+        ("kotlin-parcelize", "org.jetbrains.kotlin.plugin.parcelize"),
+        ("kotlin-allopen", "org.jetbrains.kotlin.plugin.allopen"),
+        ("kotlin-noarg", "org.jetbrains.kotlin.plugin.noarg"),
+        ("kotlin-spring", "org.jetbrains.kotlin.plugin.spring"),
+        ("kotlin-jpa", "org.jetbrains.kotlin.plugin.jpa"),
+        ("kotlinx-serialization", "org.jetbrains.kotlin.plugin.serialization"),
+        ("dagger.hilt.android.plugin", "com.google.dagger.hilt.android"),
+        ("kotlin-dsl", "org.gradle.kotlin.kotlin-dsl"),
+        ("org.gradle.java", "java"),
+        ("org.gradle.java-library", "java-library"),
+        ("org.gradle.application", "application"),
+        ("org.gradle.maven-publish", "maven-publish"),
+    ];
+    
+    /// Plugins the Kotlin DSL exposes as bare accessors in the plugins block, e.g. `java` or `` `kotlin-dsl` ``
+    pub const PLUGIN_ACCESSORS: &[&str] = &[
+        "application", "base", "checkstyle", "distribution", "eclipse", "groovy", "idea", "jacoco",
+        "java", "java-gradle-plugin", "java-library", "java-platform", "java-test-fixtures", "jvm-test-suite",
+        "kotlin-dsl", "maven-publish", "ivy-publish", "pmd", "scala", "signing", "version-catalog", "war",
+    ];
+    
+    /// Android Gradle plugin ids; application modules merge the manifests of the libraries they depend on
+    pub const ANDROID_PLUGIN_ID_PREFIX: &str = "com.android.";
+    pub const ANDROID_APPLICATION_PLUGIN_ID: &str = "com.android.application";
+    
+    /// Classpath artifacts whose versions the compatibility matrix is keyed by
+    pub const AGP_CLASSPATH_ARTIFACT: &str = "com.android.tools.build:gradle";
+    pub const KOTLIN_CLASSPATH_ARTIFACT: &str = "org.jetbrains.kotlin:kotlin-gradle-plugin";
+    
+    /// Plugin marker artifacts are published as `<id>:<id>.gradle.plugin`
+    pub const PLUGIN_MARKER_SUFFIX: &str = ".gradle.plugin";
 }
\ No newline at end of file
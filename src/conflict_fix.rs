@@ -0,0 +1,423 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::composite::discover_builds;
+use crate::error::Result;
+use crate::parser::{load_version_catalogs, DependencyLocation, DependencySourceType};
+use crate::rewrite::FileChange;
+use crate::version::{highest_version, lowest_version};
+use crate::version_catalog::{catalog_entry_lines, VersionCatalog};
+use regex::{Captures, Regex};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// How the version every declaration converges on is chosen
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionStrategy {
+    Highest,
+    Lowest,
+    /// The version the project's version catalog declares
+    Catalog,
+    Explicit(String),
+}
+
+impl FromStr for VersionStrategy {
+    type Err = String;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value {
+            "highest" => Ok(VersionStrategy::Highest),
+            "lowest" => Ok(VersionStrategy::Lowest),
+            "catalog" => Ok(VersionStrategy::Catalog),
+            "" => Err("strategy must not be empty".to_string()),
+            version => Ok(VersionStrategy::Explicit(version.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for VersionStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VersionStrategy::Highest => write!(f, "highest"),
+            VersionStrategy::Lowest => write!(f, "lowest"),
+            VersionStrategy::Catalog => write!(f, "catalog"),
+            VersionStrategy::Explicit(version) => write!(f, "{}", version),
+        }
+    }
+}
+
+/// A conflict aligned on one version
+#[derive(Debug, Clone)]
+pub struct ConflictResolution {
+    pub dependency_key: String,
+    pub version: String,
+    /// Declarations whose version is rewritten, directly or through the catalog entry they reference
+    pub rewritten: Vec<DependencyLocation>,
+}
+
+/// A conflict, or one declaration of it, that could not be rewritten
+#[derive(Debug, Clone)]
+pub struct SkippedConflictFix {
+    pub dependency_key: String,
+    /// None when the whole conflict was skipped
+    pub location: Option<DependencyLocation>,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConflictFixPlan {
+    /// Rewritten files in path order
+    pub changes: Vec<FileChange>,
+    pub resolutions: Vec<ConflictResolution>,
+    pub skipped: Vec<SkippedConflictFix>,
+}
+
+/// Where on a line the version of a declaration is written
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum VersionSite {
+    /// "group:artifact:version" string notation
+    StringNotation { group: String, artifact: String },
+    /// version: 'v' / version = "v" in map notation or an inline catalog library
+    VersionAttribute,
+    /// A key of the catalog `[versions]` table
+    CatalogVersionKey(String),
+}
+
+/// One line to rewrite and the declarations it holds the version of
+struct VersionEdit {
+    site: VersionSite,
+    old_version: String,
+    new_version: String,
+    dependency_key: String,
+    locations: Vec<DependencyLocation>,
+}
+
+/// Version catalogs of each build, deepest root first so nested builds win
+struct BuildCatalogs {
+    roots: Vec<(PathBuf, HashMap<PathBuf, VersionCatalog>)>,
+    entry_lines: HashMap<PathBuf, HashMap<(String, String), usize>>,
+}
+
+impl BuildCatalogs {
+    fn load(root_path: &Path) -> Result<Self> {
+        let builds = discover_builds(root_path)?;
+        let mut roots = Vec::new();
+        for build in &builds {
+            roots.push((build.root_path.clone(), load_version_catalogs(&build.root_path)?));
+        }
+        roots.sort_by_key(|(root, _)| std::cmp::Reverse(root.components().count()));
+
+        let mut entry_lines = HashMap::new();
+        for (_, catalogs) in &roots {
+            for catalog_path in catalogs.keys() {
+                let content = fs::read_to_string(catalog_path)?;
+                entry_lines.insert(catalog_path.clone(), catalog_entry_lines(&content)?);
+            }
+        }
+
+        Ok(Self { roots, entry_lines })
+    }
+
+    /// Catalogs of the build `file_path` belongs to, in path order
+    fn catalogs_for(&self, file_path: &Path) -> Vec<(&PathBuf, &VersionCatalog)> {
+        let mut catalogs: Vec<(&PathBuf, &VersionCatalog)> = self.roots.iter()
+            .find(|(root, _)| file_path.starts_with(root))
+            .map(|(_, catalogs)| catalogs.iter().collect())
+            .unwrap_or_default();
+        catalogs.sort_by(|a, b| a.0.cmp(b.0));
+        catalogs
+    }
+
+    /// Version the catalogs declare for a coordinate, searching the root build first
+    fn declared_version(&self, group: &str, artifact: &str) -> Option<String> {
+        let mut roots: Vec<&(PathBuf, HashMap<PathBuf, VersionCatalog>)> = self.roots.iter().collect();
+        roots.reverse();
+        for (_, catalogs) in roots {
+            let mut catalog_paths: Vec<&PathBuf> = catalogs.keys().collect();
+            catalog_paths.sort();
+            for catalog_path in catalog_paths {
+                if let Some((_, version)) = find_catalog_library(&catalogs[catalog_path], group, artifact, None) {
+                    return Some(version);
+                }
+            }
+        }
+        None
+    }
+
+    /// Line of the catalog entry holding the version of a library declared with `old_version`
+    fn version_line(
+        &self,
+        file_path: &Path,
+        accessor: Option<&str>,
+        group: &str,
+        artifact: &str,
+        old_version: &str,
+    ) -> Option<(PathBuf, usize, VersionSite)> {
+        for (catalog_path, catalog) in self.catalogs_for(file_path) {
+            if accessor.is_some_and(|accessor| !catalog.is_accessed_by(accessor)) {
+                continue;
+            }
+            let Some((alias, _)) = find_catalog_library(catalog, group, artifact, Some(old_version)) else {
+                continue;
+            };
+            let entry_lines = self.entry_lines.get(catalog_path)?;
+            let library = catalog.libraries.as_ref()?.get(&alias)?;
+            let (section, key, site) = match library.version_reference() {
+                Some(key) => ("versions", key.to_string(), VersionSite::CatalogVersionKey(key.to_string())),
+                None => ("libraries", alias.clone(), VersionSite::VersionAttribute),
+            };
+            let line_number = *entry_lines.get(&(section.to_string(), key))?;
+            return Some((catalog_path.clone(), line_number, site));
+        }
+        None
+    }
+}
+
+/// Alias and resolved version of the first library (by alias) with the coordinate, optionally at a version
+fn find_catalog_library(catalog: &VersionCatalog, group: &str, artifact: &str, version: Option<&str>) -> Option<(String, String)> {
+    let libraries = catalog.libraries.as_ref()?;
+    let mut aliases: Vec<&String> = libraries.keys().collect();
+    aliases.sort();
+
+    aliases.into_iter().find_map(|alias| {
+        let (library_group, library_artifact, library_version) = catalog.resolve_library_version(alias)?;
+        let matches = library_group == group
+            && library_artifact == artifact
+            && version.is_none_or(|version| version == library_version);
+        matches.then(|| (alias.clone(), library_version))
+    })
+}
+
+/// Plans rewriting every conflicting declaration to the version `strategy` picks.
+/// `dependency_filter` restricts the fix to one `group:artifact[:classifier]` key.
+pub fn plan_conflict_fixes(
+    root_path: &Path,
+    version_conflicts: &HashMap<String, Vec<DependencyLocation>>,
+    strategy: &VersionStrategy,
+    dependency_filter: Option<&str>,
+) -> Result<ConflictFixPlan> {
+    let catalogs = BuildCatalogs::load(root_path)?;
+    let mut skipped = Vec::new();
+    let mut resolutions = Vec::new();
+    let mut edits: BTreeMap<(PathBuf, usize), Vec<VersionEdit>> = BTreeMap::new();
+    let mut file_contents: HashMap<PathBuf, String> = HashMap::new();
+
+    let mut keys: Vec<&String> = version_conflicts.keys().collect();
+    keys.sort();
+
+    for key in keys {
+        if dependency_filter.is_some_and(|filter| filter != key) {
+            continue;
+        }
+        let locations = &version_conflicts[key];
+        let Some(target) = target_version(locations, strategy, &catalogs) else {
+            skipped.push(SkippedConflictFix {
+                dependency_key: key.clone(),
+                location: None,
+                reason: match strategy {
+                    VersionStrategy::Catalog => "no version catalog declares this dependency".to_string(),
+                    _ => "no literal version to align on".to_string(),
+                },
+            });
+            continue;
+        };
+
+        let mut rewritten = Vec::new();
+        for location in locations {
+            let Some(old_version) = location.dependency.version.as_deref() else {
+                continue;
+            };
+            if old_version == target {
+                continue;
+            }
+            if old_version.contains('$') {
+                skipped.push(SkippedConflictFix {
+                    dependency_key: key.clone(),
+                    location: Some(location.clone()),
+                    reason: "version is interpolated from a variable".to_string(),
+                });
+                continue;
+            }
+
+            match locate_version(location, old_version, &catalogs, &mut file_contents)? {
+                Some((file_path, line_number, site)) => {
+                    let line_edits = edits.entry((file_path, line_number)).or_default();
+                    match line_edits.iter_mut().find(|edit| edit.site == site && edit.old_version == old_version) {
+                        Some(edit) => edit.locations.push(location.clone()),
+                        None => line_edits.push(VersionEdit {
+                            site,
+                            old_version: old_version.to_string(),
+                            new_version: target.clone(),
+                            dependency_key: key.clone(),
+                            locations: vec![location.clone()],
+                        }),
+                    }
+                    rewritten.push(location.clone());
+                }
+                None => skipped.push(SkippedConflictFix {
+                    dependency_key: key.clone(),
+                    location: Some(location.clone()),
+                    reason: "version could not be located in its declaration".to_string(),
+                }),
+            }
+        }
+
+        resolutions.push(ConflictResolution { dependency_key: key.clone(), version: target, rewritten });
+    }
+
+    // Two conflicts may want different versions from one shared catalog entry
+    let mut changes = Vec::new();
+    let mut updated_files: BTreeMap<PathBuf, Vec<String>> = BTreeMap::new();
+    for ((file_path, line_number), line_edits) in edits {
+        let conflicting: Vec<bool> = line_edits.iter()
+            .map(|edit| line_edits.iter().any(|other| other.site == edit.site && other.new_version != edit.new_version))
+            .collect();
+        if !updated_files.contains_key(&file_path) {
+            let content = read_cached(&file_path, &mut file_contents)?;
+            updated_files.insert(file_path.clone(), content.lines().map(str::to_string).collect());
+        }
+        let lines = updated_files.get_mut(&file_path).expect("lines of the file were just loaded");
+
+        for (edit, conflicting) in line_edits.into_iter().zip(conflicting) {
+            let rewritten_line = match lines.get(line_number - 1) {
+                Some(line) if !conflicting => rewrite_version(line, &edit.site, &edit.old_version, &edit.new_version)?,
+                _ => None,
+            };
+            match rewritten_line {
+                Some(line) => lines[line_number - 1] = line,
+                None => {
+                    let reason = if conflicting {
+                        "the shared catalog version is aligned to different versions by other conflicts"
+                    } else {
+                        "version could not be located in its declaration"
+                    };
+                    unmark_rewritten(&mut resolutions, &edit);
+                    skipped.extend(edit.locations.into_iter().map(|location| SkippedConflictFix {
+                        dependency_key: edit.dependency_key.clone(),
+                        location: Some(location),
+                        reason: reason.to_string(),
+                    }));
+                }
+            }
+        }
+    }
+
+    for (file_path, lines) in updated_files {
+        let original = file_contents.remove(&file_path).unwrap_or_default();
+        let mut updated = lines.join("\n");
+        if original.ends_with('\n') {
+            updated.push('\n');
+        }
+        if updated != original {
+            changes.push(FileChange { path: file_path, original, updated });
+        }
+    }
+
+    Ok(ConflictFixPlan { changes, resolutions, skipped })
+}
+
+fn target_version(locations: &[DependencyLocation], strategy: &VersionStrategy, catalogs: &BuildCatalogs) -> Option<String> {
+    let literal_versions = locations.iter()
+        .filter_map(|location| location.dependency.version.as_deref())
+        .filter(|version| !version.contains('$'));
+
+    match strategy {
+        VersionStrategy::Highest => highest_version(literal_versions).map(str::to_string),
+        VersionStrategy::Lowest => lowest_version(literal_versions).map(str::to_string),
+        VersionStrategy::Catalog => {
+            let dependency = &locations.first()?.dependency;
+            catalogs.declared_version(&dependency.group, &dependency.artifact)
+        }
+        VersionStrategy::Explicit(version) => Some(version.clone()),
+    }
+}
+
+/// File, line and position of the version a declaration resolves to
+fn locate_version(
+    location: &DependencyLocation,
+    old_version: &str,
+    catalogs: &BuildCatalogs,
+    file_contents: &mut HashMap<PathBuf, String>,
+) -> Result<Option<(PathBuf, usize, VersionSite)>> {
+    let dependency = &location.dependency;
+    let (file_path, line_number) = match &location.source_type {
+        DependencySourceType::Inherited(origin) => (&origin.file_path, origin.line_number),
+        _ => (&location.file_path, location.line_number),
+    };
+
+    let catalog_accessor = match &location.source_type {
+        DependencySourceType::VersionCatalog(reference) | DependencySourceType::CatalogBundle(reference) => {
+            Some(reference.split('.').next().unwrap_or_default())
+        }
+        _ => None,
+    };
+    if let Some(accessor) = catalog_accessor {
+        return Ok(catalogs.version_line(file_path, Some(accessor), &dependency.group, &dependency.artifact, old_version));
+    }
+
+    let content = read_cached(file_path, file_contents)?;
+    let line = content.lines().nth(line_number.saturating_sub(1)).unwrap_or_default();
+    let sites = [
+        VersionSite::StringNotation { group: dependency.group.clone(), artifact: dependency.artifact.clone() },
+        VersionSite::VersionAttribute,
+    ];
+    for site in sites {
+        if rewrite_version(line, &site, old_version, old_version)?.is_some() {
+            return Ok(Some((file_path.clone(), line_number, site)));
+        }
+    }
+
+    // A convention plugin may declare the dependency through a catalog accessor
+    if matches!(location.source_type, DependencySourceType::Inherited(_)) {
+        return Ok(catalogs.version_line(file_path, None, &dependency.group, &dependency.artifact, old_version));
+    }
+    Ok(None)
+}
+
+/// Replaces `old_version` at `site`, keeping the surrounding quotes and spacing
+fn rewrite_version(line: &str, site: &VersionSite, old_version: &str, new_version: &str) -> Result<Option<String>> {
+    let old_version = regex::escape(old_version);
+    let pattern = match site {
+        VersionSite::StringNotation { group, artifact } => format!(
+            r#"(["']{}:{}:){}((?::[^"'@]*)?(?:@[^"']*)?["'])"#,
+            regex::escape(group),
+            regex::escape(artifact),
+            old_version
+        ),
+        VersionSite::VersionAttribute => format!(r#"(\bversion\s*[:=]\s*["']){}(["'])"#, old_version),
+        VersionSite::CatalogVersionKey(key) => format!(r#"^(\s*"?{}"?\s*=\s*["']){}(["'])"#, regex::escape(key), old_version),
+    };
+    let regex = Regex::new(&pattern)?;
+
+    if !regex.is_match(line) {
+        return Ok(None);
+    }
+    let rewritten = regex.replacen(line, 1, |captures: &Captures| format!("{}{}{}", &captures[1], new_version, &captures[2]));
+    Ok(Some(rewritten.into_owned()))
+}
+
+fn read_cached<'a>(file_path: &Path, file_contents: &'a mut HashMap<PathBuf, String>) -> Result<&'a String> {
+    if !file_contents.contains_key(file_path) {
+        let content = fs::read_to_string(file_path)?;
+        file_contents.insert(file_path.to_path_buf(), content);
+    }
+    Ok(&file_contents[file_path])
+}
+
+/// Drops the declarations of an edit that failed from its conflict's rewritten list
+fn unmark_rewritten(resolutions: &mut [ConflictResolution], edit: &VersionEdit) {
+    if let Some(resolution) = resolutions.iter_mut().find(|resolution| resolution.dependency_key == edit.dependency_key) {
+        resolution.rewritten.retain(|rewritten| {
+            !edit.locations.iter().any(|location| {
+                location.file_path == rewritten.file_path && location.line_number == rewritten.line_number
+            })
+        });
+    }
+}
\ No newline at end of file
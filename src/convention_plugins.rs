@@ -0,0 +1,131 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::config::{regex_patterns, Config};
+use crate::error::Result;
+use crate::parser::{
+    DependencyLocation, DependencySourceType, InheritedFrom, PluginLocation, PluginSourceType,
+    find_precompiled_script_plugins, parse_dependencies_from_file, parse_plugins_from_file_with_config, precompiled_script_plugin_name,
+};
+use crate::version_catalog::VersionCatalog;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A precompiled script plugin from buildSrc or an included build-logic build
+#[derive(Debug, Clone, Serialize)]
+pub struct ConventionPlugin {
+    pub id: String,
+    pub file_path: PathBuf,
+    pub dependencies: Vec<DependencyLocation>,
+    pub plugins: Vec<PluginLocation>,
+}
+
+pub fn load_convention_plugins(
+    root_path: &Path,
+    version_catalogs: &HashMap<PathBuf, VersionCatalog>,
+    config: &Config,
+) -> Result<HashMap<String, ConventionPlugin>> {
+    let package_regex = Regex::new(regex_patterns::PACKAGE_DECLARATION)?;
+    let mut conventions = HashMap::new();
+    
+    for script_file in find_precompiled_script_plugins(root_path)? {
+        let name = match precompiled_script_plugin_name(&script_file) {
+            Some(name) => name,
+            None => continue,
+        };
+        
+        // Kotlin scripts declaring a package are applied as <package>.<name>
+        let content = fs::read_to_string(&script_file)?;
+        let id = match content.lines().find_map(|line| package_regex.captures(line)) {
+            Some(captures) => format!("{}.{}", &captures[1], name),
+            None => name,
+        };
+        
+        let dependencies = parse_dependencies_from_file(&script_file, version_catalogs)?;
+        let plugins = parse_plugins_from_file_with_config(&script_file, version_catalogs, config)?;
+        
+        conventions.insert(id.clone(), ConventionPlugin {
+            id,
+            file_path: script_file,
+            dependencies,
+            plugins,
+        });
+    }
+    
+    Ok(conventions)
+}
+
+/// Attributes the dependencies and plugins of each applied convention plugin to the module applying it
+pub fn apply_convention_plugins(
+    conventions: &HashMap<String, ConventionPlugin>,
+    dependencies: &mut Vec<DependencyLocation>,
+    plugins: &mut Vec<PluginLocation>,
+) {
+    let applications: Vec<PluginLocation> = plugins
+        .iter()
+        .filter(|plugin| conventions.contains_key(&plugin.plugin.id))
+        .cloned()
+        .collect();
+    
+    for application in applications {
+        let mut visited = HashSet::new();
+        inherit_convention(&application.plugin.id, &application, conventions, &mut visited, dependencies, plugins);
+    }
+}
+
+fn inherit_convention(
+    convention_id: &str,
+    application: &PluginLocation,
+    conventions: &HashMap<String, ConventionPlugin>,
+    visited: &mut HashSet<String>,
+    dependencies: &mut Vec<DependencyLocation>,
+    plugins: &mut Vec<PluginLocation>,
+) {
+    if !visited.insert(convention_id.to_string()) {
+        return;
+    }
+    let convention = match conventions.get(convention_id) {
+        Some(convention) => convention,
+        None => return,
+    };
+    
+    for dep in &convention.dependencies {
+        dependencies.push(DependencyLocation {
+            dependency: dep.dependency.clone(),
+            file_path: application.file_path.clone(),
+            line_number: application.line_number,
+            configuration: dep.configuration.clone(),
+            source_type: DependencySourceType::Inherited(InheritedFrom {
+                from: convention.id.clone(),
+                file_path: dep.file_path.clone(),
+                line_number: dep.line_number,
+            }),
+        });
+    }
+    
+    for plugin in &convention.plugins {
+        plugins.push(PluginLocation {
+            plugin: plugin.plugin.clone(),
+            file_path: application.file_path.clone(),
+            line_number: application.line_number,
+            source_type: PluginSourceType::Inherited(InheritedFrom {
+                from: convention.id.clone(),
+                file_path: plugin.file_path.clone(),
+                line_number: plugin.line_number,
+            }),
+            apply: plugin.apply,
+        });
+        
+        // Convention plugins can build on other convention plugins
+        if conventions.contains_key(&plugin.plugin.id) {
+            inherit_convention(&plugin.plugin.id, application, conventions, visited, dependencies, plugins);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,239 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::composite::GradleBuild;
+use crate::config::{file_patterns, regex_patterns};
+use crate::error::Result;
+use crate::parser::{DependencyLocation, DependencySourceType, InheritedFrom, PluginLocation, PluginSourceType};
+use crate::settings::load_build_settings;
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Projects a cross-project configuration block applies to
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProjectScope {
+    AllProjects,
+    Subprojects,
+    /// `configure(project(":a"), project(":b")) { }`, with paths stored without the leading `:`
+    Projects(Vec<String>),
+}
+
+impl ProjectScope {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProjectScope::AllProjects => "allprojects",
+            ProjectScope::Subprojects => "subprojects",
+            ProjectScope::Projects(_) => "configure",
+        }
+    }
+    
+    fn includes_root(&self) -> bool {
+        match self {
+            ProjectScope::AllProjects => true,
+            ProjectScope::Subprojects => false,
+            ProjectScope::Projects(paths) => paths.iter().any(|path| path.is_empty()),
+        }
+    }
+    
+    fn includes(&self, project_path: &str) -> bool {
+        match self {
+            ProjectScope::AllProjects | ProjectScope::Subprojects => true,
+            ProjectScope::Projects(paths) => paths.iter().any(|path| path == project_path),
+        }
+    }
+}
+
+/// A cross-project block of a root build file, spanning 1-based lines `start_line..=end_line`
+#[derive(Debug, Clone)]
+pub struct CrossProjectBlock {
+    pub scope: ProjectScope,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+pub fn find_cross_project_blocks(content: &str) -> Result<Vec<CrossProjectBlock>> {
+    let block_regex = Regex::new(regex_patterns::CROSS_PROJECT_BLOCK)?;
+    let configure_regex = Regex::new(regex_patterns::CONFIGURE_BLOCK)?;
+    let project_reference_regex = Regex::new(regex_patterns::PROJECT_REFERENCE)?;
+    
+    let mut blocks = Vec::new();
+    let mut current_block: Option<(ProjectScope, usize)> = None;
+    let mut brace_count: i32 = 0;
+    
+    for (line_number, line) in content.lines().enumerate() {
+        if current_block.is_none() {
+            let scope = if let Some(captures) = block_regex.captures(line) {
+                match &captures[1] {
+                    "allprojects" => Some(ProjectScope::AllProjects),
+                    _ => Some(ProjectScope::Subprojects),
+                }
+            } else if let Some(captures) = configure_regex.captures(line) {
+                parse_configure_scope(&captures[1], &project_reference_regex)
+            } else {
+                None
+            };
+            
+            match scope {
+                Some(scope) => {
+                    current_block = Some((scope, line_number + 1));
+                    brace_count = 0;
+                }
+                None => continue,
+            }
+        }
+        
+        brace_count += line.matches('{').count() as i32;
+        brace_count -= line.matches('}').count() as i32;
+        
+        if brace_count <= 0 {
+            if let Some((scope, start_line)) = current_block.take() {
+                blocks.push(CrossProjectBlock {
+                    scope,
+                    start_line,
+                    end_line: line_number + 1,
+                });
+            }
+        }
+    }
+    
+    Ok(blocks)
+}
+
+/// Scope of `configure(...)`; filtered project sets cannot be evaluated and are skipped
+fn parse_configure_scope(arguments: &str, project_reference_regex: &Regex) -> Option<ProjectScope> {
+    match arguments.trim() {
+        "allprojects" => Some(ProjectScope::AllProjects),
+        "subprojects" => Some(ProjectScope::Subprojects),
+        arguments => {
+            let paths: Vec<String> = project_reference_regex
+                .captures_iter(arguments)
+                .map(|captures| captures[1].trim_start_matches(':').to_string())
+                .collect();
+            if paths.is_empty() {
+                None
+            } else {
+                Some(ProjectScope::Projects(paths))
+            }
+        }
+    }
+}
+
+/// Moves declarations made in cross-project blocks of each root build file onto the
+/// projects included in settings, as entries inherited from the block
+pub fn apply_cross_project_configuration(
+    builds: &[GradleBuild],
+    dependencies: &mut Vec<DependencyLocation>,
+    plugins: &mut Vec<PluginLocation>,
+) -> Result<()> {
+    for build in builds {
+        let root_build_file = match find_build_file(&build.root_path) {
+            Some(build_file) => build_file,
+            None => continue,
+        };
+        let blocks = find_cross_project_blocks(&fs::read_to_string(&root_build_file)?)?;
+        if blocks.is_empty() {
+            continue;
+        }
+        
+        let settings = load_build_settings(&build.root_path)?;
+        let projects: Vec<(String, PathBuf)> = settings
+            .included_projects
+            .iter()
+            .map(|project_path| {
+                let project_path = project_path.trim_start_matches(':').to_string();
+                let build_file = project_build_file(&build.root_path, &project_path);
+                (project_path, build_file)
+            })
+            .collect();
+        
+        let mut inherited_dependencies = Vec::new();
+        dependencies.retain(|dep| {
+            let block = match enclosing_block(&blocks, &root_build_file, &dep.file_path, dep.line_number) {
+                Some(block) => block,
+                None => return true,
+            };
+            
+            for (project_path, build_file) in &projects {
+                if block.scope.includes(project_path) {
+                    // Line 0: the declaration applies to the whole project file
+                    inherited_dependencies.push(DependencyLocation {
+                        dependency: dep.dependency.clone(),
+                        file_path: build_file.clone(),
+                        line_number: 0,
+                        configuration: dep.configuration.clone(),
+                        source_type: DependencySourceType::Inherited(InheritedFrom {
+                            from: block.scope.label().to_string(),
+                            file_path: dep.file_path.clone(),
+                            line_number: dep.line_number,
+                        }),
+                    });
+                }
+            }
+            block.scope.includes_root()
+        });
+        dependencies.extend(inherited_dependencies);
+        
+        let mut inherited_plugins = Vec::new();
+        plugins.retain(|plugin| {
+            let block = match enclosing_block(&blocks, &root_build_file, &plugin.file_path, plugin.line_number) {
+                Some(block) => block,
+                None => return true,
+            };
+            
+            for (project_path, build_file) in &projects {
+                if block.scope.includes(project_path) {
+                    inherited_plugins.push(PluginLocation {
+                        plugin: plugin.plugin.clone(),
+                        file_path: build_file.clone(),
+                        line_number: 0,
+                        source_type: PluginSourceType::Inherited(InheritedFrom {
+                            from: block.scope.label().to_string(),
+                            file_path: plugin.file_path.clone(),
+                            line_number: plugin.line_number,
+                        }),
+                        apply: plugin.apply,
+                    });
+                }
+            }
+            block.scope.includes_root()
+        });
+        plugins.extend(inherited_plugins);
+    }
+    
+    Ok(())
+}
+
+fn enclosing_block<'a>(
+    blocks: &'a [CrossProjectBlock],
+    root_build_file: &Path,
+    file_path: &Path,
+    line_number: usize,
+) -> Option<&'a CrossProjectBlock> {
+    if file_path != root_build_file {
+        return None;
+    }
+    blocks.iter().find(|block| (block.start_line..=block.end_line).contains(&line_number))
+}
+
+fn find_build_file(project_dir: &Path) -> Option<PathBuf> {
+    file_patterns::GRADLE_BUILD_FILES
+        .iter()
+        .map(|filename| project_dir.join(filename))
+        .find(|build_file| build_file.is_file())
+}
+
+/// Build file of a project path such as `feature:login`; falls back to the project
+/// directory when the project has no build file of its own
+fn project_build_file(build_root: &Path, project_path: &str) -> PathBuf {
+    let project_dir = project_path
+        .split(':')
+        .filter(|segment| !segment.is_empty())
+        .fold(build_root.to_path_buf(), |dir, segment| dir.join(segment));
+    
+    find_build_file(&project_dir).unwrap_or(project_dir)
+}
\ No newline at end of file
@@ -5,11 +5,45 @@
  * See LICENSE file in the project root for full license information.
  */
 
+use crate::analyzer::PluginVersionConflict;
 use crate::config::BundleNamePatterns;
+use crate::dynamic_versions::DynamicVersion;
+use crate::version::DynamicVersionKind;
+use crate::findings::{FindingsByFile, Severity};
 use crate::parser::{DependencyLocation, DependencySourceType, PluginLocation, PluginSourceType};
-use crate::bundle_analyzer::{DependencyBundle, BundleAnalysis};
+use crate::bundle_analyzer::{generate_bundle_name, DependencyBundle, BundleAnalysis};
+use crate::bundle_catalog::CatalogBundlePlan;
+use crate::bundle_conventions::ConventionPluginPlan;
+use crate::composite::BuildDependencyLocation;
+use crate::catalog_analyzer::HardcodedCatalogDependency;
+use crate::catalog_migration::CatalogMigration;
+use crate::plugins_dsl_migration::PluginsDslMigration;
+use crate::conflict_fix::ConflictFixPlan;
+use crate::rewrite::FileChange;
+use crate::similarity::ModuleCluster;
 use colored::*;
 use std::collections::HashMap;
+use std::path::Path;
+
+fn dependency_source_label(source_type: &DependencySourceType) -> String {
+    match source_type {
+        DependencySourceType::Direct => String::new(),
+        DependencySourceType::VersionCatalog(reference) => format!(" [via {}]", reference),
+        DependencySourceType::CatalogBundle(reference) => format!(" [via {}]", reference),
+        DependencySourceType::Inherited(origin) => format!(" [inherited from {}]", origin.from),
+    }
+}
+
+fn plugin_source_label(source_type: &PluginSourceType) -> String {
+    match source_type {
+        PluginSourceType::PluginsBlock => " [plugins block]".to_string(),
+        PluginSourceType::ApplyPlugin => " [apply plugin]".to_string(),
+        PluginSourceType::VersionCatalog(reference) => format!(" [via {}]", reference),
+        PluginSourceType::BuildscriptClasspath(coordinate) => format!(" [buildscript classpath {}]", coordinate),
+        PluginSourceType::PluginManagement => " [pluginManagement]".to_string(),
+        PluginSourceType::Inherited(origin) => format!(" [inherited from {}]", origin.from),
+    }
+}
 
 pub fn print_regular_duplicates(duplicates: &HashMap<String, Vec<DependencyLocation>>) {
     for (dependency_key, locations) in duplicates {
@@ -21,10 +55,7 @@ pub fn print_regular_duplicates(duplicates: &HashMap<String, Vec<DependencyLocat
                 .map(|v| format!(" (version: {})", v.bold()))
                 .unwrap_or_default();
             
-            let source_str = match &location.source_type {
-                DependencySourceType::Direct => "",
-                DependencySourceType::VersionCatalog(ref_name) => &format!(" [via libs.{}]", ref_name),
-            };
+            let source_str = dependency_source_label(&location.source_type);
                 
             println!("  📍 {}:{} - {} configuration{}{}",
                 location.file_path.display(),
@@ -47,10 +78,7 @@ pub fn print_version_conflicts(conflicts: &HashMap<String, Vec<DependencyLocatio
                 .map(|v| format!(" (version: {})", v.red().bold()).to_string())
                 .unwrap_or_default();
             
-            let source_str = match &location.source_type {
-                DependencySourceType::Direct => "",
-                DependencySourceType::VersionCatalog(ref_name) => &format!(" [via libs.{}]", ref_name),
-            };
+            let source_str = dependency_source_label(&location.source_type);
                 
             println!("  {} {}:{} - {} configuration{}{}",
                 "⚠️".red(),
@@ -93,9 +121,9 @@ fn print_bundle_recommendation(bundle: &DependencyBundle, rank: usize) {
     
     // Show dependencies
     println!("   {}", "Dependencies:".bright_white());
-    for (i, dep) in bundle.dependencies.iter().enumerate() {
-        let prefix = if i == bundle.dependencies.len() - 1 { "└─" } else { "├─" };
-        println!("     {} {}", prefix.dimmed(), dep);
+    for (i, member) in bundle.members.iter().enumerate() {
+        let prefix = if i == bundle.members.len() - 1 { "└─" } else { "├─" };
+        println!("     {} {} {}", prefix.dimmed(), member.dependency, format!("({})", member.configuration).dimmed());
     }
     
     // Show configurations
@@ -137,27 +165,6 @@ fn print_bundle_recommendation(bundle: &DependencyBundle, rank: usize) {
     );
 }
 
-fn generate_bundle_name(dependencies: &[String], patterns: &BundleNamePatterns) -> String {
-    let most_common_group = find_most_common_group(dependencies);
-    patterns.find_bundle_name(&most_common_group)
-}
-
-fn find_most_common_group(dependencies: &[String]) -> String {
-    let common_groups: HashMap<String, usize> = dependencies
-        .iter()
-        .map(|dep| dep.split(':').next().unwrap_or("unknown").to_string())
-        .fold(HashMap::new(), |mut acc, group| {
-            *acc.entry(group).or_insert(0) += 1;
-            acc
-        });
-    
-    common_groups
-        .iter()
-        .max_by_key(|(_, count)| *count)
-        .map(|(group, _)| group.clone())
-        .unwrap_or_else(|| "common".to_string())
-}
-
 pub fn print_duplicate_plugins(duplicates: &HashMap<String, Vec<PluginLocation>>) {
     for (plugin_id, locations) in duplicates {
         println!("\n🔌 Plugin: {}", plugin_id);
@@ -168,21 +175,452 @@ pub fn print_duplicate_plugins(duplicates: &HashMap<String, Vec<PluginLocation>>
                 .map(|v| format!(" (version: {})", v.bold()))
                 .unwrap_or_default();
             
-            let source_str = match &location.source_type {
-                PluginSourceType::PluginsBlock => " [plugins block]",
-                PluginSourceType::ApplyPlugin => " [apply plugin]",
-                PluginSourceType::VersionCatalog(ref_name) => {
-                    &format!(" [via libs.plugins.{}]", ref_name)
-                },
-            };
+            let source_str = plugin_source_label(&location.source_type);
                 
-            println!("  🔍 {}:{} - {}{}{}",
+            println!("  🔍 {}:{} - plugin{}{}",
+                location.file_path.display(),
+                location.line_number,
+                version_str,
+                source_str.dimmed()
+            );
+        }
+    }
+}
+
+pub fn print_plugin_version_conflicts(conflicts: &HashMap<String, PluginVersionConflict>) {
+    let mut conflicts: Vec<_> = conflicts.iter().collect();
+    conflicts.sort_by(|(a_key, a), (b_key, b)| b.severity.cmp(&a.severity).then_with(|| a_key.cmp(b_key)));
+    
+    for (plugin_key, conflict) in conflicts {
+        let severity = match conflict.severity {
+            Severity::High => "[major]".red().bold(),
+            Severity::Medium => "[minor]".yellow().bold(),
+            Severity::Low => "[patch]".dimmed(),
+        };
+        let pinned = if conflict.classpath_pinned { " [classpath]" } else { "" };
+        println!("\n🔌 Plugin: {} {}{} {}", plugin_key.bold(), severity, pinned.cyan(), conflict.versions.join(", ").dimmed());
+        
+        for location in &conflict.locations {
+            let version_str = location.plugin.version
+                .as_ref()
+                .map(|v| format!(" (version: {})", v.red().bold()))
+                .unwrap_or_default();
+            
+            let source_str = plugin_source_label(&location.source_type);
+            
+            println!("  {} {}:{} - {}{}{}",
+                "⚠️".red(),
+                location.file_path.display(),
+                location.line_number,
+                location.plugin.id,
+                version_str,
+                source_str.dimmed()
+            );
+        }
+    }
+}
+
+pub fn print_classpath_version_mismatches(mismatches: &HashMap<String, Vec<PluginLocation>>) {
+    for (plugin_key, locations) in mismatches {
+        println!("\n{} {}", "🧭".yellow(), format!("Plugin: {}", plugin_key).yellow().bold());
+        
+        for location in locations {
+            let version_str = location.plugin.version
+                .as_ref()
+                .map(|v| format!(" (version: {})", v.yellow().bold()))
+                .unwrap_or_default();
+            
+            let source_str = plugin_source_label(&location.source_type);
+            
+            println!("  {} {}:{} - {}{}{}",
+                "⚠️".yellow(),
                 location.file_path.display(),
                 location.line_number,
-                "plugin",
+                location.plugin.id,
                 version_str,
                 source_str.dimmed()
             );
         }
     }
+}
+
+pub fn print_cross_build_conflicts(conflicts: &HashMap<String, Vec<BuildDependencyLocation>>) {
+    for (dependency_key, locations) in conflicts {
+        println!("\n{} {}", "🏗️".red(), format!("Dependency: {}", dependency_key).red().bold());
+        
+        for build_location in locations {
+            let location = &build_location.location;
+            let version_str = location.dependency.version
+                .as_ref()
+                .map(|v| format!(" (version: {})", v.red().bold()))
+                .unwrap_or_default();
+            
+            let source_str = dependency_source_label(&location.source_type);
+            
+            println!("  {} [{}] {}:{} - {} configuration{}{}",
+                "⚠️".red(),
+                build_location.build.bold(),
+                location.file_path.display(),
+                location.line_number,
+                location.configuration,
+                version_str,
+                source_str.dimmed()
+            );
+        }
+    }
+}
+
+pub fn print_module_clusters(clusters: &[ModuleCluster]) {
+    for (index, cluster) in clusters.iter().enumerate() {
+        println!("\n{} {}. {} ({} modules, similarity ≥ {:.2})",
+            "🧩".cyan(),
+            index + 1,
+            "Similar modules".cyan().bold(),
+            cluster.modules.len().to_string().bright_cyan(),
+            cluster.similarity
+        );
+        
+        for (i, module) in cluster.modules.iter().enumerate() {
+            let prefix = if i == cluster.modules.len() - 1 { "└─" } else { "├─" };
+            println!("     {} {}", prefix.dimmed(), module.display());
+        }
+        
+        if cluster.core_dependencies.is_empty() {
+            println!("   {}", "No dependency is declared by every module".dimmed());
+        } else {
+            println!("   {} ({}):", "Core dependencies".bright_white(), cluster.core_dependencies.len());
+            for (i, dependency) in cluster.core_dependencies.iter().enumerate() {
+                let prefix = if i == cluster.core_dependencies.len() - 1 { "└─" } else { "├─" };
+                println!("     {} {}", prefix.dimmed(), dependency);
+            }
+        }
+        println!("   {} Consider a shared convention plugin or base module for these modules", "💭".bright_blue());
+    }
+}
+
+/// Findings of one analysis under a heading per file, e.g. `🤖 Build file`
+pub fn print_findings<K, S>(findings: &FindingsByFile<K, S>, heading: &str) {
+    for (file_path, file_findings) in findings {
+        println!("\n{}: {}", heading, file_path.display());
+        
+        for finding in file_findings {
+            println!("  {} {}:{} - {}",
+                "⚠️".yellow(),
+                file_path.display(),
+                finding.line_number,
+                finding.message
+            );
+        }
+    }
+}
+
+pub fn print_dynamic_version_findings(findings: &FindingsByFile<DynamicVersionKind, DynamicVersion>) {
+    for (file_path, file_findings) in findings {
+        println!("\n🎲 File: {}", file_path.display());
+        
+        for finding in file_findings {
+            let severity = match finding.subject.severity {
+                Severity::High => "[high]".red().bold(),
+                Severity::Medium => "[medium]".yellow().bold(),
+                Severity::Low => "[low]".dimmed(),
+            };
+            
+            println!("  {} {}:{} {} - {}",
+                "⚠️".yellow(),
+                file_path.display(),
+                finding.line_number,
+                severity,
+                finding.message
+            );
+        }
+    }
+}
+
+pub fn print_hardcoded_catalog_dependencies(hardcoded: &HashMap<String, Vec<HardcodedCatalogDependency>>) {
+    for (dependency_key, dependencies) in hardcoded {
+        println!("\n📝 Dependency: {}", dependency_key);
+        
+        for hardcoded_dependency in dependencies {
+            let location = &hardcoded_dependency.location;
+            let version_str = location.dependency.version
+                .as_ref()
+                .map(|v| format!(" (version: {})", v.bold()))
+                .unwrap_or_default();
+            let catalog_version_str = match (&hardcoded_dependency.catalog_version, hardcoded_dependency.version_differs) {
+                (Some(v), true) => format!(" (catalog version: {})", v.yellow().bold()),
+                (Some(v), false) => format!(" (catalog version: {})", v),
+                (None, _) => String::new(),
+            };
+            
+            println!("  📍 {}:{} - {} configuration{} → use {}{}",
+                location.file_path.display(),
+                location.line_number,
+                location.configuration,
+                version_str,
+                hardcoded_dependency.catalog_reference.bright_green(),
+                catalog_version_str
+            );
+        }
+    }
+}
+
+pub fn print_file_change_diffs(changes: &[FileChange], root_path: &Path) {
+    for change in changes {
+        for line in change.unified_diff(root_path).lines() {
+            if line.starts_with("+++") || line.starts_with("---") {
+                println!("{}", line.bold());
+            } else if line.starts_with("@@") {
+                println!("{}", line.cyan());
+            } else if line.starts_with('+') {
+                println!("{}", line.green());
+            } else if line.starts_with('-') {
+                println!("{}", line.red());
+            } else {
+                println!("{}", line);
+            }
+        }
+    }
+}
+
+pub fn print_catalog_migration(migration: &CatalogMigration, root_path: &Path, dry_run: bool) {
+    if dry_run {
+        print_file_change_diffs(&migration.changes, root_path);
+    }
+    
+    if migration.migrated.is_empty() {
+        println!("✅ No hardcoded dependencies to migrate.");
+    } else {
+        let verb = if dry_run { "Would migrate" } else { "Migrated" };
+        println!("\n{} {} {} into {}",
+            "📦".green(),
+            verb.green().bold(),
+            format!("{} declarations", migration.migrated.len()).green().bold(),
+            migration.catalog_path.display()
+        );
+        for migrated in &migration.migrated {
+            println!("  ✏️  {}:{} - {} → {}",
+                migrated.location.file_path.display(),
+                migrated.location.line_number,
+                migrated.location.dependency.key(),
+                migrated.catalog_reference.bright_green()
+            );
+        }
+    }
+    
+    if !migration.skipped.is_empty() {
+        println!("\n{} Skipped {} declarations:", "⏭️".yellow(), migration.skipped.len());
+        for skipped in &migration.skipped {
+            let location = &skipped.location;
+            let version_str = location.dependency.version
+                .as_ref()
+                .map(|v| format!(":{}", v))
+                .unwrap_or_default();
+            
+            println!("  📍 {}:{} - {}{} {}",
+                location.file_path.display(),
+                location.line_number,
+                location.dependency.key(),
+                version_str,
+                format!("({})", skipped.reason).dimmed()
+            );
+        }
+    }
+}
+
+pub fn print_plugins_dsl_migration(migration: &PluginsDslMigration, root_path: &Path, dry_run: bool) {
+    if dry_run {
+        print_file_change_diffs(&migration.changes, root_path);
+    }
+    
+    if migration.migrated.is_empty() {
+        println!("✅ No apply plugin statements to migrate.");
+    } else {
+        let verb = if dry_run { "Would move" } else { "Moved" };
+        println!("\n{} {} {} into the plugins block",
+            "🔌".green(),
+            verb.green().bold(),
+            format!("{} apply plugin statements", migration.migrated.len()).green().bold()
+        );
+        for migrated in &migration.migrated {
+            let note = if migrated.already_declared { " (already requested, statement removed)" } else { "" };
+            println!("  ✏️  {}:{} - {} → {}{}",
+                migrated.location.file_path.display(),
+                migrated.location.line_number,
+                migrated.statement,
+                migrated.location.plugin.id.bright_green(),
+                note.dimmed()
+            );
+        }
+    }
+    
+    if !migration.skipped.is_empty() {
+        println!("\n{} Skipped {} statements:", "⏭️".yellow(), migration.skipped.len());
+        for skipped in &migration.skipped {
+            println!("  📍 {}:{} - {} {}",
+                skipped.location.file_path.display(),
+                skipped.location.line_number,
+                skipped.location.plugin.id,
+                format!("({})", skipped.reason).dimmed()
+            );
+        }
+    }
+}
+
+pub fn print_conflict_fix_plan(plan: &ConflictFixPlan, root_path: &Path, dry_run: bool) {
+    if dry_run {
+        print_file_change_diffs(&plan.changes, root_path);
+    }
+    
+    let aligned: Vec<_> = plan.resolutions.iter().filter(|resolution| !resolution.rewritten.is_empty()).collect();
+    if aligned.is_empty() {
+        println!("✅ No conflicting versions to align.");
+    } else {
+        let verb = if dry_run { "Would align" } else { "Aligned" };
+        println!("\n{} {} {}",
+            "🔧".green(),
+            verb.green().bold(),
+            format!("{} version conflicts", aligned.len()).green().bold()
+        );
+        for resolution in aligned {
+            println!("  📦 {} → {}", resolution.dependency_key.bold(), resolution.version.bright_green());
+            for location in &resolution.rewritten {
+                let version_str = location.dependency.version.as_deref().unwrap_or_default();
+                println!("    ✏️  {}:{} - {} → {}",
+                    location.file_path.display(),
+                    location.line_number,
+                    version_str.red(),
+                    resolution.version.green()
+                );
+            }
+        }
+    }
+    
+    if !plan.skipped.is_empty() {
+        println!("\n{} Skipped {} fixes:", "⏭️".yellow(), plan.skipped.len());
+        for skipped in &plan.skipped {
+            match &skipped.location {
+                Some(location) => println!("  📍 {}:{} - {}:{} {}",
+                    location.file_path.display(),
+                    location.line_number,
+                    skipped.dependency_key,
+                    location.dependency.version.as_deref().unwrap_or_default(),
+                    format!("({})", skipped.reason).dimmed()
+                ),
+                None => println!("  📦 {} {}", skipped.dependency_key, format!("({})", skipped.reason).dimmed()),
+            }
+        }
+    }
+}
+pub fn print_catalog_bundle_plan(plan: &CatalogBundlePlan, root_path: &Path, applied: bool, dry_run: bool) {
+    if dry_run {
+        print_file_change_diffs(&plan.changes, root_path);
+    }
+    
+    if plan.bundles.is_empty() {
+        println!("✅ No bundle recommendations to emit.");
+    } else if !applied && !dry_run {
+        println!("\n{} {} {}:",
+            "📚".cyan(),
+            "Catalog bundles for".cyan().bold(),
+            plan.catalog_path.display()
+        );
+        if !plan.version_entries.is_empty() {
+            println!("[versions]");
+            for entry in &plan.version_entries {
+                println!("{}", entry.green());
+            }
+            println!();
+        }
+        if !plan.library_entries.is_empty() {
+            println!("[libraries]");
+            for entry in &plan.library_entries {
+                println!("{}", entry.green());
+            }
+            println!();
+        }
+        println!("[bundles]");
+        for entry in &plan.bundle_entries {
+            println!("{}", entry.green());
+        }
+    }
+    
+    for bundle in &plan.bundles {
+        if bundle.rewritten_modules.is_empty() {
+            continue;
+        }
+        let verb = if applied { "Replaced" } else { "Replace" };
+        println!("\n{} {} {} with {} in:",
+            "✏️".green(),
+            verb.green().bold(),
+            bundle.dependencies.join(", "),
+            bundle.reference.bright_green()
+        );
+        for module in &bundle.rewritten_modules {
+            println!("  📍 {}", module.display());
+        }
+    }
+    
+    if !plan.skipped_bundles.is_empty() || !plan.skipped_modules.is_empty() {
+        println!("\n{} Skipped:", "⏭️".yellow());
+        for skipped in &plan.skipped_bundles {
+            println!("  📎 {} {}", skipped.dependencies.join(", "), format!("({})", skipped.reason).dimmed());
+        }
+        for skipped in &plan.skipped_modules {
+            println!("  📍 {} - {} {}",
+                skipped.module.display(),
+                skipped.bundle_reference,
+                format!("({})", skipped.reason).dimmed()
+            );
+        }
+    }
+}
+pub fn print_convention_plugin_plan(plan: &ConventionPluginPlan, root_path: &Path, applied: bool, dry_run: bool) {
+    if dry_run {
+        print_file_change_diffs(&plan.changes, root_path);
+    }
+    
+    if plan.plugins.is_empty() {
+        println!("✅ No bundle recommendations to emit.");
+    } else if !applied && !dry_run {
+        // Without --apply or --dry-run the generated plugins are printed in full
+        for plugin in &plan.plugins {
+            if let Some(change) = plan.changes.iter().find(|change| change.path == plugin.path) {
+                println!("\n{} {}", "🧩".cyan(), plugin.path.display().to_string().cyan().bold());
+                print!("{}", change.updated.green());
+            }
+        }
+    }
+    
+    for plugin in &plan.plugins {
+        let verb = if applied { "Generated" } else { "Generate" };
+        println!("\n{} {} {} for {}",
+            "🧩".green(),
+            verb.green().bold(),
+            format!("id(\"{}\")", plugin.plugin_id).bright_green(),
+            plugin.dependencies.join(", ")
+        );
+        for module in &plugin.modules {
+            let lines: Vec<String> = module.removable_lines.iter().map(|line| line.to_string()).collect();
+            let removable = if lines.is_empty() {
+                "nothing to remove".to_string()
+            } else {
+                format!("remove lines {}", lines.join(", "))
+            };
+            println!("  📍 {} - apply the plugin, {}", module.build_file.display(), removable.dimmed());
+        }
+    }
+    
+    if plan.include_build_missing {
+        println!("\n{} Add {} to the pluginManagement block of the root settings file",
+            "💭".bright_blue(),
+            format!("includeBuild(\"{}\")", plan.build_logic_path.strip_prefix(root_path).unwrap_or(&plan.build_logic_path).display()).bright_green()
+        );
+    }
+    
+    if !plan.skipped_bundles.is_empty() {
+        println!("\n{} Skipped:", "⏭️".yellow());
+        for skipped in &plan.skipped_bundles {
+            println!("  📎 {} {}", skipped.dependencies.join(", "), format!("({})", skipped.reason).dimmed());
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,192 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::config::DynamicVersionSeverities;
+use crate::error::Result;
+use crate::findings::{Finding, FindingReporter, FindingsByFile, Severity};
+use crate::parser::{DependencyLocation, DependencySourceType, PluginLocation, PluginSourceType};
+use crate::version::DynamicVersionKind;
+use crate::version_catalog::{catalog_entry_lines, VersionCatalog, VersionRef};
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DynamicVersion {
+    pub severity: Severity,
+    /// `group:artifact`, plugin id or catalog entry such as `[versions] okhttp`
+    pub declaration: String,
+    pub version: String,
+    /// Configuration of a dependency declaration; catalog entries and plugins have none
+    pub configuration: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DynamicVersionAnalysis {
+    /// Keyed by build file or catalog
+    pub findings: FindingsByFile<DynamicVersionKind, DynamicVersion>,
+}
+
+/// Dynamic, ranged and snapshot versions of the `[versions]`, `[libraries]` and `[plugins]` entries of a build's catalogs,
+/// as serious as the most serious configuration among the build's `dependencies` referencing each entry
+pub fn find_dynamic_catalog_versions(
+    version_catalogs: &HashMap<PathBuf, VersionCatalog>,
+    dependencies: &[DependencyLocation],
+    severities: &DynamicVersionSeverities,
+) -> Result<FindingsByFile<DynamicVersionKind, DynamicVersion>> {
+    let mut findings = FindingReporter::new();
+
+    for (catalog_path, catalog) in version_catalogs {
+        let entry_lines = catalog_entry_lines(&fs::read_to_string(catalog_path)?)?;
+        let entry_configurations = catalog_entry_configurations(catalog, dependencies);
+        let mut entries: Vec<(&str, &str, &str)> = Vec::new();
+        for (key, version) in catalog.versions.iter().flatten() {
+            entries.push(("versions", key, version));
+        }
+        for (alias, library) in catalog.libraries.iter().flatten() {
+            if let Some(VersionRef::Direct(version)) = &library.version {
+                entries.push(("libraries", alias, version));
+            }
+        }
+        for (alias, plugin) in catalog.plugins.iter().flatten() {
+            if let Some(VersionRef::Direct(version)) = &plugin.version {
+                entries.push(("plugins", alias, version));
+            }
+        }
+
+        for (section, alias, version) in entries {
+            let Some(kind) = DynamicVersionKind::of(version) else { continue };
+            // Entries no dependency references, and plugins, are judged without a configuration
+            let configuration = entry_configurations
+                .get(&(section, alias))
+                .and_then(|configurations| configurations.iter().rev().max_by_key(|configuration| severities.severity(kind, Some(configuration))))
+                .copied();
+            let line_number = entry_lines.get(&(section.to_string(), alias.to_string())).copied().unwrap_or_default();
+            if let Some(finding) = dynamic_version_finding(format!("[{}] {}", section, alias), version, configuration, line_number, severities) {
+                findings.add(catalog_path, finding);
+            }
+        }
+    }
+
+    Ok(findings.finish())
+}
+
+/// Configurations of the dependencies declared through each `[libraries]` entry of `catalog`, and through the
+/// `[versions]` entry each library references
+fn catalog_entry_configurations<'a>(
+    catalog: &'a VersionCatalog,
+    dependencies: &'a [DependencyLocation],
+) -> HashMap<(&'static str, &'a str), BTreeSet<&'a str>> {
+    let mut configurations: HashMap<(&str, &str), BTreeSet<&str>> = HashMap::new();
+
+    for (alias, library) in catalog.libraries.iter().flatten() {
+        let Some((group, artifact)) = library.coordinate() else { continue };
+        let library_configurations: BTreeSet<&str> = dependencies
+            .iter()
+            .filter(|location| match &location.source_type {
+                DependencySourceType::VersionCatalog(reference) | DependencySourceType::CatalogBundle(reference) => {
+                    reference.split('.').next().is_some_and(|accessor| catalog.is_accessed_by(accessor))
+                }
+                _ => false,
+            })
+            .filter(|location| location.dependency.group == group && location.dependency.artifact == artifact)
+            .map(|location| location.configuration.as_str())
+            .collect();
+        if library_configurations.is_empty() {
+            continue;
+        }
+
+        let version_key = match &library.version {
+            Some(VersionRef::Reference { r#ref }) => Some(r#ref),
+            _ => library.version_ref.as_ref(),
+        };
+        if let Some(version_key) = version_key {
+            configurations.entry(("versions", version_key.as_str())).or_default().extend(&library_configurations);
+        }
+        configurations.entry(("libraries", alias.as_str())).or_default().extend(library_configurations);
+    }
+
+    configurations
+}
+
+/// Dynamic, ranged and snapshot versions declared in build files, together with the catalog findings of every build
+pub fn analyze_dynamic_versions(
+    dependencies: &[DependencyLocation],
+    plugins: &[PluginLocation],
+    catalog_findings: FindingsByFile<DynamicVersionKind, DynamicVersion>,
+    severities: &DynamicVersionSeverities,
+) -> DynamicVersionAnalysis {
+    let mut findings = FindingReporter::from(catalog_findings);
+    // Inherited declarations are reported once, where the convention plugin or root project declares them
+    let mut reported: HashSet<(PathBuf, usize, String)> = HashSet::new();
+    let mut report = |file_path: &Path, finding: Finding<DynamicVersionKind, DynamicVersion>| {
+        if reported.insert((file_path.to_path_buf(), finding.line_number, finding.subject.declaration.clone())) {
+            findings.add(file_path, finding);
+        }
+    };
+
+    for location in dependencies {
+        // Catalog versions are reported at their catalog entry
+        let (file_path, line_number) = match &location.source_type {
+            DependencySourceType::Direct => (location.file_path.as_path(), location.line_number),
+            DependencySourceType::Inherited(inherited) => (inherited.file_path.as_path(), inherited.line_number),
+            DependencySourceType::VersionCatalog(_) | DependencySourceType::CatalogBundle(_) => continue,
+        };
+        let Some(version) = &location.dependency.version else { continue };
+        if let Some(finding) = dynamic_version_finding(location.dependency.key(), version, Some(&location.configuration), line_number, severities) {
+            report(file_path, finding);
+        }
+    }
+
+    for location in plugins {
+        let (file_path, line_number) = match &location.source_type {
+            PluginSourceType::VersionCatalog(_) => continue,
+            PluginSourceType::Inherited(inherited) => (inherited.file_path.as_path(), inherited.line_number),
+            _ => (location.file_path.as_path(), location.line_number),
+        };
+        let Some(version) = &location.plugin.version else { continue };
+        if let Some(finding) = dynamic_version_finding(location.plugin.id.clone(), version, None, line_number, severities) {
+            report(file_path, finding);
+        }
+    }
+
+    DynamicVersionAnalysis { findings: findings.finish() }
+}
+
+fn dynamic_version_finding(
+    declaration: String,
+    version: &str,
+    configuration: Option<&str>,
+    line_number: usize,
+    severities: &DynamicVersionSeverities,
+) -> Option<Finding<DynamicVersionKind, DynamicVersion>> {
+    let kind = DynamicVersionKind::of(version)?;
+    let severity = severities.severity(kind, configuration)?;
+    let reason = match kind {
+        DynamicVersionKind::Plus => "is a dynamic version resolving to the newest matching release",
+        DynamicVersionKind::LatestRelease => "resolves to whichever release is newest when the build runs",
+        DynamicVersionKind::LatestIntegration => "resolves to whichever release or snapshot is newest when the build runs",
+        DynamicVersionKind::Range => "is a version range resolving to the newest release inside it",
+        DynamicVersionKind::Snapshot => "is a snapshot whose contents change without the version changing",
+    };
+
+    Some(Finding {
+        kind,
+        message: match configuration {
+            Some(configuration) => format!("{} ({}) uses {}, which {}; pin a fixed version for reproducible builds", declaration, configuration, version, reason),
+            None => format!("{} uses {}, which {}; pin a fixed version for reproducible builds", declaration, version, reason),
+        },
+        subject: DynamicVersion {
+            severity,
+            declaration,
+            version: version.to_string(),
+            configuration: configuration.map(str::to_string),
+        },
+        line_number,
+    })
+}
\ No newline at end of file
@@ -0,0 +1,71 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use clap::ValueEnum;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// How serious a finding is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, ValueEnum)]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+}
+
+/// A problem reported at one line of a build file, settings file or catalog
+#[derive(Debug, Clone, Serialize)]
+pub struct Finding<K, S> {
+    pub kind: K,
+    /// What the finding is about, e.g. the setting, repository or catalog alias
+    pub subject: S,
+    pub line_number: usize,
+    pub message: String,
+}
+
+/// Findings keyed by the file they are reported in, each file's ordered by line
+pub type FindingsByFile<K, S> = HashMap<PathBuf, Vec<Finding<K, S>>>;
+
+/// Collects the findings of one analysis by file
+pub struct FindingReporter<K, S> {
+    findings: FindingsByFile<K, S>,
+}
+
+impl<K, S> Default for FindingReporter<K, S> {
+    fn default() -> Self {
+        Self { findings: HashMap::new() }
+    }
+}
+
+impl<K, S> From<FindingsByFile<K, S>> for FindingReporter<K, S> {
+    fn from(findings: FindingsByFile<K, S>) -> Self {
+        Self { findings }
+    }
+}
+
+impl<K, S> FindingReporter<K, S> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn report(&mut self, file_path: &Path, line_number: usize, kind: K, subject: S, message: String) {
+        self.add(file_path, Finding { kind, subject, line_number, message });
+    }
+
+    pub fn add(&mut self, file_path: &Path, finding: Finding<K, S>) {
+        self.findings.entry(file_path.to_path_buf()).or_default().push(finding);
+    }
+
+    /// The findings of each file by line, findings on the same line by message
+    pub fn finish(mut self) -> FindingsByFile<K, S> {
+        for file_findings in self.findings.values_mut() {
+            file_findings.sort_by(|a, b| a.line_number.cmp(&b.line_number).then_with(|| a.message.cmp(&b.message)));
+        }
+        self.findings
+    }
+}
\ No newline at end of file
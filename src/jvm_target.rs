@@ -0,0 +1,242 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::config::{regex_patterns, Config};
+use crate::error::Result;
+use crate::findings::{FindingReporter, FindingsByFile};
+use crate::parser::{find_gradle_files, parse_plugins_from_file_with_config};
+use crate::version::majority_deviations;
+use crate::version_catalog::VersionCatalog;
+use regex::Regex;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Kotlin Gradle plugins all share this id prefix; only their modules compile Kotlin
+const KOTLIN_PLUGIN_ID_PREFIX: &str = "org.jetbrains.kotlin.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum JvmSetting {
+    Toolchain,
+    KotlinJvmTarget,
+    SourceCompatibility,
+    TargetCompatibility,
+}
+
+impl fmt::Display for JvmSetting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            JvmSetting::Toolchain => "jvmToolchain",
+            JvmSetting::KotlinJvmTarget => "jvmTarget",
+            JvmSetting::SourceCompatibility => "sourceCompatibility",
+            JvmSetting::TargetCompatibility => "targetCompatibility",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+const JVM_SETTINGS: &[JvmSetting] = &[
+    JvmSetting::Toolchain,
+    JvmSetting::KotlinJvmTarget,
+    JvmSetting::SourceCompatibility,
+    JvmSetting::TargetCompatibility,
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JvmSettingValue {
+    /// Value as written without quotes, e.g. `JavaVersion.VERSION_1_8` or `JvmTarget.JVM_17`
+    pub value: String,
+    /// Java feature release the value names (`8` for `1.8`), when it is a literal
+    pub java_version: Option<String>,
+    pub line_number: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JvmModule {
+    pub build_file: PathBuf,
+    /// Applies a Kotlin plugin, so its Java and Kotlin targets have to agree
+    pub is_kotlin: bool,
+    /// `jvmToolchain(17)` or `toolchain { languageVersion = JavaLanguageVersion.of(17) }`
+    pub toolchain: Option<JvmSettingValue>,
+    /// `kotlinOptions.jvmTarget` or `compilerOptions { jvmTarget }`
+    pub kotlin_jvm_target: Option<JvmSettingValue>,
+    pub source_compatibility: Option<JvmSettingValue>,
+    pub target_compatibility: Option<JvmSettingValue>,
+}
+
+impl JvmModule {
+    pub fn setting(&self, setting: JvmSetting) -> Option<&JvmSettingValue> {
+        match setting {
+            JvmSetting::Toolchain => self.toolchain.as_ref(),
+            JvmSetting::KotlinJvmTarget => self.kotlin_jvm_target.as_ref(),
+            JvmSetting::SourceCompatibility => self.source_compatibility.as_ref(),
+            JvmSetting::TargetCompatibility => self.target_compatibility.as_ref(),
+        }
+    }
+
+    /// Bytecode target of Java sources; without explicit compatibility it follows the toolchain
+    fn java_target(&self) -> Option<(JvmSetting, &JvmSettingValue)> {
+        [JvmSetting::TargetCompatibility, JvmSetting::SourceCompatibility, JvmSetting::Toolchain]
+            .into_iter()
+            .find_map(|setting| self.setting(setting).map(|value| (setting, value)))
+    }
+
+    /// Bytecode target of Kotlin sources; without an explicit jvmTarget it follows the toolchain
+    fn kotlin_target(&self) -> Option<(JvmSetting, &JvmSettingValue)> {
+        [JvmSetting::KotlinJvmTarget, JvmSetting::Toolchain]
+            .into_iter()
+            .find_map(|setting| self.setting(setting).map(|value| (setting, value)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum JvmTargetFindingKind {
+    InconsistentSetting,
+    JavaKotlinMismatch,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct JvmTargetAnalysis {
+    /// Modules declaring at least one toolchain, jvmTarget or compatibility setting, by build file
+    pub modules: Vec<JvmModule>,
+    /// About the setting they are reported at
+    pub findings: FindingsByFile<JvmTargetFindingKind, JvmSetting>,
+}
+
+/// JVM settings of the build files under `root_path`
+pub fn load_jvm_modules(
+    root_path: &Path,
+    version_catalogs: &HashMap<PathBuf, VersionCatalog>,
+    config: &Config,
+) -> Result<Vec<JvmModule>> {
+    let toolchain_regex = Regex::new(regex_patterns::JVM_TOOLCHAIN)?;
+    let jvm_target_regex = Regex::new(regex_patterns::KOTLIN_JVM_TARGET)?;
+    let compatibility_regex = Regex::new(regex_patterns::JAVA_COMPATIBILITY)?;
+    let version_regex = Regex::new(regex_patterns::JAVA_VERSION_NUMBER)?;
+    let mut modules = Vec::new();
+
+    for build_file in find_gradle_files(root_path)? {
+        let mut module = JvmModule {
+            build_file: build_file.clone(),
+            is_kotlin: parse_plugins_from_file_with_config(&build_file, version_catalogs, config)?
+                .iter()
+                .any(|plugin| plugin.apply && plugin.plugin.id.starts_with(KOTLIN_PLUGIN_ID_PREFIX)),
+            toolchain: None,
+            kotlin_jvm_target: None,
+            source_compatibility: None,
+            target_compatibility: None,
+        };
+
+        for (line_index, line) in fs::read_to_string(&build_file)?.lines().enumerate() {
+            let line = line.split("//").next().unwrap_or(line);
+            let (setting, raw) = if let Some(captures) = toolchain_regex.captures(line) {
+                (JvmSetting::Toolchain, captures.get(1))
+            } else if let Some(captures) = jvm_target_regex.captures(line) {
+                (JvmSetting::KotlinJvmTarget, captures.get(1))
+            } else if let Some(captures) = compatibility_regex.captures(line) {
+                let setting = if &captures[1] == "sourceCompatibility" {
+                    JvmSetting::SourceCompatibility
+                } else {
+                    JvmSetting::TargetCompatibility
+                };
+                (setting, captures.get(2))
+            } else {
+                continue;
+            };
+            let Some(raw) = raw else { continue };
+
+            let value = written_value(raw.as_str());
+            let java_version = version_regex.captures(&value).map(|captures| {
+                match (&captures[1], captures.get(2)) {
+                    // 1.8 and JVM_1_8 name Java 8
+                    ("1", Some(feature_release)) => feature_release.as_str().to_string(),
+                    (major, _) => major.to_string(),
+                }
+            });
+            let setting_value = Some(JvmSettingValue { value, java_version, line_number: line_index + 1 });
+            match setting {
+                JvmSetting::Toolchain => module.toolchain = setting_value,
+                JvmSetting::KotlinJvmTarget => module.kotlin_jvm_target = setting_value,
+                JvmSetting::SourceCompatibility => module.source_compatibility = setting_value,
+                JvmSetting::TargetCompatibility => module.target_compatibility = setting_value,
+            }
+        }
+
+        if JVM_SETTINGS.iter().any(|setting| module.setting(*setting).is_some()) {
+            modules.push(module);
+        }
+    }
+
+    modules.sort_by(|a, b| a.build_file.cmp(&b.build_file));
+    Ok(modules)
+}
+
+/// `= "17"`, ` JavaVersion.VERSION_17` or `(JvmTarget.JVM_17)` without the assignment, call
+/// parentheses, closing braces of a one-line block and quotes
+fn written_value(raw: &str) -> String {
+    let raw = raw.trim();
+    let value = if let Some(arguments) = raw.strip_prefix('(') {
+        let mut depth = 1;
+        let end = arguments
+            .char_indices()
+            .find(|(_, c)| {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+                depth == 0
+            })
+            .map(|(index, _)| index)
+            .unwrap_or(arguments.len());
+        &arguments[..end]
+    } else {
+        raw.strip_prefix('=').unwrap_or(raw).trim_end_matches(|c: char| c == '}' || c == ';' || c.is_whitespace())
+    };
+    value.trim().trim_matches(|c| c == '"' || c == '\'').to_string()
+}
+
+/// Settings out of line with the other modules and Kotlin modules whose Java and Kotlin targets disagree
+pub fn analyze_jvm_targets(modules: Vec<JvmModule>) -> JvmTargetAnalysis {
+    let mut findings = FindingReporter::new();
+    let mut report = |module: &JvmModule, kind, setting: JvmSetting, message| {
+        let line_number = module.setting(setting).map(|value| value.line_number).unwrap_or_default();
+        findings.report(&module.build_file, line_number, kind, setting, message);
+    };
+
+    for &setting in JVM_SETTINGS {
+        let deviations = majority_deviations(&modules, setting, |module| module.setting(setting)?.java_version.as_deref());
+        for (module, message) in deviations {
+            report(module, JvmTargetFindingKind::InconsistentSetting, setting, message);
+        }
+    }
+
+    for module in modules.iter().filter(|module| module.is_kotlin) {
+        let (Some((java_setting, java_value)), Some((kotlin_setting, kotlin_value))) = (module.java_target(), module.kotlin_target()) else {
+            continue;
+        };
+        let (Some(java_version), Some(kotlin_version)) = (&java_value.java_version, &kotlin_value.java_version) else { continue };
+        if java_version == kotlin_version {
+            continue;
+        }
+        // Point at the explicit setting; a toolchain alone cannot disagree with itself
+        let setting = if kotlin_setting == JvmSetting::Toolchain { java_setting } else { kotlin_setting };
+        report(
+            module,
+            JvmTargetFindingKind::JavaKotlinMismatch,
+            setting,
+            format!(
+                "Kotlin targets JVM {} ({}) but Java targets JVM {} ({}); the Kotlin Gradle plugin rejects inconsistent JVM targets",
+                kotlin_version, kotlin_setting, java_version, java_setting
+            ),
+        );
+    }
+
+    JvmTargetAnalysis { modules, findings: findings.finish() }
+}
\ No newline at end of file
@@ -11,5 +11,25 @@ pub mod analyzer;
 pub mod display;
 pub mod version_catalog;
 pub mod bundle_analyzer;
+pub mod bundle_catalog;
+pub mod bundle_conventions;
+pub mod similarity;
+pub mod plugin_applications;
+pub mod android_sdk;
+pub mod jvm_target;
+pub mod catalog_migration;
+pub mod plugins_dsl_migration;
+pub mod catalog_analyzer;
+pub mod repository_analyzer;
+pub mod compatibility;
+pub mod dynamic_versions;
+pub mod conflict_fix;
+pub mod convention_plugins;
+pub mod composite;
+pub mod settings;
+pub mod cross_project;
+pub mod rewrite;
+pub mod version;
+pub mod findings;
 pub mod config;
 pub mod error;
\ No newline at end of file
@@ -11,25 +11,59 @@ mod analyzer;
 mod display;
 mod version_catalog;
 mod bundle_analyzer;
+mod bundle_catalog;
+mod bundle_conventions;
+mod similarity;
+mod plugin_applications;
+mod android_sdk;
+mod jvm_target;
+mod catalog_migration;
+mod plugins_dsl_migration;
+mod catalog_analyzer;
+mod repository_analyzer;
+mod compatibility;
+mod dynamic_versions;
+mod conflict_fix;
+mod convention_plugins;
+mod composite;
+mod settings;
+mod cross_project;
+mod rewrite;
+mod version;
+mod findings;
 mod config;
 mod error;
 mod loading;
 
 use clap::Parser;
 use colored::*;
-use cli::{Args, validate_args, AnalysisOptions, Commands};
-use config::Config;
-use analyzer::{perform_complete_analysis, CompleteAnalysis, DuplicateAnalysis, PluginAnalysis};
+use cli::{Args, validate_args, AnalysisOptions, BundleEmitFormat, Commands, FixTarget};
+use config::{CompatibilityMatrix, Config};
+use analyzer::{perform_complete_analysis_with_config, CompleteAnalysis, DuplicateAnalysis, PluginAnalysis};
 use bundle_analyzer::BundleAnalysis;
-use display::{print_version_conflicts, print_regular_duplicates, print_bundle_recommendations, print_duplicate_plugins};
+use bundle_catalog::plan_catalog_bundles;
+use bundle_conventions::plan_convention_plugins;
+use composite::CompositeAnalysis;
+use catalog_analyzer::CatalogAnalysis;
+use android_sdk::AndroidAnalysis;
+use jvm_target::JvmTargetAnalysis;
+use repository_analyzer::RepositoryAnalysis;
+use compatibility::CompatibilityAnalysis;
+use dynamic_versions::DynamicVersionAnalysis;
+use similarity::SimilarityAnalysis;
+use catalog_migration::plan_catalog_migration;
+use plugins_dsl_migration::plan_plugins_dsl_migration;
+use conflict_fix::{plan_conflict_fixes, VersionStrategy};
+use display::{print_version_conflicts, print_regular_duplicates, print_bundle_recommendations, print_duplicate_plugins, print_plugin_version_conflicts, print_classpath_version_mismatches, print_cross_build_conflicts, print_findings, print_dynamic_version_findings, print_hardcoded_catalog_dependencies, print_catalog_migration, print_plugins_dsl_migration, print_conflict_fix_plan, print_catalog_bundle_plan, print_convention_plugin_plan, print_module_clusters};
 use serde::Serialize;
 use loading::{ProgressBar, LoadingSpinner};
+use rewrite::apply_file_changes;
 use std::fs;
 use std::io::Write;
 
 fn main() {
     let args = Args::parse();
-    let config = Config::default();
+    let mut config = Config::default();
     
     // Validate threshold arguments
     if let Err(error) = validate_args(&args, &config) {
@@ -39,14 +73,51 @@ fn main() {
         std::process::exit(1);
     }
     
+    // Migrations rewrite the project instead of reporting on it
+    if let Some(Commands::MigrateToCatalog { dry_run }) = &args.command {
+        run_catalog_migration(&args, *dry_run);
+        return;
+    }
+    
+    if let Some(Commands::MigrateToPluginsDsl { dry_run }) = &args.command {
+        run_plugins_dsl_migration(&args, &config, *dry_run);
+        return;
+    }
+    
+    if let Some(Commands::Fix { target: FixTarget::Conflicts { strategy, dependency, dry_run } }) = &args.command {
+        run_conflict_fix(&args, &config, strategy, dependency.as_deref(), *dry_run);
+        return;
+    }
+    
+    if let Some(matrix_path) = &args.matrix {
+        match CompatibilityMatrix::from_file(matrix_path) {
+            Ok(matrix) => config.compatibility_matrix = matrix,
+            Err(e) => {
+                if !args.silent {
+                    eprintln!("❌ Error reading compatibility matrix {}: {}", matrix_path.display(), e);
+                }
+                std::process::exit(1);
+            }
+        }
+    }
+    
+    if let Some(Commands::DynamicVersions { min_severity: Some(min_severity) }) = &args.command {
+        config.dynamic_version_severities.min_severity = *min_severity;
+    }
+    
     let options = args.get_analysis_options(&config);
     
+    if let Some(Commands::Bundles { emit: Some(format), apply, dry_run, .. }) = &args.command {
+        run_bundle_emit(&args, &options, &config, *format, *apply, *dry_run);
+        return;
+    }
+    
     // Only show loading animation if not in silent mode
     let analysis_result = if args.silent {
-        perform_complete_analysis(&args.path, options.min_bundle_size, options.min_bundle_modules)
+        perform_complete_analysis_with_config(&args.path, &options.parameters, &config)
     } else {
         let mut progress = ProgressBar::new("Analyzing Gradle project dependencies");
-        let result = perform_complete_analysis(&args.path, options.min_bundle_size, options.min_bundle_modules);
+        let result = perform_complete_analysis_with_config(&args.path, &options.parameters, &config);
         match &result {
             Ok(_) => progress.finish_with_message("✅ Analysis completed successfully"),
             Err(_) => progress.finish(),
@@ -92,6 +163,118 @@ fn main() {
     }
 }
 
+fn run_catalog_migration(args: &Args, dry_run: bool) {
+    let migration = match plan_catalog_migration(&args.path) {
+        Ok(migration) => migration,
+        Err(e) => {
+            if !args.silent {
+                eprintln!("❌ Error: {}", e);
+            }
+            std::process::exit(1);
+        }
+    };
+    
+    if !dry_run {
+        if let Err(e) = apply_file_changes(&migration.changes) {
+            if !args.silent {
+                eprintln!("❌ Error writing migrated files: {}", e);
+            }
+            std::process::exit(1);
+        }
+    }
+    
+    if !args.silent {
+        print_catalog_migration(&migration, &args.path, dry_run);
+    }
+}
+
+fn run_plugins_dsl_migration(args: &Args, config: &Config, dry_run: bool) {
+    let migration = match plan_plugins_dsl_migration(&args.path, config) {
+        Ok(migration) => migration,
+        Err(e) => {
+            if !args.silent {
+                eprintln!("❌ Error: {}", e);
+            }
+            std::process::exit(1);
+        }
+    };
+    
+    if !dry_run {
+        if let Err(e) = apply_file_changes(&migration.changes) {
+            if !args.silent {
+                eprintln!("❌ Error writing migrated files: {}", e);
+            }
+            std::process::exit(1);
+        }
+    }
+    
+    if !args.silent {
+        print_plugins_dsl_migration(&migration, &args.path, dry_run);
+    }
+}
+
+fn run_conflict_fix(args: &Args, config: &Config, strategy: &VersionStrategy, dependency: Option<&str>, dry_run: bool) {
+    let plan = perform_complete_analysis_with_config(&args.path, &AnalysisOptions::none().parameters, config)
+        .and_then(|analysis| plan_conflict_fixes(&args.path, &analysis.duplicate_analysis.version_conflicts, strategy, dependency));
+    let plan = match plan {
+        Ok(plan) => plan,
+        Err(e) => {
+            if !args.silent {
+                eprintln!("❌ Error: {}", e);
+            }
+            std::process::exit(1);
+        }
+    };
+    
+    if !dry_run {
+        if let Err(e) = apply_file_changes(&plan.changes) {
+            if !args.silent {
+                eprintln!("❌ Error writing fixed files: {}", e);
+            }
+            std::process::exit(1);
+        }
+    }
+    
+    if !args.silent {
+        print_conflict_fix_plan(&plan, &args.path, dry_run);
+    }
+}
+
+fn run_bundle_emit(args: &Args, options: &AnalysisOptions, config: &Config, format: BundleEmitFormat, apply: bool, dry_run: bool) {
+    let exit_with_error = |e: &dyn std::fmt::Display| -> ! {
+        if !args.silent {
+            eprintln!("❌ Error: {}", e);
+        }
+        std::process::exit(1);
+    };
+    
+    let analysis = perform_complete_analysis_with_config(&args.path, &options.parameters, config)
+        .unwrap_or_else(|e| exit_with_error(&e));
+    let recommended = analysis.bundle_analysis.recommended_bundles.len().min(options.max_bundle_recommendations);
+    let bundles = &analysis.bundle_analysis.recommended_bundles[..recommended];
+    
+    match format {
+        BundleEmitFormat::Catalog => {
+            let plan = plan_catalog_bundles(&args.path, bundles).unwrap_or_else(|e| exit_with_error(&e));
+            if apply {
+                apply_file_changes(&plan.changes).unwrap_or_else(|e| exit_with_error(&e));
+            }
+            if !args.silent {
+                print_catalog_bundle_plan(&plan, &args.path, apply, dry_run);
+            }
+        }
+        BundleEmitFormat::ConventionPlugin => {
+            let plan = plan_convention_plugins(&args.path, bundles).unwrap_or_else(|e| exit_with_error(&e));
+            if apply {
+                apply_file_changes(&plan.changes).unwrap_or_else(|e| exit_with_error(&e));
+            }
+            if !args.silent {
+                print_convention_plugin_plan(&plan, &args.path, apply, dry_run);
+            }
+        }
+    }
+}
+
 fn write_analysis_to_file(analysis: &CompleteAnalysis, output_path: &std::path::Path, command: &Option<Commands>) -> Result<(), Box<dyn std::error::Error>> {
     let filtered_analysis = create_filtered_analysis(analysis, command);
     let json_output = serde_json::to_string_pretty(&filtered_analysis)?;
@@ -100,14 +283,30 @@ fn write_analysis_to_file(analysis: &CompleteAnalysis, output_path: &std::path::
     Ok(())
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Default)]
 struct FilteredAnalysis {
     #[serde(skip_serializing_if = "Option::is_none")]
     duplicate_analysis: Option<DuplicateAnalysis>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    jvm_target_analysis: Option<JvmTargetAnalysis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     plugin_analysis: Option<PluginAnalysis>,
     #[serde(skip_serializing_if = "Option::is_none")]
     bundle_analysis: Option<BundleAnalysis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    composite_analysis: Option<CompositeAnalysis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    catalog_analysis: Option<CatalogAnalysis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    similarity_analysis: Option<SimilarityAnalysis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    android_analysis: Option<AndroidAnalysis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repository_analysis: Option<RepositoryAnalysis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compatibility_analysis: Option<CompatibilityAnalysis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dynamic_version_analysis: Option<DynamicVersionAnalysis>,
 }
 
 fn create_filtered_analysis(analysis: &CompleteAnalysis, command: &Option<Commands>) -> FilteredAnalysis {
@@ -120,8 +319,14 @@ fn create_filtered_analysis(analysis: &CompleteAnalysis, command: &Option<Comman
                     regular_duplicates: HashMap::new(),
                     version_conflicts: analysis.duplicate_analysis.version_conflicts.clone(),
                 }),
-                plugin_analysis: None,
-                bundle_analysis: None,
+                plugin_analysis: Some(PluginAnalysis {
+                    duplicate_plugins: HashMap::new(),
+                    version_conflicts: analysis.plugin_analysis.version_conflicts.clone(),
+                    classpath_version_mismatches: analysis.plugin_analysis.classpath_version_mismatches.clone(),
+                    application_findings: HashMap::new(),
+                }),
+                composite_analysis: Some(analysis.composite_analysis.clone()),
+                ..FilteredAnalysis::default()
             }
         }
         Some(Commands::Dependencies { .. }) => {
@@ -130,37 +335,85 @@ fn create_filtered_analysis(analysis: &CompleteAnalysis, command: &Option<Comman
                     regular_duplicates: analysis.duplicate_analysis.regular_duplicates.clone(),
                     version_conflicts: HashMap::new(),
                 }),
-                plugin_analysis: None,
-                bundle_analysis: None,
+                ..FilteredAnalysis::default()
             }
         }
         Some(Commands::Plugins { .. }) => {
             FilteredAnalysis {
-                duplicate_analysis: None,
                 plugin_analysis: Some(analysis.plugin_analysis.clone()),
-                bundle_analysis: None,
+                ..FilteredAnalysis::default()
             }
         }
         Some(Commands::Duplicates { .. }) => {
             FilteredAnalysis {
                 duplicate_analysis: Some(analysis.duplicate_analysis.clone()),
                 plugin_analysis: Some(analysis.plugin_analysis.clone()),
-                bundle_analysis: None,
+                ..FilteredAnalysis::default()
             }
         }
         Some(Commands::Bundles { .. }) => {
             FilteredAnalysis {
-                duplicate_analysis: None,
-                plugin_analysis: None,
                 bundle_analysis: Some(analysis.bundle_analysis.clone()),
+                ..FilteredAnalysis::default()
+            }
+        }
+        Some(Commands::Catalog) => {
+            FilteredAnalysis {
+                catalog_analysis: Some(analysis.catalog_analysis.clone()),
+                ..FilteredAnalysis::default()
+            }
+        }
+        Some(Commands::Repositories) => {
+            FilteredAnalysis {
+                repository_analysis: Some(analysis.repository_analysis.clone()),
+                ..FilteredAnalysis::default()
+            }
+        }
+        Some(Commands::JvmTarget) => {
+            FilteredAnalysis {
+                jvm_target_analysis: Some(analysis.jvm_target_analysis.clone()),
+                ..FilteredAnalysis::default()
+            }
+        }
+        Some(Commands::Android) => {
+            FilteredAnalysis {
+                android_analysis: Some(analysis.android_analysis.clone()),
+                ..FilteredAnalysis::default()
+            }
+        }
+        Some(Commands::Compatibility) => {
+            FilteredAnalysis {
+                compatibility_analysis: Some(analysis.compatibility_analysis.clone()),
+                ..FilteredAnalysis::default()
+            }
+        }
+        Some(Commands::DynamicVersions { .. }) => {
+            FilteredAnalysis {
+                dynamic_version_analysis: Some(analysis.dynamic_version_analysis.clone()),
+                ..FilteredAnalysis::default()
             }
         }
+        Some(Commands::Similarity { .. }) => {
+            FilteredAnalysis {
+                similarity_analysis: Some(analysis.similarity_analysis.clone()),
+                ..FilteredAnalysis::default()
+            }
+        }
+        Some(Commands::MigrateToCatalog { .. }) | Some(Commands::MigrateToPluginsDsl { .. }) | Some(Commands::Fix { .. }) => FilteredAnalysis::default(),
         Some(Commands::All { .. }) | None => {
             // For "all" command or no command (default), include everything
             FilteredAnalysis {
                 duplicate_analysis: Some(analysis.duplicate_analysis.clone()),
+                jvm_target_analysis: Some(analysis.jvm_target_analysis.clone()),
                 plugin_analysis: Some(analysis.plugin_analysis.clone()),
                 bundle_analysis: Some(analysis.bundle_analysis.clone()),
+                composite_analysis: Some(analysis.composite_analysis.clone()),
+                catalog_analysis: Some(analysis.catalog_analysis.clone()),
+                android_analysis: Some(analysis.android_analysis.clone()),
+                repository_analysis: Some(analysis.repository_analysis.clone()),
+                compatibility_analysis: Some(analysis.compatibility_analysis.clone()),
+                dynamic_version_analysis: Some(analysis.dynamic_version_analysis.clone()),
+                similarity_analysis: None,
             }
         }
     }
@@ -171,13 +424,37 @@ fn print_analysis_to_console(analysis: &CompleteAnalysis, options: &AnalysisOpti
     let duplicate_dependencies_count = analysis.duplicate_analysis.regular_duplicates.len();
     let duplicate_plugins_count = analysis.plugin_analysis.duplicate_plugins.len();
     let bundle_recommendations_count = analysis.bundle_analysis.recommended_bundles.len();
+    let plugin_version_conflicts_count = analysis.plugin_analysis.version_conflicts.len();
+    let classpath_mismatches_count = analysis.plugin_analysis.classpath_version_mismatches.len();
+    let cross_build_conflicts_count = analysis.composite_analysis.cross_build_conflicts.len();
+    let catalog_findings_count: usize = analysis.catalog_analysis.findings.values().map(|findings| findings.len()).sum();
+    let hardcoded_dependencies_count = analysis.catalog_analysis.hardcoded_dependencies.len();
+    let plugin_application_findings_count: usize = analysis.plugin_analysis.application_findings.values().map(|findings| findings.len()).sum();
+    let jvm_target_findings_count: usize = analysis.jvm_target_analysis.findings.values().map(|findings| findings.len()).sum();
+    let repository_findings_count: usize = analysis.repository_analysis.findings.values().map(|findings| findings.len()).sum();
+    let android_findings_count: usize = analysis.android_analysis.findings.values().map(|findings| findings.len()).sum();
+    let compatibility_findings_count: usize = analysis.compatibility_analysis.findings.values().map(|findings| findings.len()).sum();
+    let dynamic_version_findings_count: usize = analysis.dynamic_version_analysis.findings.values().map(|findings| findings.len()).sum();
+    let module_clusters_count = analysis.similarity_analysis.clusters.len();
     
     let show_version_conflicts = version_conflicts_count >= options.min_version_conflicts;
     let show_duplicate_dependencies = duplicate_dependencies_count >= options.min_duplicate_dependencies;
     let show_duplicate_plugins = duplicate_plugins_count >= options.min_duplicate_plugins;
     let show_bundle_recommendations = bundle_recommendations_count > 0 && options.max_bundle_recommendations > 0;
+    let show_plugin_version_conflicts = plugin_version_conflicts_count > 0 && options.show_plugin_version_conflicts;
+    let show_classpath_mismatches = classpath_mismatches_count > 0 && options.show_classpath_mismatches;
+    let show_cross_build_conflicts = cross_build_conflicts_count > 0 && options.show_cross_build_conflicts;
+    let show_catalog_findings = catalog_findings_count > 0 && options.show_catalog_findings;
+    let show_hardcoded_dependencies = hardcoded_dependencies_count > 0 && options.show_catalog_findings;
+    let show_plugin_application_findings = plugin_application_findings_count > 0 && options.show_plugin_application_findings;
+    let show_jvm_target_findings = jvm_target_findings_count > 0 && options.show_jvm_target_findings;
+    let show_repository_findings = repository_findings_count > 0 && options.show_repository_findings;
+    let show_android_findings = android_findings_count > 0 && options.show_android_findings;
+    let show_compatibility_findings = compatibility_findings_count > 0 && options.show_compatibility_findings;
+    let show_dynamic_version_findings = dynamic_version_findings_count > 0 && options.show_dynamic_version_findings;
+    let show_module_clusters = module_clusters_count > 0 && options.parameters.min_module_similarity.is_some();
     
-    if !show_version_conflicts && !show_duplicate_dependencies && !show_duplicate_plugins && !show_bundle_recommendations {
+    if !show_version_conflicts && !show_duplicate_dependencies && !show_duplicate_plugins && !show_bundle_recommendations && !show_plugin_version_conflicts && !show_classpath_mismatches && !show_plugin_application_findings && !show_cross_build_conflicts && !show_catalog_findings && !show_hardcoded_dependencies && !show_jvm_target_findings && !show_android_findings && !show_repository_findings && !show_compatibility_findings && !show_dynamic_version_findings && !show_module_clusters {
         println!("✅ No issues found above the specified thresholds.");
         if version_conflicts_count > 0 || duplicate_dependencies_count > 0 || duplicate_plugins_count > 0 {
             println!("   (Found {} version conflicts, {} duplicate dependencies, and {} duplicate plugins below thresholds)", 
@@ -193,24 +470,130 @@ fn print_analysis_to_console(analysis: &CompleteAnalysis, options: &AnalysisOpti
             print_version_conflicts(&analysis.duplicate_analysis.version_conflicts);
         }
         
-        if show_duplicate_dependencies {
+        if show_cross_build_conflicts {
             if show_version_conflicts {
                 println!();
             }
+            println!("{} {} {} across {} builds:",
+                "🏗️".red(),
+                "Found".red().bold(),
+                format!("{} cross-build version conflicts", cross_build_conflicts_count).red().bold(),
+                analysis.composite_analysis.builds.len()
+            );
+            print_cross_build_conflicts(&analysis.composite_analysis.cross_build_conflicts);
+        }
+        
+        if show_plugin_version_conflicts {
+            if show_version_conflicts || show_cross_build_conflicts {
+                println!();
+            }
+            println!("{} {} {}:",
+                "🔌".red(),
+                "Found".red().bold(),
+                format!("{} plugin version conflicts", plugin_version_conflicts_count).red().bold()
+            );
+            print_plugin_version_conflicts(&analysis.plugin_analysis.version_conflicts);
+        }
+        
+        if show_duplicate_dependencies {
+            if show_version_conflicts || show_cross_build_conflicts || show_plugin_version_conflicts {
+                println!();
+            }
             println!("⚠️  Found {} duplicate dependencies:", duplicate_dependencies_count);
             print_regular_duplicates(&analysis.duplicate_analysis.regular_duplicates);
         }
         
         if show_duplicate_plugins {
-            if show_version_conflicts || show_duplicate_dependencies {
+            if show_version_conflicts || show_cross_build_conflicts || show_plugin_version_conflicts || show_duplicate_dependencies {
                 println!();
             }
-            println!("🔌 Found {} duplicate plugins:", duplicate_plugins_count);
+            println!("🔌 Found {} plugins applied more than once by the same build file:", duplicate_plugins_count);
             print_duplicate_plugins(&analysis.plugin_analysis.duplicate_plugins);
         }
         
+        if show_classpath_mismatches {
+            if show_version_conflicts || show_cross_build_conflicts || show_plugin_version_conflicts || show_duplicate_dependencies || show_duplicate_plugins {
+                println!();
+            }
+            println!("🧭 Found {} plugin version mismatches between buildscript classpath, pluginManagement and plugins blocks:", classpath_mismatches_count);
+            print_classpath_version_mismatches(&analysis.plugin_analysis.classpath_version_mismatches);
+        }
+        
+        if show_plugin_application_findings {
+            if show_version_conflicts || show_cross_build_conflicts || show_plugin_version_conflicts || show_duplicate_dependencies || show_duplicate_plugins || show_classpath_mismatches {
+                println!();
+            }
+            println!("🧹 Found {} redundant or legacy plugin applications:", plugin_application_findings_count);
+            print_findings(&analysis.plugin_analysis.application_findings, "🧹 Build file");
+        }
+        
+        if show_catalog_findings {
+            if show_version_conflicts || show_cross_build_conflicts || show_plugin_version_conflicts || show_duplicate_dependencies || show_duplicate_plugins || show_classpath_mismatches || show_plugin_application_findings {
+                println!();
+            }
+            println!("📚 Found {} version catalog issues:", catalog_findings_count);
+            print_findings(&analysis.catalog_analysis.findings, "📚 Catalog");
+        }
+        
+        if show_hardcoded_dependencies {
+            if show_version_conflicts || show_cross_build_conflicts || show_plugin_version_conflicts || show_duplicate_dependencies || show_duplicate_plugins || show_classpath_mismatches || show_plugin_application_findings || show_catalog_findings {
+                println!();
+            }
+            println!("📝 Found {} hardcoded dependencies already declared in a version catalog:", hardcoded_dependencies_count);
+            print_hardcoded_catalog_dependencies(&analysis.catalog_analysis.hardcoded_dependencies);
+        }
+        
+        if show_jvm_target_findings {
+            if show_version_conflicts || show_cross_build_conflicts || show_plugin_version_conflicts || show_duplicate_dependencies || show_duplicate_plugins || show_classpath_mismatches || show_plugin_application_findings || show_catalog_findings || show_hardcoded_dependencies {
+                println!();
+            }
+            println!("☕ Found {} JVM toolchain and target inconsistencies across {} modules:", jvm_target_findings_count, analysis.jvm_target_analysis.modules.len());
+            print_findings(&analysis.jvm_target_analysis.findings, "☕ Build file");
+        }
+        
+        if show_android_findings {
+            if show_version_conflicts || show_cross_build_conflicts || show_plugin_version_conflicts || show_duplicate_dependencies || show_duplicate_plugins || show_classpath_mismatches || show_plugin_application_findings || show_catalog_findings || show_hardcoded_dependencies || show_jvm_target_findings {
+                println!();
+            }
+            println!("🤖 Found {} Android SDK and namespace inconsistencies across {} Android modules:", android_findings_count, analysis.android_analysis.modules.len());
+            print_findings(&analysis.android_analysis.findings, "🤖 Build file");
+        }
+        
+        if show_repository_findings {
+            if show_version_conflicts || show_cross_build_conflicts || show_plugin_version_conflicts || show_duplicate_dependencies || show_duplicate_plugins || show_classpath_mismatches || show_plugin_application_findings || show_catalog_findings || show_hardcoded_dependencies || show_jvm_target_findings || show_android_findings {
+                println!();
+            }
+            println!("📦 Found {} repository declaration issues:", repository_findings_count);
+            print_findings(&analysis.repository_analysis.findings, "📦 File");
+        }
+        
+        if show_compatibility_findings {
+            if show_version_conflicts || show_cross_build_conflicts || show_plugin_version_conflicts || show_duplicate_dependencies || show_duplicate_plugins || show_classpath_mismatches || show_plugin_application_findings || show_catalog_findings || show_hardcoded_dependencies || show_jvm_target_findings || show_android_findings || show_repository_findings {
+                println!();
+            }
+            println!("🧰 Found {} incompatible Gradle, Android Gradle plugin, Kotlin and JDK versions:", compatibility_findings_count);
+            print_findings(&analysis.compatibility_analysis.findings, "🧰 File");
+        }
+        
+        if show_dynamic_version_findings {
+            if show_version_conflicts || show_cross_build_conflicts || show_plugin_version_conflicts || show_duplicate_dependencies || show_duplicate_plugins || show_classpath_mismatches || show_plugin_application_findings || show_catalog_findings || show_hardcoded_dependencies || show_jvm_target_findings || show_android_findings || show_repository_findings || show_compatibility_findings {
+                println!();
+            }
+            println!("🎲 Found {} dynamic, ranged or snapshot versions:", dynamic_version_findings_count);
+            print_dynamic_version_findings(&analysis.dynamic_version_analysis.findings);
+        }
+        
         if show_bundle_recommendations {
             print_bundle_recommendations(&analysis.bundle_analysis, options.max_bundle_recommendations);
         }
+        
+        if show_module_clusters {
+            println!("{} {} {}:",
+                "🧩".cyan(),
+                "Found".cyan().bold(),
+                format!("{} groups of modules with similar dependencies (Jaccard ≥ {:.2})", module_clusters_count, analysis.similarity_analysis.min_similarity).cyan().bold()
+            );
+            print_module_clusters(&analysis.similarity_analysis.clusters);
+        }
     }
 }
\ No newline at end of file
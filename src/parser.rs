@@ -5,21 +5,50 @@
  * See LICENSE file in the project root for full license information.
  */
 
-use crate::config::{file_patterns, regex_patterns};
+use crate::config::plugin_patterns::PLUGIN_ACCESSORS;
+use crate::config::{file_patterns, regex_patterns, Config};
 use crate::error::{Result};
+use crate::settings::{parse_settings_file, resolve_settings_path, walk_build};
 use crate::version_catalog::{find_version_catalog_files, parse_version_catalog, VersionCatalog};
 use regex::Regex;
 use serde::Serialize;
 use std::collections::HashMap;
+use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
-use walkdir::WalkDir;
+
+/// Classifier Gradle publishes `java-test-fixtures` artifacts under
+pub const TEST_FIXTURES_CLASSIFIER: &str = "test-fixtures";
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct Dependency {
     pub group: String,
     pub artifact: String,
     pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classifier: Option<String>, // e.g. sources, test-fixtures
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extension: Option<String>, // artifact type from @aar, @jar
+}
+
+impl Dependency {
+    pub fn new(group: String, artifact: String, version: Option<String>) -> Self {
+        Self {
+            group,
+            artifact,
+            version,
+            classifier: None,
+            extension: None,
+        }
+    }
+
+    /// Key used to group declarations of the same artifact (`group:artifact[:classifier]`)
+    pub fn key(&self) -> String {
+        match &self.classifier {
+            Some(classifier) => format!("{}:{}:{}", self.group, self.artifact, classifier),
+            None => format!("{}:{}", self.group, self.artifact),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -34,7 +63,17 @@ pub struct DependencyLocation {
 #[derive(Debug, Clone, Serialize)]
 pub enum DependencySourceType {
     Direct,
-    VersionCatalog(String), // The libs.xxx reference
+    VersionCatalog(String), // The full catalog reference, e.g. libs.okhttp
+    CatalogBundle(String), // The bundle reference the dependency was expanded from, e.g. libs.bundles.compose
+    Inherited(InheritedFrom), // Declared elsewhere and applied to this module
+}
+
+/// Origin of a declaration inherited from a convention plugin
+#[derive(Debug, Clone, Serialize)]
+pub struct InheritedFrom {
+    pub from: String, // Convention plugin id
+    pub file_path: PathBuf,
+    pub line_number: usize,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
@@ -49,23 +88,86 @@ pub struct PluginLocation {
     pub file_path: PathBuf,
     pub line_number: usize,
     pub source_type: PluginSourceType,
+    pub apply: bool, // false for `apply false` declarations, which only put the plugin on the build classpath
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub enum PluginSourceType {
     PluginsBlock,
     ApplyPlugin,
-    VersionCatalog(String), // The libs.plugins.xxx reference
+    VersionCatalog(String), // The full catalog reference, e.g. libs.plugins.ktlint
+    BuildscriptClasspath(String), // The group:artifact on the buildscript classpath
+    PluginManagement, // plugins { } inside settings pluginManagement { }
+    Inherited(InheritedFrom), // Applied through a convention plugin
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum RepositoryKind {
+    Google,
+    MavenCentral,
+    JCenter,
+    MavenLocal,
+    GradlePluginPortal,
+    Maven,
+    Ivy,
+    FlatDir,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+pub struct Repository {
+    pub kind: RepositoryKind,
+    pub url: Option<String>, // maven and ivy repositories only
+}
+
+impl fmt::Display for Repository {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.kind, &self.url) {
+            (RepositoryKind::Google, _) => write!(f, "google()"),
+            (RepositoryKind::MavenCentral, _) => write!(f, "mavenCentral()"),
+            (RepositoryKind::JCenter, _) => write!(f, "jcenter()"),
+            (RepositoryKind::MavenLocal, _) => write!(f, "mavenLocal()"),
+            (RepositoryKind::GradlePluginPortal, _) => write!(f, "gradlePluginPortal()"),
+            (RepositoryKind::Maven, Some(url)) => write!(f, "maven({})", url),
+            (RepositoryKind::Ivy, Some(url)) => write!(f, "ivy({})", url),
+            (RepositoryKind::Maven, None) => write!(f, "maven {{ }}"),
+            (RepositoryKind::Ivy, None) => write!(f, "ivy {{ }}"),
+            (RepositoryKind::FlatDir, _) => write!(f, "flatDir {{ }}"),
+        }
+    }
+}
+
+/// Block a `repositories { }` belongs to, which decides what resolves from it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum RepositoryScope {
+    Project, // dependencies of the project, also through allprojects/subprojects
+    Buildscript, // the buildscript classpath
+    PluginManagement, // plugins requested in plugins { }, from settings pluginManagement { }
+    DependencyResolutionManagement, // dependencies of every project, from settings
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RepositoryLocation {
+    pub repository: Repository,
+    pub file_path: PathBuf,
+    pub line_number: usize,
+    pub scope: RepositoryScope,
 }
 
 struct DependencyPatterns {
     string_dep: Regex,
-    map_dep_group_first: Regex,
-    map_dep_name_first: Regex,
-    libs_dep: Regex,
+    map_dep: Regex,
+    map_dep_entry: Regex,
     version_catalog_dep: Regex,
+    catalog_bundle_dep: Regex,
+    find_library_dep: Regex,
     project_dep: Regex,
     projects_accessor_dep: Regex,
+    test_fixtures: Regex,
+    variant_of: Regex,
+    variant_classifier: Regex,
+    variant_artifact_type: Regex,
+    repository: Regex,
+    repository_url: Regex,
 }
 
 struct PluginPatterns {
@@ -74,21 +176,32 @@ struct PluginPatterns {
     plugin_kotlin_dsl_id_version: Regex,
     plugin_kotlin_dsl_id_only: Regex,
     plugin_kotlin_shorthand_version: Regex,
+    plugin_kotlin_shorthand_id_only: Regex,
     plugin_kotlin_shorthand_only: Regex,
     apply_plugin: Regex,
     apply_plugin_groovy: Regex,
+    apply_false: Regex,
     libs_plugin: Regex,
+    classpath_dep: Regex,
+    classpath_catalog_dep: Regex,
 }
 
 fn create_dependency_patterns() -> Result<DependencyPatterns> {
     Ok(DependencyPatterns {
         string_dep: Regex::new(regex_patterns::STRING_DEPENDENCY)?,
-        map_dep_group_first: Regex::new(regex_patterns::MAP_DEPENDENCY_1)?,
-        map_dep_name_first: Regex::new(regex_patterns::MAP_DEPENDENCY_2)?,
-        libs_dep: Regex::new(regex_patterns::LIBS_DEPENDENCY)?,
+        map_dep: Regex::new(regex_patterns::MAP_DEPENDENCY)?,
+        map_dep_entry: Regex::new(regex_patterns::MAP_DEPENDENCY_ENTRY)?,
         version_catalog_dep: Regex::new(regex_patterns::VERSION_CATALOG_DEPENDENCY)?,
+        catalog_bundle_dep: Regex::new(regex_patterns::CATALOG_BUNDLE_DEPENDENCY)?,
+        find_library_dep: Regex::new(regex_patterns::FIND_LIBRARY_DEPENDENCY)?,
         project_dep: Regex::new(regex_patterns::PROJECT_DEPENDENCY)?,
         projects_accessor_dep: Regex::new(regex_patterns::PROJECTS_ACCESSOR_DEPENDENCY)?,
+        test_fixtures: Regex::new(regex_patterns::TEST_FIXTURES_WRAPPER)?,
+        variant_of: Regex::new(regex_patterns::VARIANT_OF_WRAPPER)?,
+        variant_classifier: Regex::new(regex_patterns::VARIANT_CLASSIFIER)?,
+        variant_artifact_type: Regex::new(regex_patterns::VARIANT_ARTIFACT_TYPE)?,
+        repository: Regex::new(regex_patterns::REPOSITORY_DECLARATION)?,
+        repository_url: Regex::new(regex_patterns::REPOSITORY_URL)?,
     })
 }
 
@@ -99,17 +212,21 @@ fn create_plugin_patterns() -> Result<PluginPatterns> {
         plugin_kotlin_dsl_id_version: Regex::new(regex_patterns::PLUGIN_KOTLIN_DSL_ID_VERSION)?,
         plugin_kotlin_dsl_id_only: Regex::new(regex_patterns::PLUGIN_KOTLIN_DSL_ID_ONLY)?,
         plugin_kotlin_shorthand_version: Regex::new(regex_patterns::PLUGIN_KOTLIN_SHORTHAND_VERSION)?,
+        plugin_kotlin_shorthand_id_only: Regex::new(regex_patterns::PLUGIN_KOTLIN_SHORTHAND_ID_ONLY)?,
         plugin_kotlin_shorthand_only: Regex::new(regex_patterns::PLUGIN_KOTLIN_SHORTHAND_ONLY)?,
         apply_plugin: Regex::new(regex_patterns::APPLY_PLUGIN)?,
         apply_plugin_groovy: Regex::new(regex_patterns::APPLY_PLUGIN_GROOVY)?,
+        apply_false: Regex::new(regex_patterns::PLUGIN_APPLY_FALSE)?,
         libs_plugin: Regex::new(regex_patterns::LIBS_PLUGIN)?,
+        classpath_dep: Regex::new(regex_patterns::CLASSPATH_DEPENDENCY)?,
+        classpath_catalog_dep: Regex::new(regex_patterns::CLASSPATH_CATALOG_DEPENDENCY)?,
     })
 }
 
 pub fn find_gradle_files(root_path: &Path) -> Result<Vec<PathBuf>> {
     let mut gradle_files = Vec::new();
     
-    for entry in WalkDir::new(root_path) {
+    for entry in walk_build(root_path)? {
         let entry = entry?;
         let path = entry.path();
         
@@ -125,31 +242,190 @@ pub fn find_gradle_files(root_path: &Path) -> Result<Vec<PathBuf>> {
     Ok(gradle_files)
 }
 
+pub fn find_settings_files(root_path: &Path) -> Result<Vec<PathBuf>> {
+    let mut settings_files = Vec::new();
+    
+    for entry in walk_build(root_path)? {
+        let entry = entry?;
+        let path = entry.path();
+        
+        if path.is_file() {
+            if let Some(filename) = path.file_name().and_then(|n| n.to_str()) {
+                if file_patterns::GRADLE_SETTINGS_FILES.contains(&filename) {
+                    settings_files.push(path.to_path_buf());
+                }
+            }
+        }
+    }
+    
+    Ok(settings_files)
+}
+
+/// Finds precompiled script plugins (`<id>.gradle.kts` under `src/main/kotlin`) in buildSrc and included builds
+pub fn find_precompiled_script_plugins(root_path: &Path) -> Result<Vec<PathBuf>> {
+    let mut script_files = Vec::new();
+    
+    for entry in walk_build(root_path)? {
+        let entry = entry?;
+        let path = entry.path();
+        
+        if path.is_file() && precompiled_script_plugin_name(path).is_some() {
+            let path_str = path.to_string_lossy().replace('\\', "/");
+            let in_source_dir = file_patterns::PRECOMPILED_SCRIPT_DIRS
+                .iter()
+                .any(|dir| path_str.contains(&format!("/{}/", dir)));
+            if in_source_dir {
+                script_files.push(path.to_path_buf());
+            }
+        }
+    }
+    
+    Ok(script_files)
+}
+
+/// Plugin name of a precompiled script file (`myorg.android-library.gradle.kts` -> `myorg.android-library`)
+pub fn precompiled_script_plugin_name(path: &Path) -> Option<String> {
+    let filename = path.file_name()?.to_str()?;
+    if file_patterns::GRADLE_BUILD_FILES.contains(&filename) || file_patterns::GRADLE_SETTINGS_FILES.contains(&filename) {
+        return None;
+    }
+    
+    file_patterns::PRECOMPILED_SCRIPT_EXTENSIONS
+        .iter()
+        .find_map(|extension| filename.strip_suffix(extension))
+        .filter(|name| !name.is_empty())
+        .map(|name| name.to_string())
+}
+
 pub fn load_version_catalogs(root_path: &Path) -> Result<HashMap<PathBuf, VersionCatalog>> {
-    let catalog_files = find_version_catalog_files(root_path)?;
+    let mut catalog_files = find_version_catalog_files(root_path)?;
+    
+    // Catalogs declared in settings may use any file name and live outside the build
+    let mut declarations: HashMap<PathBuf, Vec<(String, PathBuf)>> = HashMap::new();
+    for settings_file in find_settings_files(root_path)? {
+        let settings_dir = settings_file.parent().unwrap_or(root_path);
+        for declaration in parse_settings_file(&settings_file)?.version_catalogs {
+            let catalog_file = resolve_settings_path(settings_dir, &declaration.path);
+            if catalog_file.is_file() {
+                if !catalog_files.contains(&catalog_file) {
+                    catalog_files.push(catalog_file.clone());
+                }
+                declarations.entry(catalog_file).or_default().push((declaration.accessor, settings_dir.to_path_buf()));
+            }
+        }
+    }
+    
     let mut catalogs = HashMap::new();
     
     for catalog_file in catalog_files {
-        let catalog = parse_version_catalog(&catalog_file)?;
+        let mut catalog = parse_version_catalog(&catalog_file)?;
+        // Declared names replace the conventional one; a file declared under several names keeps each
+        if let Some(declarations) = declarations.get(&catalog_file) {
+            catalog.accessors.clear();
+            for (accessor, settings_dir) in declarations {
+                if !catalog.is_accessed_by(accessor) {
+                    catalog.accessors.push(accessor.clone());
+                }
+                if !catalog.scopes.contains(settings_dir) {
+                    catalog.scopes.push(settings_dir.clone());
+                }
+            }
+        }
         catalogs.insert(catalog_file, catalog);
     }
     
     Ok(catalogs)
 }
 
+/// Catalogs visible from `file_path`, those of the nearest enclosing build first
+fn catalogs_in_scope<'a>(version_catalogs: &'a HashMap<PathBuf, VersionCatalog>, file_path: &Path) -> Vec<&'a VersionCatalog> {
+    let mut catalogs: Vec<(usize, &PathBuf, &VersionCatalog)> = version_catalogs
+        .iter()
+        .filter_map(|(path, catalog)| {
+            catalog.scopes
+                .iter()
+                .filter(|scope| file_path.starts_with(scope))
+                .map(|scope| scope.components().count())
+                .max()
+                .map(|depth| (depth, path, catalog))
+        })
+        .collect();
+    catalogs.sort_by(|(depth, path, _), (other_depth, other_path, _)| other_depth.cmp(depth).then_with(|| path.cmp(other_path)));
+    catalogs.into_iter().map(|(_, _, catalog)| catalog).collect()
+}
+
+/// Catalog `accessor` refers to in `file_path`
+fn catalog_named<'a>(version_catalogs: &'a HashMap<PathBuf, VersionCatalog>, accessor: &str, file_path: &Path) -> Option<&'a VersionCatalog> {
+    catalogs_in_scope(version_catalogs, file_path)
+        .into_iter()
+        .find(|catalog| catalog.is_accessed_by(accessor))
+}
+
+fn resolve_catalog_library(
+    version_catalogs: &HashMap<PathBuf, VersionCatalog>,
+    accessor: &str,
+    reference: &str,
+    file_path: &Path,
+) -> Option<(String, String, String)> {
+    catalog_named(version_catalogs, accessor, file_path)?.resolve_library_version(reference)
+}
+
+/// Plugins of `file_path` with the default plugin id aliases
+#[allow(dead_code)]
 pub fn parse_plugins_from_file(
     file_path: &Path,
     version_catalogs: &HashMap<PathBuf, VersionCatalog>
+) -> Result<Vec<PluginLocation>> {
+    let config = Config::default();
+    parse_plugins_from_file_with_config(file_path, version_catalogs, &config)
+}
+
+/// Plugin ids are reported by their canonical id, so `apply plugin: 'kotlin-android'`,
+/// `kotlin("android")` and `id("org.jetbrains.kotlin.android")` name the same plugin
+pub fn parse_plugins_from_file_with_config(
+    file_path: &Path,
+    version_catalogs: &HashMap<PathBuf, VersionCatalog>,
+    config: &Config,
 ) -> Result<Vec<PluginLocation>> {
     let content = fs::read_to_string(file_path)?;
     let mut plugins = Vec::new();
     let mut in_plugins_block = false;
     let mut brace_count = 0;
+    let mut in_buildscript = false;
+    let mut buildscript_brace_count: i32 = 0;
+    let mut plugin_management_brace_count: i32 = 0;
     
     let patterns = create_plugin_patterns()?;
     
     for (line_number, line) in content.lines().enumerate() {
         let trimmed_line = line.trim();
+        let brace_delta = trimmed_line.matches('{').count() as i32 - trimmed_line.matches('}').count() as i32;
+        
+        // Classpath entries inside buildscript { dependencies { } }
+        if !in_buildscript && trimmed_line.starts_with(regex_patterns::BUILDSCRIPT_BLOCK) && trimmed_line.contains('{') {
+            in_buildscript = true;
+            buildscript_brace_count = 0;
+        }
+        if in_buildscript {
+            buildscript_brace_count += brace_delta;
+            in_buildscript = buildscript_brace_count > 0;
+            
+            if let Some(plugin) = parse_classpath_plugin(&patterns.classpath_dep, trimmed_line, file_path, line_number + 1)? {
+                plugins.push(plugin);
+            } else if let Some(plugin) = parse_libs_classpath_plugin(&patterns.classpath_catalog_dep, trimmed_line, file_path, line_number + 1, version_catalogs)? {
+                plugins.push(plugin);
+            }
+            continue;
+        }
+        
+        // Track settings pluginManagement { } so its plugins block can be told apart
+        if plugin_management_brace_count > 0 {
+            plugin_management_brace_count += brace_delta;
+        } else if trimmed_line.starts_with(regex_patterns::PLUGIN_MANAGEMENT_BLOCK) && trimmed_line.contains('{') {
+            plugin_management_brace_count = brace_delta;
+            continue;
+        }
+        let in_plugin_management = plugin_management_brace_count > 0;
         
         // Check if we're entering a plugins block
         if trimmed_line.starts_with(regex_patterns::PLUGINS_BLOCK) && trimmed_line.contains('{') {
@@ -176,24 +452,40 @@ pub fn parse_plugins_from_file(
             }
             
             // Parse different plugin formats inside plugins block - check versioned patterns first
-            if let Some(plugin) = parse_plugin_id_version(&patterns.plugin_id_version, trimmed_line, file_path, line_number + 1)? {
-                plugins.push(plugin);
+            let parsed = if let Some(plugin) = parse_plugin_id_version(&patterns.plugin_id_version, trimmed_line, file_path, line_number + 1)? {
+                Some(plugin)
             } else if let Some(plugin) = parse_plugin_kotlin_dsl_id_version(&patterns.plugin_kotlin_dsl_id_version, trimmed_line, file_path, line_number + 1)? {
-                plugins.push(plugin);
+                Some(plugin)
             } else if let Some(plugin) = parse_plugin_kotlin_shorthand_version(&patterns.plugin_kotlin_shorthand_version, trimmed_line, file_path, line_number + 1)? {
-                plugins.push(plugin);
+                Some(plugin)
             } else if let Some(plugin) = parse_plugin_id_only(&patterns.plugin_id_only, trimmed_line, file_path, line_number + 1)? {
-                plugins.push(plugin);
+                Some(plugin)
             } else if let Some(plugin) = parse_plugin_kotlin_dsl_id_only(&patterns.plugin_kotlin_dsl_id_only, trimmed_line, file_path, line_number + 1)? {
-                plugins.push(plugin);
+                Some(plugin)
+            } else if let Some(plugin) = parse_plugin_kotlin_shorthand_id_only(&patterns.plugin_kotlin_shorthand_id_only, trimmed_line, file_path, line_number + 1)? {
+                Some(plugin)
             } else if let Some(plugin) = parse_plugin_kotlin_shorthand_only(&patterns.plugin_kotlin_shorthand_only, trimmed_line, file_path, line_number + 1)? {
-                plugins.push(plugin);
-            } else if let Some(plugin) = parse_libs_plugin(&patterns.libs_plugin, trimmed_line, file_path, line_number + 1, version_catalogs)? {
+                Some(plugin)
+            } else {
+                parse_libs_plugin(&patterns.libs_plugin, trimmed_line, file_path, line_number + 1, version_catalogs)?
+            };
+            
+            if let Some(mut plugin) = parsed {
+                if in_plugin_management {
+                    plugin.source_type = PluginSourceType::PluginManagement;
+                }
+                plugin.apply = !patterns.apply_false.is_match(trimmed_line);
                 plugins.push(plugin);
             }
         }
     }
     
+    for plugin in &mut plugins {
+        if !matches!(plugin.source_type, PluginSourceType::BuildscriptClasspath(_)) {
+            plugin.plugin.id = config.plugin_id_aliases.canonical_id(&plugin.plugin.id);
+        }
+    }
+    
     Ok(plugins)
 }
 
@@ -201,14 +493,38 @@ pub fn parse_dependencies_from_file(
     file_path: &Path, 
     version_catalogs: &HashMap<PathBuf, VersionCatalog>
 ) -> Result<Vec<DependencyLocation>> {
+    Ok(parse_build_file(file_path, version_catalogs)?.dependencies)
+}
+
+/// Repositories declared in a build or settings file, including `buildscript`, settings
+/// `pluginManagement` and `dependencyResolutionManagement` blocks; publishing targets are skipped
+pub fn parse_repositories_from_file(file_path: &Path) -> Result<Vec<RepositoryLocation>> {
+    Ok(parse_build_file(file_path, &HashMap::new())?.repositories)
+}
+
+struct ParsedBuildFile {
+    dependencies: Vec<DependencyLocation>,
+    repositories: Vec<RepositoryLocation>,
+}
+
+fn parse_build_file(
+    file_path: &Path, 
+    version_catalogs: &HashMap<PathBuf, VersionCatalog>
+) -> Result<ParsedBuildFile> {
     let content = fs::read_to_string(file_path)?;
     let mut dependencies = Vec::new();
+    let mut repositories = Vec::new();
     
     let patterns = create_dependency_patterns()?;
     
     #[derive(Debug)]
     enum ParserState {
         Normal,
+        InBuildscript(i32), // brace count for buildscript block (classpath entries are plugins)
+        InPublishing(i32), // brace count for publishing block (its repositories are upload targets)
+        InPluginManagement(i32), // brace count for settings pluginManagement block
+        InDependencyResolutionManagement(i32), // brace count for settings dependencyResolutionManagement block
+        InRepositories(RepositoryScope, Box<ParserState>, i32), // scope, state to return to, brace count for repositories block
         InKotlin(i32), // brace count for kotlin block
         InSourceSets(i32), // brace count for sourceSets block  
         InSourceSet(String, i32), // source set name, brace count for this sourceSet
@@ -217,12 +533,35 @@ pub fn parse_dependencies_from_file(
     
     let mut state = ParserState::Normal;
     
+    // A repositories block written on one line returns to the enclosing state right away
+    let enter_repositories = |repositories: &mut Vec<RepositoryLocation>, scope: RepositoryScope, parent: ParserState, trimmed_line: &str, line_number: usize| {
+        let (_, declarations) = trimmed_line.split_once('{').unwrap_or_default();
+        parse_repository_line(&patterns, declarations, file_path, line_number, scope, repositories);
+        
+        let brace_count = trimmed_line.matches('{').count() as i32 - trimmed_line.matches('}').count() as i32;
+        if brace_count <= 0 {
+            parent
+        } else {
+            ParserState::InRepositories(scope, Box::new(parent), brace_count)
+        }
+    };
+    
     for (line_number, line) in content.lines().enumerate() {
         let trimmed_line = line.trim();
         
         state = match state {
             ParserState::Normal => {
-                if trimmed_line.starts_with("kotlin") && trimmed_line.contains('{') {
+                if trimmed_line.starts_with(regex_patterns::BUILDSCRIPT_BLOCK) && trimmed_line.contains('{') {
+                    ParserState::InBuildscript(1)
+                } else if trimmed_line.starts_with(regex_patterns::PUBLISHING_BLOCK) && trimmed_line.contains('{') {
+                    ParserState::InPublishing(1)
+                } else if trimmed_line.starts_with(regex_patterns::PLUGIN_MANAGEMENT_BLOCK) && trimmed_line.contains('{') {
+                    ParserState::InPluginManagement(1)
+                } else if trimmed_line.starts_with(regex_patterns::DEPENDENCY_RESOLUTION_MANAGEMENT_BLOCK) && trimmed_line.contains('{') {
+                    ParserState::InDependencyResolutionManagement(1)
+                } else if trimmed_line.starts_with(regex_patterns::REPOSITORIES_BLOCK) && trimmed_line.contains('{') {
+                    enter_repositories(&mut repositories, RepositoryScope::Project, ParserState::Normal, trimmed_line, line_number + 1)
+                } else if trimmed_line.starts_with("kotlin") && trimmed_line.contains('{') {
                     ParserState::InKotlin(1)
                 } else if trimmed_line.starts_with("sourceSets") && trimmed_line.contains('{') {
                     ParserState::InSourceSets(1)
@@ -233,6 +572,74 @@ pub fn parse_dependencies_from_file(
                 }
             }
             
+            ParserState::InBuildscript(mut brace_count) => {
+                if trimmed_line.starts_with(regex_patterns::REPOSITORIES_BLOCK) && trimmed_line.contains('{') {
+                    enter_repositories(&mut repositories, RepositoryScope::Buildscript, ParserState::InBuildscript(brace_count), trimmed_line, line_number + 1)
+                } else {
+                    brace_count += trimmed_line.matches('{').count() as i32;
+                    brace_count -= trimmed_line.matches('}').count() as i32;
+                    
+                    if brace_count == 0 {
+                        ParserState::Normal
+                    } else {
+                        ParserState::InBuildscript(brace_count)
+                    }
+                }
+            }
+            
+            ParserState::InPublishing(mut brace_count) => {
+                brace_count += trimmed_line.matches('{').count() as i32;
+                brace_count -= trimmed_line.matches('}').count() as i32;
+                
+                if brace_count == 0 {
+                    ParserState::Normal
+                } else {
+                    ParserState::InPublishing(brace_count)
+                }
+            }
+            
+            ParserState::InPluginManagement(mut brace_count) => {
+                if trimmed_line.starts_with(regex_patterns::REPOSITORIES_BLOCK) && trimmed_line.contains('{') {
+                    enter_repositories(&mut repositories, RepositoryScope::PluginManagement, ParserState::InPluginManagement(brace_count), trimmed_line, line_number + 1)
+                } else {
+                    brace_count += trimmed_line.matches('{').count() as i32;
+                    brace_count -= trimmed_line.matches('}').count() as i32;
+                    
+                    if brace_count == 0 {
+                        ParserState::Normal
+                    } else {
+                        ParserState::InPluginManagement(brace_count)
+                    }
+                }
+            }
+            
+            ParserState::InDependencyResolutionManagement(mut brace_count) => {
+                if trimmed_line.starts_with(regex_patterns::REPOSITORIES_BLOCK) && trimmed_line.contains('{') {
+                    enter_repositories(&mut repositories, RepositoryScope::DependencyResolutionManagement, ParserState::InDependencyResolutionManagement(brace_count), trimmed_line, line_number + 1)
+                } else {
+                    brace_count += trimmed_line.matches('{').count() as i32;
+                    brace_count -= trimmed_line.matches('}').count() as i32;
+                    
+                    if brace_count == 0 {
+                        ParserState::Normal
+                    } else {
+                        ParserState::InDependencyResolutionManagement(brace_count)
+                    }
+                }
+            }
+            
+            ParserState::InRepositories(scope, parent, mut brace_count) => {
+                parse_repository_line(&patterns, trimmed_line, file_path, line_number + 1, scope, &mut repositories);
+                brace_count += trimmed_line.matches('{').count() as i32;
+                brace_count -= trimmed_line.matches('}').count() as i32;
+                
+                if brace_count <= 0 {
+                    *parent
+                } else {
+                    ParserState::InRepositories(scope, parent, brace_count)
+                }
+            }
+            
             ParserState::InKotlin(mut brace_count) => {
                 brace_count += trimmed_line.matches('{').count() as i32;
                 brace_count -= trimmed_line.matches('}').count() as i32;
@@ -302,51 +709,134 @@ pub fn parse_dependencies_from_file(
                     };
                     
                     // Check if this is a project dependency (should be ignored)
-                    if is_project_dependency(&patterns, trimmed_line) {
-                        // Skip project dependencies - clone source_set_name for next iteration
-                        ParserState::InDependencies(source_set_name.clone(), brace_count)
-                    } else if let Some(mut dep) = parse_string_dependency(&patterns.string_dep, trimmed_line, file_path, line_number + 1)? {
-                        dep.configuration = format!("{}{}", dep.configuration, source_set_suffix);
-                        dependencies.push(dep);
-                        ParserState::InDependencies(source_set_name, brace_count)
-                    } else if let Some(mut dep) = parse_map_dependency_group_first(&patterns.map_dep_group_first, trimmed_line, file_path, line_number + 1)? {
-                        dep.configuration = format!("{}{}", dep.configuration, source_set_suffix);
-                        dependencies.push(dep);
-                        ParserState::InDependencies(source_set_name, brace_count)
-                    } else if let Some(mut dep) = parse_map_dependency_name_first(&patterns.map_dep_name_first, trimmed_line, file_path, line_number + 1)? {
-                        dep.configuration = format!("{}{}", dep.configuration, source_set_suffix);
-                        dependencies.push(dep);
-                        ParserState::InDependencies(source_set_name, brace_count)
-                    } else if let Some(mut dep) = parse_libs_dependency(&patterns.libs_dep, trimmed_line, file_path, line_number + 1, version_catalogs)? {
-                        dep.configuration = format!("{}{}", dep.configuration, source_set_suffix);
-                        dependencies.push(dep);
-                        ParserState::InDependencies(source_set_name, brace_count)
-                    } else if let Some(mut dep) = parse_version_catalog_dependency(&patterns.version_catalog_dep, trimmed_line, file_path, line_number + 1, version_catalogs)? {
-                        dep.configuration = format!("{}{}", dep.configuration, source_set_suffix);
-                        dependencies.push(dep);
-                        ParserState::InDependencies(source_set_name, brace_count)
-                    } else {
-                        ParserState::InDependencies(source_set_name, brace_count)
+                    if !is_project_dependency(&patterns, trimmed_line) {
+                        for mut dep in parse_dependency_line(&patterns, trimmed_line, file_path, line_number + 1, version_catalogs)? {
+                            dep.configuration = format!("{}{}", dep.configuration, source_set_suffix);
+                            dependencies.push(dep);
+                        }
                     }
+                    ParserState::InDependencies(source_set_name, brace_count)
                 }
             }
         };
     }
     
-    Ok(dependencies)
+    Ok(ParsedBuildFile { dependencies, repositories })
+}
+
+/// Records the repositories declared on a line of a repositories block; a URL on a line of its
+/// own belongs to the `maven { }` or `ivy { }` block declared before it
+fn parse_repository_line(
+    patterns: &DependencyPatterns,
+    line: &str,
+    file_path: &Path,
+    line_number: usize,
+    scope: RepositoryScope,
+    repositories: &mut Vec<RepositoryLocation>,
+) {
+    let line = strip_line_comment(line);
+    let declarations: Vec<_> = patterns.repository.captures_iter(line).collect();
+    
+    if declarations.is_empty() {
+        let open_block = repositories
+            .last_mut()
+            .filter(|location| matches!(location.repository.kind, RepositoryKind::Maven | RepositoryKind::Ivy) && location.repository.url.is_none());
+        if let (Some(location), Some(captures)) = (open_block, patterns.repository_url.captures(line)) {
+            location.repository.url = Some(captures[1].to_string());
+        }
+        return;
+    }
+    
+    for (index, captures) in declarations.iter().enumerate() {
+        let kind = match &captures[1] {
+            "google" => RepositoryKind::Google,
+            "mavenCentral" => RepositoryKind::MavenCentral,
+            "jcenter" => RepositoryKind::JCenter,
+            "mavenLocal" => RepositoryKind::MavenLocal,
+            "gradlePluginPortal" => RepositoryKind::GradlePluginPortal,
+            "maven" => RepositoryKind::Maven,
+            "ivy" => RepositoryKind::Ivy,
+            _ => RepositoryKind::FlatDir,
+        };
+        // maven("https://...") and one-line maven { url "..." } carry their URL up to the next declaration
+        let url = if matches!(kind, RepositoryKind::Maven | RepositoryKind::Ivy) {
+            let start = captures.get(0).map_or(0, |m| m.end());
+            let end = declarations.get(index + 1).and_then(|next| next.get(0)).map_or(line.len(), |m| m.start());
+            patterns.repository_url.captures(&line[start..end]).map(|url| url[1].to_string())
+        } else {
+            None
+        };
+        repositories.push(RepositoryLocation {
+            repository: Repository { kind, url },
+            file_path: file_path.to_path_buf(),
+            line_number,
+            scope,
+        });
+    }
+}
+
+/// Drops a trailing `// comment`, leaving the `//` of URLs such as `https://` alone
+fn strip_line_comment(line: &str) -> &str {
+    line.match_indices("//")
+        .find(|(index, _)| !line[..*index].ends_with(':'))
+        .map_or(line, |(index, _)| &line[..index])
+}
+
+fn parse_dependency_line(
+    patterns: &DependencyPatterns,
+    line: &str,
+    file_path: &Path,
+    line_number: usize,
+    version_catalogs: &HashMap<PathBuf, VersionCatalog>,
+) -> Result<Vec<DependencyLocation>> {
+    // A bundle expands to all of its libraries at the declaring line
+    let bundle_deps = parse_catalog_bundle_dependency(&patterns.catalog_bundle_dep, line, file_path, line_number, version_catalogs)?;
+    if !bundle_deps.is_empty() {
+        return Ok(bundle_deps);
+    }
+    
+    let parsed = if let Some(dep) = parse_string_dependency(&patterns.string_dep, line, file_path, line_number)? {
+        Some(dep)
+    } else if let Some(dep) = parse_map_dependency(&patterns.map_dep, &patterns.map_dep_entry, line, file_path, line_number)? {
+        Some(dep)
+    } else if let Some(dep) = parse_find_library_dependency(&patterns.find_library_dep, line, file_path, line_number, version_catalogs)? {
+        Some(dep)
+    } else {
+        parse_version_catalog_dependency(&patterns.version_catalog_dep, line, file_path, line_number, version_catalogs)?
+    };
+    
+    Ok(parsed
+        .map(|mut dep| {
+            apply_variant_modifiers(&mut dep.dependency, patterns, line);
+            dep
+        })
+        .into_iter()
+        .collect())
+}
+
+/// Applies `testFixtures(...)` and `variantOf(...) { classifier(..); artifactType(..) }` wrappers
+fn apply_variant_modifiers(dependency: &mut Dependency, patterns: &DependencyPatterns, line: &str) {
+    if patterns.test_fixtures.is_match(line) {
+        dependency.classifier = Some(TEST_FIXTURES_CLASSIFIER.to_string());
+    } else if patterns.variant_of.is_match(line) {
+        if let Some(captures) = patterns.variant_classifier.captures(line) {
+            dependency.classifier = Some(captures[1].to_string());
+        }
+        if let Some(captures) = patterns.variant_artifact_type.captures(line) {
+            dependency.extension = Some(captures[1].to_string());
+        }
+    }
 }
 
 fn create_dependency_location(
-    group: String,
-    artifact: String,
-    version: Option<String>,
+    dependency: Dependency,
     configuration: String,
     file_path: &Path,
     line_number: usize,
     source_type: DependencySourceType,
 ) -> DependencyLocation {
     DependencyLocation {
-        dependency: Dependency { group, artifact, version },
+        dependency,
         file_path: file_path.to_path_buf(),
         line_number,
         configuration,
@@ -362,14 +852,16 @@ fn parse_string_dependency(
 ) -> Result<Option<DependencyLocation>> {
     if let Some(captures) = regex.captures(line) {
         let configuration = captures[1].to_string();
-        let group = captures[2].to_string();
-        let artifact = captures[3].to_string();
-        let version = captures.get(4).map(|m| m.as_str().to_string());
+        let mut dependency = Dependency::new(
+            captures[2].to_string(),
+            captures[3].to_string(),
+            captures.get(4).map(|m| m.as_str().to_string()),
+        );
+        dependency.classifier = captures.get(5).map(|m| m.as_str().to_string());
+        dependency.extension = captures.get(6).map(|m| m.as_str().to_string());
         
         Ok(Some(create_dependency_location(
-            group,
-            artifact,
-            version,
+            dependency,
             configuration,
             file_path,
             line_number,
@@ -380,56 +872,38 @@ fn parse_string_dependency(
     }
 }
 
-fn parse_map_dependency_group_first(
+fn parse_map_dependency(
     regex: &Regex,
+    entry_regex: &Regex,
     line: &str,
     file_path: &Path,
     line_number: usize,
 ) -> Result<Option<DependencyLocation>> {
     if let Some(captures) = regex.captures(line) {
         let configuration = captures[1].to_string();
-        let group = captures[2].to_string();
-        let artifact = captures[3].to_string();
-        let version = Some(captures[4].to_string());
         
-        Ok(Some(create_dependency_location(
-            group,
-            artifact,
-            version,
-            configuration,
-            file_path,
-            line_number,
-            DependencySourceType::Direct,
-        )))
-    } else {
-        Ok(None)
-    }
-}
-
-fn parse_map_dependency_name_first(
-    regex: &Regex,
-    line: &str,
-    file_path: &Path,
-    line_number: usize,
-) -> Result<Option<DependencyLocation>> {
-    if let Some(captures) = regex.captures(line) {
-        let configuration = captures[1].to_string();
-        let artifact = captures[2].to_string();
-        let group = captures[3].to_string();
-        let version = Some(captures[4].to_string());
+        // Entries may appear in any order: group, name, version, classifier, ext
+        let mut entries: HashMap<String, String> = HashMap::new();
+        for entry in entry_regex.captures_iter(line) {
+            entries.insert(entry[1].to_string(), entry[2].to_string());
+        }
         
-        Ok(Some(create_dependency_location(
-            group,
-            artifact,
-            version,
-            configuration,
-            file_path,
-            line_number,
-            DependencySourceType::Direct,
-        )))
-    } else {
-        Ok(None)
+        if let (Some(group), Some(artifact)) = (entries.remove("group"), entries.remove("name")) {
+            let mut dependency = Dependency::new(group, artifact, entries.remove("version"));
+            dependency.classifier = entries.remove("classifier");
+            dependency.extension = entries.remove("ext");
+            
+            return Ok(Some(create_dependency_location(
+                dependency,
+                configuration,
+                file_path,
+                line_number,
+                DependencySourceType::Direct,
+            )));
+        }
     }
+    
+    Ok(None)
 }
 
 fn is_project_dependency(patterns: &DependencyPatterns, line: &str) -> bool {
@@ -439,11 +913,7 @@ fn is_project_dependency(patterns: &DependencyPatterns, line: &str) -> bool {
 fn extract_source_set_dependencies(line: &str) -> Option<String> {
     // Match patterns like "commonMain.dependencies {" or "androidMain.dependencies {"
     let re = regex::Regex::new(r"^\s*([a-zA-Z0-9]+)\.dependencies\s*\{\s*$").ok()?;
-    if let Some(captures) = re.captures(line) {
-        Some(captures[1].to_string())
-    } else {
-        None
-    }
+    re.captures(line).map(|captures| captures[1].to_string())
 }
 
 fn extract_source_set_block(line: &str) -> Option<String> {
@@ -480,35 +950,34 @@ fn parse_version_catalog_dependency(
             return Ok(None); // This is a projects accessor, not a version catalog
         }
         
-        // Try to resolve from version catalogs
-        for catalog in version_catalogs.values() {
-            // For compose.xxx, look for compose-xxx or compose.xxx in catalog
-            let lookup_key = if catalog_name == "compose" {
-                format!("compose-{}", lib_reference.replace('.', "-"))
-            } else {
-                lib_reference.replace('.', "-")
-            };
-            
-            if let Some((group, artifact, version)) = catalog.resolve_library_version(&lookup_key) {
-                return Ok(Some(create_dependency_location(
-                    group,
-                    artifact,
-                    Some(version),
-                    configuration,
-                    file_path,
-                    line_number,
-                    DependencySourceType::VersionCatalog(format!("{}.{}", catalog_name, lib_reference)),
-                )));
-            }
+        // Resolve against the catalog registered under this accessor
+        if let Some((group, artifact, version)) = resolve_catalog_library(version_catalogs, &catalog_name, &lib_reference, file_path) {
+            return Ok(Some(create_dependency_location(
+                Dependency::new(group, artifact, Some(version)),
+                configuration,
+                file_path,
+                line_number,
+                DependencySourceType::VersionCatalog(format!("{}.{}", catalog_name, lib_reference)),
+            )));
         }
         
         // If not found in catalogs, check if it might be a built-in accessor like compose.runtime
         if catalog_name == "compose" {
-            // Create a synthetic dependency for compose BOM entries
+            // Projects may pin compose-xxx entries in any of their catalogs, the nearest build's first
+            let lookup_key = format!("compose-{}", lib_reference.replace('.', "-"));
+            let pinned = catalogs_in_scope(version_catalogs, file_path)
+                .into_iter()
+                .find_map(|catalog| catalog.resolve_library_version(&lookup_key));
+            
+            let dependency = match pinned {
+                Some((group, artifact, version)) => Dependency::new(group, artifact, Some(version)),
+                // Create a synthetic dependency for compose BOM entries
+                // Version managed by compose BOM
+                None => Dependency::new("org.jetbrains.compose".to_string(), lib_reference.replace('.', "-"), None),
+            };
+            
             return Ok(Some(create_dependency_location(
-                "org.jetbrains.compose".to_string(),
-                lib_reference.replace('.', "-"),
-                None, // Version managed by compose BOM
+                dependency,
                 configuration,
                 file_path,
                 line_number,
@@ -520,30 +989,60 @@ fn parse_version_catalog_dependency(
     Ok(None)
 }
 
-fn parse_libs_dependency(
+fn parse_catalog_bundle_dependency(
     regex: &Regex,
     line: &str,
     file_path: &Path,
     line_number: usize,
     version_catalogs: &HashMap<PathBuf, VersionCatalog>,
-) -> Result<Option<DependencyLocation>> {
+) -> Result<Vec<DependencyLocation>> {
     if let Some(captures) = regex.captures(line) {
         let configuration = captures[1].to_string();
-        let lib_reference = captures[2].to_string();
+        let catalog_name = captures[2].to_string();
+        let bundle_reference = captures[3].to_string();
+        
+        let members = catalog_named(version_catalogs, &catalog_name, file_path)
+            .and_then(|catalog| catalog.resolve_bundle(&bundle_reference));
         
-        // Try to resolve from version catalogs
-        for catalog in version_catalogs.values() {
-            if let Some((group, artifact, version)) = catalog.resolve_library_version(&lib_reference) {
-                return Ok(Some(create_dependency_location(
-                    group,
-                    artifact,
-                    Some(version),
-                    configuration,
+        if let Some(members) = members {
+            let source_reference = format!("{}.bundles.{}", catalog_name, bundle_reference);
+            return Ok(members
+                .into_iter()
+                .map(|(group, artifact, version)| create_dependency_location(
+                    Dependency::new(group, artifact, version),
+                    configuration.clone(),
                     file_path,
                     line_number,
-                    DependencySourceType::VersionCatalog(lib_reference),
-                )));
-            }
+                    DependencySourceType::CatalogBundle(source_reference.clone()),
+                ))
+                .collect());
+        }
+    }
+    
+    Ok(Vec::new())
+}
+
+fn parse_find_library_dependency(
+    regex: &Regex,
+    line: &str,
+    file_path: &Path,
+    line_number: usize,
+    version_catalogs: &HashMap<PathBuf, VersionCatalog>,
+) -> Result<Option<DependencyLocation>> {
+    if let Some(captures) = regex.captures(line) {
+        // Either add("configuration", ...) or configuration(...)
+        let configuration = captures.get(1).or(captures.get(2)).map(|m| m.as_str().to_string()).unwrap_or_default();
+        let catalog_name = captures[3].to_string();
+        let lib_reference = captures[4].to_string();
+        
+        if let Some((group, artifact, version)) = resolve_catalog_library(version_catalogs, &catalog_name, &lib_reference, file_path) {
+            return Ok(Some(create_dependency_location(
+                Dependency::new(group, artifact, Some(version)),
+                configuration,
+                file_path,
+                line_number,
+                DependencySourceType::VersionCatalog(format!("{}.{}", catalog_name, lib_reference.replace('-', "."))),
+            )));
         }
     }
     
@@ -562,6 +1061,7 @@ fn create_plugin_location(
         file_path: file_path.to_path_buf(),
         line_number,
         source_type,
+        apply: true,
     }
 }
 
@@ -674,6 +1174,27 @@ fn parse_plugin_kotlin_shorthand_version(
     }
 }
 
+fn parse_plugin_kotlin_shorthand_id_only(
+    regex: &Regex,
+    line: &str,
+    file_path: &Path,
+    line_number: usize,
+) -> Result<Option<PluginLocation>> {
+    if let Some(captures) = regex.captures(line) {
+        let id = format!("org.jetbrains.kotlin.{}", &captures[1]);
+        
+        Ok(Some(create_plugin_location(
+            id,
+            None,
+            file_path,
+            line_number,
+            PluginSourceType::PluginsBlock,
+        )))
+    } else {
+        Ok(None)
+    }
+}
+
 fn parse_plugin_kotlin_shorthand_only(
     regex: &Regex,
     line: &str,
@@ -681,15 +1202,12 @@ fn parse_plugin_kotlin_shorthand_only(
     line_number: usize,
 ) -> Result<Option<PluginLocation>> {
     if let Some(captures) = regex.captures(line) {
-        let plugin_name = captures[1].to_string();
+        let id = captures[1].to_string();
         
-        // Only handle common plugin names to avoid false matches
-        let id = match plugin_name.as_str() {
-            "application" => "application".to_string(),
-            "java-library" => "java-library".to_string(),
-            "java" => "java".to_string(),
-            _ => return Ok(None),
-        };
+        // Only handle known plugin accessors to avoid false matches
+        if !PLUGIN_ACCESSORS.contains(&id.as_str()) {
+            return Ok(None);
+        }
         
         Ok(Some(create_plugin_location(
             id,
@@ -732,19 +1250,68 @@ fn parse_libs_plugin(
     version_catalogs: &HashMap<PathBuf, VersionCatalog>,
 ) -> Result<Option<PluginLocation>> {
     if let Some(captures) = regex.captures(line) {
-        let plugin_reference = captures[1].to_string();
+        let catalog_name = captures[1].to_string();
+        let plugin_reference = captures[2].to_string();
         
-        // Try to resolve from version catalogs
-        for catalog in version_catalogs.values() {
-            if let Some((id, version)) = catalog.resolve_plugin_version(&plugin_reference) {
-                return Ok(Some(create_plugin_location(
-                    id,
-                    version,
-                    file_path,
-                    line_number,
-                    PluginSourceType::VersionCatalog(plugin_reference),
-                )));
-            }
+        // Resolve against the catalog registered under this accessor
+        let resolved = catalog_named(version_catalogs, &catalog_name, file_path)
+            .and_then(|catalog| catalog.resolve_plugin_version(&plugin_reference));
+        if let Some((id, version)) = resolved {
+            return Ok(Some(create_plugin_location(
+                id,
+                version,
+                file_path,
+                line_number,
+                PluginSourceType::VersionCatalog(format!("{}.plugins.{}", catalog_name, plugin_reference)),
+            )));
+        }
+    }
+    
+    Ok(None)
+}
+
+fn parse_classpath_plugin(
+    regex: &Regex,
+    line: &str,
+    file_path: &Path,
+    line_number: usize,
+) -> Result<Option<PluginLocation>> {
+    if let Some(captures) = regex.captures(line) {
+        let coordinate = format!("{}:{}", &captures[1], &captures[2]);
+        let version = captures.get(3).map(|m| m.as_str().to_string());
+        
+        Ok(Some(create_plugin_location(
+            coordinate.clone(),
+            version,
+            file_path,
+            line_number,
+            PluginSourceType::BuildscriptClasspath(coordinate),
+        )))
+    } else {
+        Ok(None)
+    }
+}
+
+fn parse_libs_classpath_plugin(
+    regex: &Regex,
+    line: &str,
+    file_path: &Path,
+    line_number: usize,
+    version_catalogs: &HashMap<PathBuf, VersionCatalog>,
+) -> Result<Option<PluginLocation>> {
+    if let Some(captures) = regex.captures(line) {
+        let catalog_name = captures[1].to_string();
+        let lib_reference = captures[2].to_string();
+        
+        if let Some((group, artifact, version)) = resolve_catalog_library(version_catalogs, &catalog_name, &lib_reference, file_path) {
+            let coordinate = format!("{}:{}", group, artifact);
+            return Ok(Some(create_plugin_location(
+                coordinate.clone(),
+                Some(version),
+                file_path,
+                line_number,
+                PluginSourceType::BuildscriptClasspath(coordinate),
+            )));
         }
     }
     
@@ -0,0 +1,107 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::findings::{FindingReporter, FindingsByFile};
+use crate::parser::{PluginLocation, PluginSourceType};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum PluginApplicationFindingKind {
+    RedundantVersion,
+    MixedApplication,
+    UnusedApplyFalse,
+    LegacyApplyPlugin,
+}
+
+/// Whether a declaration applies the plugin to its project rather than only declaring its version
+fn is_application(location: &PluginLocation) -> bool {
+    location.apply && !matches!(
+        location.source_type,
+        PluginSourceType::BuildscriptClasspath(_) | PluginSourceType::PluginManagement
+    )
+}
+
+/// Plugins applied in a way the plugins DSL makes redundant or discourages, about the plugin id and keyed by build file
+pub fn analyze_plugin_applications(all_plugins: &[PluginLocation]) -> FindingsByFile<PluginApplicationFindingKind, String> {
+    let mut findings = FindingReporter::new();
+    let mut report = |file_path: &Path, kind, plugin_id: &str, line_number, message| {
+        findings.report(file_path, line_number, kind, plugin_id.to_string(), message);
+    };
+
+    let apply_false: Vec<&PluginLocation> = all_plugins
+        .iter()
+        .filter(|loc| !loc.apply && !matches!(loc.source_type, PluginSourceType::Inherited(_)))
+        .collect();
+    let applied_ids: HashSet<&str> = all_plugins
+        .iter()
+        .filter(|loc| is_application(loc))
+        .map(|loc| loc.plugin.id.as_str())
+        .collect();
+
+    for declaration in &apply_false {
+        if !applied_ids.contains(declaration.plugin.id.as_str()) {
+            report(
+                &declaration.file_path,
+                PluginApplicationFindingKind::UnusedApplyFalse,
+                &declaration.plugin.id,
+                declaration.line_number,
+                format!("`{}` is declared with `apply false` but no module applies it", declaration.plugin.id),
+            );
+        }
+    }
+
+    // Subprojects resolve the plugin from the classpath of the build file that declared it with `apply false`
+    for location in all_plugins.iter().filter(|loc| is_application(loc) && matches!(loc.source_type, PluginSourceType::PluginsBlock)) {
+        let Some(version) = &location.plugin.version else { continue };
+        let Some(module_dir) = location.file_path.parent() else { continue };
+        let root_declaration = apply_false.iter().find(|declaration| {
+            declaration.plugin.id == location.plugin.id
+                && declaration.file_path != location.file_path
+                && declaration.file_path.parent().is_some_and(|root_dir| module_dir.starts_with(root_dir))
+        });
+        if let Some(declaration) = root_declaration {
+            report(
+                &location.file_path,
+                PluginApplicationFindingKind::RedundantVersion,
+                &location.plugin.id,
+                location.line_number,
+                format!(
+                    "`{}` declares version {} although {}:{} already adds it with `apply false`; drop the version",
+                    location.plugin.id,
+                    version,
+                    declaration.file_path.display(),
+                    declaration.line_number
+                ),
+            );
+        }
+    }
+
+    let mut dsl_applications: HashMap<(&Path, &str), usize> = HashMap::new();
+    for location in all_plugins.iter().filter(|loc| is_application(loc)) {
+        if matches!(location.source_type, PluginSourceType::PluginsBlock | PluginSourceType::VersionCatalog(_)) {
+            dsl_applications.entry((location.file_path.as_path(), location.plugin.id.as_str())).or_insert(location.line_number);
+        }
+    }
+
+    for location in all_plugins.iter().filter(|loc| matches!(loc.source_type, PluginSourceType::ApplyPlugin)) {
+        let (kind, message) = match dsl_applications.get(&(location.file_path.as_path(), location.plugin.id.as_str())) {
+            Some(plugins_line) => (
+                PluginApplicationFindingKind::MixedApplication,
+                format!("`{}` is applied with `apply plugin` and again in the plugins block at line {}", location.plugin.id, plugins_line),
+            ),
+            None => (
+                PluginApplicationFindingKind::LegacyApplyPlugin,
+                format!("`apply plugin` of `{}` can move to the plugins block", location.plugin.id),
+            ),
+        };
+        report(&location.file_path, kind, &location.plugin.id, location.line_number, message);
+    }
+
+    findings.finish()
+}
\ No newline at end of file
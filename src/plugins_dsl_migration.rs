@@ -0,0 +1,220 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::config::{regex_patterns, Config};
+use crate::error::Result;
+use crate::parser::{find_gradle_files, load_version_catalogs, parse_plugins_from_file_with_config, PluginLocation, PluginSourceType};
+use crate::rewrite::FileChange;
+use regex::Regex;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+/// Indentation of plugins block entries when the block is created
+const PLUGINS_BLOCK_INDENT: &str = "    ";
+
+#[derive(Debug, Clone)]
+pub struct MigratedPlugin {
+    /// Located by the canonical id requested in the plugins block, e.g. `org.jetbrains.kotlin.android`
+    pub location: PluginLocation,
+    /// The removed statement, e.g. `apply plugin: 'kotlin-android'`
+    pub statement: String,
+    /// The plugins block already requested the plugin, so the statement was only removed
+    pub already_declared: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct SkippedPlugin {
+    pub location: PluginLocation,
+    pub reason: String,
+}
+
+/// Planned move of `apply plugin` statements into the plugins block of their build file
+#[derive(Debug, Clone)]
+pub struct PluginsDslMigration {
+    pub changes: Vec<FileChange>,
+    pub migrated: Vec<MigratedPlugin>,
+    pub skipped: Vec<SkippedPlugin>,
+}
+
+pub fn plan_plugins_dsl_migration(root_path: &Path, config: &Config) -> Result<PluginsDslMigration> {
+    let version_catalogs = load_version_catalogs(root_path)?;
+    let statement_regexes = [
+        Regex::new(regex_patterns::APPLY_PLUGIN_STATEMENT)?,
+        Regex::new(regex_patterns::APPLY_PLUGIN_GROOVY_STATEMENT)?,
+    ];
+    let mut build_files = find_gradle_files(root_path)?;
+    build_files.sort();
+
+    let mut changes = Vec::new();
+    let mut migrated = Vec::new();
+    let mut skipped = Vec::new();
+
+    for build_file in build_files {
+        let plugins = parse_plugins_from_file_with_config(&build_file, &version_catalogs, config)?;
+        if !plugins.iter().any(|plugin| matches!(plugin.source_type, PluginSourceType::ApplyPlugin)) {
+            continue;
+        }
+
+        let original = fs::read_to_string(&build_file)?;
+        let lines: Vec<&str> = original.lines().collect();
+        let depths = line_depths(&lines);
+        let plugins_block = top_level_block(&lines, &depths, regex_patterns::PLUGINS_BLOCK);
+
+        let mut requested: HashSet<String> = plugins
+            .iter()
+            .filter(|plugin| matches!(plugin.source_type, PluginSourceType::PluginsBlock | PluginSourceType::VersionCatalog(_)))
+            .map(|plugin| plugin.plugin.id.clone())
+            .collect();
+        let mut removed_lines = HashSet::new();
+        let mut entries = Vec::new();
+
+        for location in plugins.into_iter().filter(|plugin| matches!(plugin.source_type, PluginSourceType::ApplyPlugin)) {
+            let index = location.line_number - 1;
+            let reason = if depths[index] > 0 {
+                Some("applied inside a block; the plugins block only configures the project itself")
+            } else if !statement_regexes.iter().any(|regex| regex.is_match(lines[index])) {
+                Some("shares its line with other statements")
+            } else if matches!(plugins_block, Some((start, end)) if start == end) {
+                Some("the plugins block is written on one line")
+            } else {
+                None
+            };
+            if let Some(reason) = reason {
+                skipped.push(SkippedPlugin { location, reason: reason.to_string() });
+                continue;
+            }
+
+            let already_declared = !requested.insert(location.plugin.id.clone());
+            if !already_declared {
+                entries.push(plugin_request(&build_file, &location.plugin.id));
+            }
+            removed_lines.insert(index);
+            migrated.push(MigratedPlugin { location, statement: lines[index].trim().to_string(), already_declared });
+        }
+
+        if removed_lines.is_empty() {
+            continue;
+        }
+
+        let updated = rewrite_build_file(&original, &lines, &depths, plugins_block, &removed_lines, &entries);
+        changes.push(FileChange { path: build_file, original, updated });
+    }
+
+    Ok(PluginsDslMigration { changes, migrated, skipped })
+}
+
+/// `id("...")` for Kotlin DSL build files, `id '...'` for Groovy
+fn plugin_request(build_file: &Path, plugin_id: &str) -> String {
+    if build_file.extension().is_some_and(|extension| extension == "kts") {
+        format!("id(\"{}\")", plugin_id)
+    } else {
+        format!("id '{}'", plugin_id)
+    }
+}
+
+/// Brace depth at the start of every line
+fn line_depths(lines: &[&str]) -> Vec<usize> {
+    let mut depth: usize = 0;
+    lines
+        .iter()
+        .map(|line| {
+            let line_depth = depth;
+            let opened = line.matches('{').count();
+            let closed = line.matches('}').count();
+            depth = (depth + opened).saturating_sub(closed);
+            line_depth
+        })
+        .collect()
+}
+
+/// First and last line index of a top-level `name { }` block
+fn top_level_block(lines: &[&str], depths: &[usize], name: &str) -> Option<(usize, usize)> {
+    let start = (0..lines.len()).find(|&index| {
+        let trimmed = lines[index].trim_start();
+        depths[index] == 0
+            && trimmed.strip_prefix(name).is_some_and(|rest| rest.trim_start().starts_with('{'))
+    })?;
+    // The block ends on the line after which the depth is back to zero
+    let end = (start..lines.len())
+        .find(|&index| depths.get(index + 1).copied().unwrap_or(0) == 0)
+        .unwrap_or(start);
+    Some((start, end))
+}
+
+fn rewrite_build_file(
+    original: &str,
+    lines: &[&str],
+    depths: &[usize],
+    plugins_block: Option<(usize, usize)>,
+    removed_lines: &HashSet<usize>,
+    entries: &[String],
+) -> String {
+    // Requests go at the end of the existing block, or into a new block after buildscript { } and leading comments
+    let (insert_at, block) = match plugins_block {
+        Some((start, end)) => {
+            let indent = lines[start + 1..end]
+                .iter()
+                .find(|line| !line.trim().is_empty())
+                .map(|line| line[..line.len() - line.trim_start().len()].to_string())
+                .unwrap_or_else(|| PLUGINS_BLOCK_INDENT.to_string());
+            (end, entries.iter().map(|entry| format!("{}{}", indent, entry)).collect::<Vec<_>>())
+        }
+        None => {
+            let insert_at = match top_level_block(lines, depths, regex_patterns::BUILDSCRIPT_BLOCK) {
+                Some((_, end)) => end + 1,
+                None => lines
+                    .iter()
+                    .position(|line| {
+                        let trimmed = line.trim();
+                        !(trimmed.is_empty() || trimmed.starts_with("//") || trimmed.starts_with("import "))
+                    })
+                    .unwrap_or(lines.len()),
+            };
+            let mut block = Vec::new();
+            if insert_at > 0 && !lines[insert_at - 1].trim().is_empty() {
+                block.push(String::new());
+            }
+            block.push(format!("{} {{", regex_patterns::PLUGINS_BLOCK));
+            block.extend(entries.iter().map(|entry| format!("{}{}", PLUGINS_BLOCK_INDENT, entry)));
+            block.push("}".to_string());
+            if lines.get(insert_at).is_some_and(|line| !line.trim().is_empty()) {
+                block.push(String::new());
+            }
+            (insert_at, block)
+        }
+    };
+
+    let mut updated_lines: Vec<String> = Vec::new();
+    let mut after_removal = false;
+    for (index, line) in lines.iter().enumerate() {
+        if index == insert_at && !entries.is_empty() {
+            updated_lines.extend(block.iter().cloned());
+        }
+        if removed_lines.contains(&index) {
+            after_removal = true;
+            continue;
+        }
+        // Removing a paragraph of statements leaves a single blank line between its neighbours
+        let collapses = line.trim().is_empty()
+            && after_removal
+            && updated_lines.last().is_none_or(|previous| previous.trim().is_empty());
+        after_removal = false;
+        if !collapses {
+            updated_lines.push(line.to_string());
+        }
+    }
+    if insert_at == lines.len() && !entries.is_empty() {
+        updated_lines.extend(block);
+    }
+
+    let mut updated = updated_lines.join("\n");
+    if original.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated
+}
\ No newline at end of file
@@ -0,0 +1,135 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::config::file_patterns;
+use crate::error::Result;
+use crate::findings::{FindingReporter, FindingsByFile};
+use crate::parser::{find_gradle_files, find_settings_files, parse_repositories_from_file, RepositoryKind, RepositoryLocation, RepositoryScope};
+use crate::settings::load_build_settings;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Settings mode under which Gradle fails the build on any project repository
+const FAIL_ON_PROJECT_REPOS: &str = "FAIL_ON_PROJECT_REPOS";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum RepositoryFindingKind {
+    DeprecatedJcenter,
+    MavenLocal,
+    InsecureUrl,
+    ProjectRepositoryRejected,
+    DuplicateRepository,
+    ProvidedBySettings,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RepositoryAnalysis {
+    pub repositories: Vec<RepositoryLocation>,
+    /// About the repository as declared, e.g. `jcenter()` or `maven(https://jitpack.io)`
+    pub findings: FindingsByFile<RepositoryFindingKind, String>,
+}
+
+/// Repositories of the settings and build files of the build rooted at `root_path`, with the declarations
+/// that are deprecated, insecure, machine-dependent, rejected by settings, repeated or already provided by settings
+pub fn analyze_repositories(root_path: &Path) -> Result<RepositoryAnalysis> {
+    let repositories_mode = load_build_settings(root_path)?.repositories_mode;
+    let mut files = find_settings_files(root_path)?;
+    files.extend(find_gradle_files(root_path)?);
+    files.sort();
+
+    let mut repositories = Vec::new();
+    for file in &files {
+        repositories.extend(parse_repositories_from_file(file)?);
+    }
+
+    let mut findings = FindingReporter::new();
+    let mut report = |location: &RepositoryLocation, kind, message| {
+        findings.report(&location.file_path, location.line_number, kind, location.repository.to_string(), message);
+    };
+    // Repositories settings declare for every project, at their first declaration
+    let mut settings_repositories: HashMap<(RepositoryKind, Option<&str>), &RepositoryLocation> = HashMap::new();
+    for location in repositories.iter().filter(|location| location.scope == RepositoryScope::DependencyResolutionManagement) {
+        settings_repositories.entry((location.repository.kind, normalized_url(location))).or_insert(location);
+    }
+    let fails_on_project_repositories = repositories_mode.as_deref() == Some(FAIL_ON_PROJECT_REPOS);
+    let mut first_declarations: HashMap<(&Path, RepositoryScope, RepositoryKind, Option<&str>), usize> = HashMap::new();
+
+    for location in &repositories {
+        match location.repository.kind {
+            RepositoryKind::JCenter => report(
+                location,
+                RepositoryFindingKind::DeprecatedJcenter,
+                "jcenter() is read-only and deprecated; resolve from mavenCentral() instead".to_string(),
+            ),
+            RepositoryKind::MavenLocal => report(
+                location,
+                RepositoryFindingKind::MavenLocal,
+                "mavenLocal() resolves from the local ~/.m2 cache, so results differ between machines; keep it out of shared build configuration".to_string(),
+            ),
+            _ => {}
+        }
+
+        if let Some(url) = location.repository.url.as_deref().filter(|url| url.starts_with("http://")) {
+            report(
+                location,
+                RepositoryFindingKind::InsecureUrl,
+                format!("{} is fetched over plain HTTP; use https:// (Gradle refuses it unless allowInsecureProtocol is set)", url),
+            );
+        }
+
+        let is_settings_file = location
+            .file_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| file_patterns::GRADLE_SETTINGS_FILES.contains(&name));
+        if location.scope == RepositoryScope::Project && !is_settings_file && fails_on_project_repositories {
+            report(
+                location,
+                RepositoryFindingKind::ProjectRepositoryRejected,
+                format!(
+                    "settings sets RepositoriesMode.{}, so Gradle fails on this project repository; declare it in dependencyResolutionManagement",
+                    FAIL_ON_PROJECT_REPOS
+                ),
+            );
+        }
+
+        if location.scope == RepositoryScope::Project && !is_settings_file && !fails_on_project_repositories {
+            if let Some(settings_location) = settings_repositories.get(&(location.repository.kind, normalized_url(location))) {
+                report(
+                    location,
+                    RepositoryFindingKind::ProvidedBySettings,
+                    format!(
+                        "{} is already provided by dependencyResolutionManagement at {}:{}; declare repositories once in settings",
+                        location.repository,
+                        settings_location.file_path.display(),
+                        settings_location.line_number
+                    ),
+                );
+            }
+        }
+
+        let key = (location.file_path.as_path(), location.scope, location.repository.kind, normalized_url(location));
+        match first_declarations.get(&key) {
+            Some(first_line) => report(
+                location,
+                RepositoryFindingKind::DuplicateRepository,
+                format!("{} is already declared at line {}", location.repository, first_line),
+            ),
+            None => {
+                first_declarations.insert(key, location.line_number);
+            }
+        }
+    }
+
+    Ok(RepositoryAnalysis { repositories, findings: findings.finish() })
+}
+
+/// https://repo.example.com/ and https://repo.example.com are the same repository
+fn normalized_url(location: &RepositoryLocation) -> Option<&str> {
+    location.repository.url.as_deref().map(|url| url.trim_end_matches('/'))
+}
\ No newline at end of file
@@ -0,0 +1,162 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::error::Result;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Number of unchanged lines shown around each hunk of a unified diff
+const DIFF_CONTEXT_LINES: usize = 3;
+
+/// A planned edit of one file; `original` is empty for files that do not exist yet
+#[derive(Debug, Clone)]
+pub struct FileChange {
+    pub path: PathBuf,
+    pub original: String,
+    pub updated: String,
+}
+
+impl FileChange {
+    /// Unified diff of the change with paths relative to `root_path`
+    pub fn unified_diff(&self, root_path: &Path) -> String {
+        let label = self.path.strip_prefix(root_path).unwrap_or(&self.path).display().to_string();
+        let old_label = if self.original.is_empty() { "/dev/null".to_string() } else { format!("a/{}", label) };
+        unified_diff(&self.original, &self.updated, &old_label, &format!("b/{}", label))
+    }
+}
+
+/// Writes every change to disk, creating parent directories of new files
+pub fn apply_file_changes(changes: &[FileChange]) -> Result<()> {
+    for change in changes {
+        if let Some(parent) = change.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&change.path, &change.updated)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DiffOp {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Line-based unified diff; empty when both texts have the same lines
+pub fn unified_diff(original: &str, updated: &str, old_label: &str, new_label: &str) -> String {
+    let old_lines: Vec<&str> = original.lines().collect();
+    let new_lines: Vec<&str> = updated.lines().collect();
+    let ops = diff_lines(&old_lines, &new_lines);
+
+    if ops.iter().all(|(op, _)| *op == DiffOp::Equal) {
+        return String::new();
+    }
+
+    let mut output = format!("--- {}\n+++ {}\n", old_label, new_label);
+
+    // Old and new line index before each op
+    let mut positions = Vec::with_capacity(ops.len());
+    let (mut old_index, mut new_index) = (0, 0);
+    for (op, _) in &ops {
+        positions.push((old_index, new_index));
+        match op {
+            DiffOp::Equal => { old_index += 1; new_index += 1; }
+            DiffOp::Delete => old_index += 1,
+            DiffOp::Insert => new_index += 1,
+        }
+    }
+
+    let changed: Vec<usize> = (0..ops.len()).filter(|&i| ops[i].0 != DiffOp::Equal).collect();
+    let mut hunk_start = 0;
+    while hunk_start < changed.len() {
+        // Changes closer than twice the context share a hunk
+        let mut hunk_end = hunk_start;
+        while hunk_end + 1 < changed.len() && changed[hunk_end + 1] - changed[hunk_end] <= DIFF_CONTEXT_LINES * 2 + 1 {
+            hunk_end += 1;
+        }
+
+        let first = changed[hunk_start].saturating_sub(DIFF_CONTEXT_LINES);
+        let last = (changed[hunk_end] + DIFF_CONTEXT_LINES).min(ops.len() - 1);
+        let hunk = &ops[first..=last];
+        let old_count = hunk.iter().filter(|(op, _)| *op != DiffOp::Insert).count();
+        let new_count = hunk.iter().filter(|(op, _)| *op != DiffOp::Delete).count();
+        let (old_start, new_start) = positions[first];
+
+        output.push_str(&format!("@@ -{} +{} @@\n",
+            hunk_range(old_start, old_count),
+            hunk_range(new_start, new_count)
+        ));
+        for (op, line) in hunk {
+            let prefix = match op {
+                DiffOp::Equal => ' ',
+                DiffOp::Delete => '-',
+                DiffOp::Insert => '+',
+            };
+            output.push(prefix);
+            output.push_str(line);
+            output.push('\n');
+        }
+
+        hunk_start = hunk_end + 1;
+    }
+
+    output
+}
+
+fn hunk_range(start: usize, count: usize) -> String {
+    // An empty range points at the line before it
+    let start = if count == 0 { start } else { start + 1 };
+    if count == 1 {
+        start.to_string()
+    } else {
+        format!("{},{}", start, count)
+    }
+}
+
+/// Longest common subsequence diff, after trimming the common prefix and suffix
+fn diff_lines<'a>(old_lines: &[&'a str], new_lines: &[&'a str]) -> Vec<(DiffOp, &'a str)> {
+    let prefix = old_lines.iter().zip(new_lines).take_while(|(a, b)| a == b).count();
+    let suffix = old_lines[prefix..].iter().rev()
+        .zip(new_lines[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_middle = &old_lines[prefix..old_lines.len() - suffix];
+    let new_middle = &new_lines[prefix..new_lines.len() - suffix];
+
+    // lcs[i][j] is the LCS length of old_middle[i..] and new_middle[j..]
+    let mut lcs = vec![vec![0u32; new_middle.len() + 1]; old_middle.len() + 1];
+    for i in (0..old_middle.len()).rev() {
+        for j in (0..new_middle.len()).rev() {
+            lcs[i][j] = if old_middle[i] == new_middle[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops: Vec<(DiffOp, &str)> = old_lines[..prefix].iter().map(|line| (DiffOp::Equal, *line)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < old_middle.len() || j < new_middle.len() {
+        if i < old_middle.len() && j < new_middle.len() && old_middle[i] == new_middle[j] {
+            ops.push((DiffOp::Equal, old_middle[i]));
+            i += 1;
+            j += 1;
+        } else if i < old_middle.len() && (j == new_middle.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            ops.push((DiffOp::Delete, old_middle[i]));
+            i += 1;
+        } else {
+            ops.push((DiffOp::Insert, new_middle[j]));
+            j += 1;
+        }
+    }
+    ops.extend(old_lines[old_lines.len() - suffix..].iter().map(|line| (DiffOp::Equal, *line)));
+
+    ops
+}
\ No newline at end of file
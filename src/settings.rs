@@ -0,0 +1,130 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::config::{file_patterns, regex_patterns};
+use crate::error::Result;
+use regex::Regex;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use walkdir::{DirEntry, WalkDir};
+
+/// Declarations read from a build's settings.gradle(.kts)
+#[derive(Debug, Clone, Default)]
+pub struct GradleSettings {
+    pub root_project_name: Option<String>,
+    /// `includeBuild(...)` paths as written, relative to the settings file
+    pub included_builds: Vec<String>,
+    /// `include(...)` project paths such as `:feature:login`
+    pub included_projects: Vec<String>,
+    pub version_catalogs: Vec<CatalogDeclaration>,
+    /// `RepositoriesMode` constant of `dependencyResolutionManagement`, e.g. `FAIL_ON_PROJECT_REPOS`
+    pub repositories_mode: Option<String>,
+}
+
+/// `versionCatalogs { create("androidx") { from(files("gradle/androidx.versions.toml")) } }` entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatalogDeclaration {
+    pub accessor: String,
+    /// TOML path as written, relative to the settings file
+    pub path: String,
+}
+
+pub fn parse_settings_file(file_path: &Path) -> Result<GradleSettings> {
+    let content = fs::read_to_string(file_path)?;
+    let include_build_regex = Regex::new(regex_patterns::INCLUDE_BUILD)?;
+    let root_project_name_regex = Regex::new(regex_patterns::ROOT_PROJECT_NAME)?;
+    let include_projects_regex = Regex::new(regex_patterns::INCLUDE_PROJECTS)?;
+    let quoted_string_regex = Regex::new(regex_patterns::QUOTED_STRING)?;
+    let version_catalogs_regex = Regex::new(regex_patterns::VERSION_CATALOGS_BLOCK)?;
+    let catalog_create_regex = Regex::new(regex_patterns::VERSION_CATALOG_CREATE)?;
+    let catalog_from_files_regex = Regex::new(regex_patterns::VERSION_CATALOG_FROM_FILES)?;
+    let repositories_mode_regex = Regex::new(regex_patterns::REPOSITORIES_MODE)?;
+    let mut settings = GradleSettings::default();
+    let mut catalogs_brace_count: i32 = 0;
+    let mut current_catalog: Option<String> = None;
+    
+    for line in content.lines() {
+        let brace_delta = line.matches('{').count() as i32 - line.matches('}').count() as i32;
+        
+        // versionCatalogs { <accessor> { from(files("...")) } }
+        if catalogs_brace_count > 0 {
+            if let Some(captures) = catalog_create_regex.captures(line) {
+                current_catalog = captures.get(1).or(captures.get(2)).map(|m| m.as_str().to_string());
+            }
+            if let (Some(accessor), Some(captures)) = (&current_catalog, catalog_from_files_regex.captures(line)) {
+                settings.version_catalogs.push(CatalogDeclaration {
+                    accessor: accessor.clone(),
+                    path: captures[1].to_string(),
+                });
+            }
+            catalogs_brace_count += brace_delta;
+            continue;
+        } else if version_catalogs_regex.is_match(line) {
+            catalogs_brace_count = brace_delta;
+            current_catalog = None;
+            continue;
+        }
+        
+        if let Some(captures) = include_build_regex.captures(line) {
+            settings.included_builds.push(captures[1].to_string());
+        } else if let Some(captures) = root_project_name_regex.captures(line) {
+            settings.root_project_name = Some(captures[1].to_string());
+        } else if let Some(captures) = repositories_mode_regex.captures(line) {
+            settings.repositories_mode = Some(captures[1].to_string());
+        } else if include_projects_regex.is_match(line) {
+            // include(":app", ":lib") declares several projects at once
+            settings.included_projects.extend(
+                quoted_string_regex.captures_iter(line).map(|captures| captures[1].to_string())
+            );
+        }
+    }
+    
+    Ok(settings)
+}
+
+/// Settings of the build rooted at `build_root`; empty when the build has no settings file
+pub fn load_build_settings(build_root: &Path) -> Result<GradleSettings> {
+    for filename in file_patterns::GRADLE_SETTINGS_FILES {
+        let settings_file = build_root.join(filename);
+        if settings_file.is_file() {
+            return parse_settings_file(&settings_file);
+        }
+    }
+    
+    Ok(GradleSettings::default())
+}
+
+/// Resolves a path written in a settings file against its directory, folding `..` lexically
+/// so the result lines up with paths found by scanning the parent directory
+pub fn resolve_settings_path(settings_dir: &Path, relative_path: &str) -> PathBuf {
+    let mut resolved = PathBuf::new();
+    
+    for component in settings_dir.join(relative_path).components() {
+        match component {
+            Component::ParentDir if matches!(resolved.components().next_back(), Some(Component::Normal(_))) => {
+                resolved.pop();
+            }
+            _ => resolved.push(component),
+        }
+    }
+    
+    resolved
+}
+
+/// Walks the build rooted at `build_root`, skipping the builds it includes with `includeBuild(...)`
+/// from inside its own directory since those are scanned as builds of their own
+pub fn walk_build(build_root: &Path) -> Result<impl Iterator<Item = walkdir::Result<DirEntry>>> {
+    let nested_builds: Vec<PathBuf> = load_build_settings(build_root)?
+        .included_builds
+        .iter()
+        .map(|included_build| resolve_settings_path(build_root, included_build))
+        .collect();
+    
+    Ok(WalkDir::new(build_root)
+        .into_iter()
+        .filter_entry(move |entry| entry.depth() == 0 || !nested_builds.iter().any(|nested| entry.path() == nested)))
+}
\ No newline at end of file
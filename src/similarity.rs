@@ -0,0 +1,109 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use crate::bundle_analyzer::module_dependency_sets;
+use crate::parser::DependencyLocation;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+#[derive(Debug, Serialize, Clone, Default)]
+pub struct SimilarityAnalysis {
+    pub modules: Vec<PathBuf>,
+    pub matrix: Vec<Vec<f64>>, // Jaccard similarity of the dependency sets, indexed like `modules`
+    pub min_similarity: f64,
+    pub clusters: Vec<ModuleCluster>,
+}
+
+/// Modules whose dependency sets are pairwise at least `min_similarity` similar
+#[derive(Debug, Serialize, Clone)]
+pub struct ModuleCluster {
+    pub modules: Vec<PathBuf>,
+    pub similarity: f64, // lowest similarity between two modules of the cluster
+    pub core_dependencies: Vec<String>, // group:artifact[:classifier] declared by every module
+}
+
+/// Complete-linkage hierarchical clustering of modules by the Jaccard similarity of their
+/// dependency sets, cut where the next merge would pair two modules below `min_similarity`
+pub fn analyze_module_similarity(dependencies: &[DependencyLocation], min_similarity: f64) -> SimilarityAnalysis {
+    // The module sets of the bundle analyzer, by dependency key whatever the configuration or version
+    let module_dep_sets = module_dependency_sets(dependencies, |dep| dep.dependency.key());
+    let modules: Vec<PathBuf> = module_dep_sets.keys().cloned().collect();
+    let dep_sets: Vec<&BTreeSet<String>> = module_dep_sets.values().collect();
+    
+    let matrix: Vec<Vec<f64>> = dep_sets
+        .iter()
+        .map(|a| dep_sets.iter().map(|b| jaccard_similarity(a, b)).collect())
+        .collect();
+    
+    // Every module starts alone; linkage[i][j] is the lowest similarity between members of clusters i and j
+    let mut clusters: Vec<Option<(Vec<usize>, f64)>> = (0..modules.len()).map(|i| Some((vec![i], 1.0))).collect();
+    let mut linkage = matrix.clone();
+    loop {
+        let closest = (0..clusters.len())
+            .filter(|&i| clusters[i].is_some())
+            .flat_map(|i| (i + 1..clusters.len()).filter(|&j| clusters[j].is_some()).map(move |j| (i, j)))
+            .max_by(|&(a_i, a_j), &(b_i, b_j)| linkage[a_i][a_j].partial_cmp(&linkage[b_i][b_j]).unwrap().then_with(|| (b_i, b_j).cmp(&(a_i, a_j))));
+        let Some((i, j)) = closest else { break };
+        let similarity = linkage[i][j];
+        if similarity < min_similarity {
+            break;
+        }
+        
+        let (absorbed, _) = clusters[j].take().unwrap_or_default();
+        if let Some((members, cluster_similarity)) = clusters[i].as_mut() {
+            members.extend(absorbed);
+            *cluster_similarity = similarity;
+        }
+        let merged: Vec<f64> = linkage[i].iter().zip(&linkage[j]).map(|(a, b)| a.min(*b)).collect();
+        for (k, similarity) in merged.into_iter().enumerate() {
+            linkage[i][k] = similarity;
+            linkage[k][i] = similarity;
+        }
+    }
+    
+    let mut clusters: Vec<ModuleCluster> = clusters
+        .into_iter()
+        .flatten()
+        .filter(|(members, _)| members.len() > 1)
+        .map(|(mut members, similarity)| {
+            members.sort();
+            let core_dependencies = members
+                .iter()
+                .skip(1)
+                .fold(dep_sets[members[0]].clone(), |core, &member| core.intersection(dep_sets[member]).cloned().collect());
+            ModuleCluster {
+                modules: members.iter().map(|&member| modules[member].clone()).collect(),
+                similarity,
+                core_dependencies: core_dependencies.into_iter().collect(),
+            }
+        })
+        .collect();
+    
+    // Largest groups first, then the ones sharing the most
+    clusters.sort_by(|a, b| {
+        b.modules.len()
+            .cmp(&a.modules.len())
+            .then_with(|| b.core_dependencies.len().cmp(&a.core_dependencies.len()))
+            .then_with(|| a.modules.cmp(&b.modules))
+    });
+    
+    SimilarityAnalysis {
+        modules,
+        matrix,
+        min_similarity,
+        clusters,
+    }
+}
+
+fn jaccard_similarity(a: &BTreeSet<String>, b: &BTreeSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
\ No newline at end of file
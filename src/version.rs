@@ -0,0 +1,214 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Rank of qualifiers Gradle does not order specially
+const OTHER_QUALIFIER_RANK: u8 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum VersionPart {
+    Numeric(String),
+    Qualifier(String),
+}
+
+/// Compares versions the way Gradle does: `1.10 > 1.9`, `1.0-rc1 < 1.0`, `1.0 < 1.0.1`
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let a_parts = version_parts(a);
+    let b_parts = version_parts(b);
+
+    for (a_part, b_part) in a_parts.iter().zip(&b_parts) {
+        let ordering = compare_parts(a_part, b_part);
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    // An extra numeric part makes a version higher, an extra qualifier lower (1.0 < 1.0.1, 1.0-beta < 1.0)
+    match a_parts.len().cmp(&b_parts.len()) {
+        Ordering::Equal => Ordering::Equal,
+        Ordering::Greater => match &a_parts[b_parts.len()] {
+            VersionPart::Numeric(_) => Ordering::Greater,
+            VersionPart::Qualifier(_) => Ordering::Less,
+        },
+        Ordering::Less => match &b_parts[a_parts.len()] {
+            VersionPart::Numeric(_) => Ordering::Less,
+            VersionPart::Qualifier(_) => Ordering::Greater,
+        },
+    }
+}
+
+/// Highest of the given versions, if any
+pub fn highest_version<'a>(versions: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    versions.into_iter().max_by(|a, b| compare_versions(a, b))
+}
+
+/// Lowest of the given versions, if any
+pub fn lowest_version<'a>(versions: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    versions.into_iter().min_by(|a, b| compare_versions(a, b))
+}
+
+/// Version declared most often and how often; ties go to the highest version
+pub fn most_common_version<'a>(versions: impl IntoIterator<Item = &'a str>) -> Option<(&'a str, usize)> {
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for version in versions {
+        match counts.iter_mut().find(|(counted, _)| *counted == version) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((version, 1)),
+        }
+    }
+
+    counts
+        .into_iter()
+        .max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then_with(|| compare_versions(a, b)))
+}
+
+/// Most common version when it is declared more than once and more often than any other version
+pub fn majority_version<'a>(versions: impl IntoIterator<Item = &'a str>) -> Option<(&'a str, usize)> {
+    let versions: Vec<&str> = versions.into_iter().collect();
+    let (majority, majority_count) = most_common_version(versions.iter().copied())?;
+    let runner_up_count = most_common_version(versions.iter().copied().filter(|version| *version != majority))
+        .map_or(0, |(_, count)| count);
+
+    (majority_count > 1 && majority_count > runner_up_count).then_some((majority, majority_count))
+}
+
+/// Items whose version differs from the majority version of all items, each with a message like
+/// `minSdk 21 differs from 24 used by 3 of 4 modules`
+pub fn majority_deviations<'a, T>(
+    items: &'a [T],
+    setting: impl fmt::Display,
+    version_of: impl Fn(&'a T) -> Option<&'a str>,
+) -> Vec<(&'a T, String)> {
+    let versions: Vec<&str> = items.iter().filter_map(&version_of).collect();
+    let Some((majority, majority_count)) = majority_version(versions.iter().copied()) else { return Vec::new() };
+
+    items
+        .iter()
+        .filter_map(|item| {
+            let version = version_of(item).filter(|version| *version != majority)?;
+            let message = format!(
+                "{} {} differs from {} used by {} of {} modules",
+                setting, version, majority, majority_count, versions.len()
+            );
+            Some((item, message))
+        })
+        .collect()
+}
+
+/// Position of the first part at which two versions differ (0 for the major version), if they differ at all
+pub fn first_differing_part(a: &str, b: &str) -> Option<usize> {
+    let a_parts = version_parts(a);
+    let b_parts = version_parts(b);
+
+    a_parts
+        .iter()
+        .zip(&b_parts)
+        .position(|(a_part, b_part)| compare_parts(a_part, b_part) != Ordering::Equal)
+        .or_else(|| (a_parts.len() != b_parts.len()).then_some(a_parts.len().min(b_parts.len())))
+}
+
+/// Version notations that resolve to different artifacts over time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum DynamicVersionKind {
+    /// `+` or a prefix such as `1.+`
+    Plus,
+    LatestRelease,
+    LatestIntegration,
+    /// `[1.0,2.0)`, `]1.0,)` and other Ivy/Maven ranges
+    Range,
+    /// `1.0-SNAPSHOT`
+    Snapshot,
+}
+
+impl DynamicVersionKind {
+    pub fn of(version: &str) -> Option<Self> {
+        let version = version.trim();
+        if version.ends_with('+') {
+            Some(Self::Plus)
+        } else if version == "latest.release" {
+            Some(Self::LatestRelease)
+        } else if version == "latest.integration" {
+            Some(Self::LatestIntegration)
+        } else if version.starts_with(['[', ']', '(']) && version.ends_with(['[', ']', ')']) {
+            Some(Self::Range)
+        } else if version.to_ascii_uppercase().ends_with("-SNAPSHOT") {
+            Some(Self::Snapshot)
+        } else {
+            None
+        }
+    }
+}
+
+/// Splits on `.`, `-`, `_` and `+`, and between digits and letters (`1.0rc1` -> 1, 0, rc, 1)
+fn version_parts(version: &str) -> Vec<VersionPart> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+
+    for c in version.chars() {
+        if matches!(c, '.' | '-' | '_' | '+') {
+            push_part(&mut parts, &mut current);
+            continue;
+        }
+        if current.chars().last().is_some_and(|last| last.is_ascii_digit() != c.is_ascii_digit()) {
+            push_part(&mut parts, &mut current);
+        }
+        current.push(c);
+    }
+    push_part(&mut parts, &mut current);
+
+    parts
+}
+
+fn push_part(parts: &mut Vec<VersionPart>, current: &mut String) {
+    if current.is_empty() {
+        return;
+    }
+    let part = std::mem::take(current);
+    if part.chars().all(|c| c.is_ascii_digit()) {
+        parts.push(VersionPart::Numeric(part));
+    } else {
+        parts.push(VersionPart::Qualifier(part));
+    }
+}
+
+fn compare_parts(a: &VersionPart, b: &VersionPart) -> Ordering {
+    match (a, b) {
+        (VersionPart::Numeric(a), VersionPart::Numeric(b)) => {
+            // Numeric strings of any length: fewer significant digits is smaller
+            let a = a.trim_start_matches('0');
+            let b = b.trim_start_matches('0');
+            a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+        }
+        (VersionPart::Numeric(_), VersionPart::Qualifier(_)) => Ordering::Greater,
+        (VersionPart::Qualifier(_), VersionPart::Numeric(_)) => Ordering::Less,
+        (VersionPart::Qualifier(a), VersionPart::Qualifier(b)) => {
+            let (a_rank, b_rank) = (qualifier_rank(a), qualifier_rank(b));
+            if a_rank == OTHER_QUALIFIER_RANK && b_rank == OTHER_QUALIFIER_RANK {
+                a.cmp(b)
+            } else {
+                a_rank.cmp(&b_rank)
+            }
+        }
+    }
+}
+
+/// dev < any other qualifier < rc < snapshot < final < ga < release < sp
+fn qualifier_rank(qualifier: &str) -> u8 {
+    match qualifier.to_lowercase().as_str() {
+        "dev" => 0,
+        "rc" => 2,
+        "snapshot" => 3,
+        "final" => 4,
+        "ga" => 5,
+        "release" => 6,
+        "sp" => 7,
+        _ => OTHER_QUALIFIER_RANK,
+    }
+}
\ No newline at end of file
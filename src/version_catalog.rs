@@ -5,19 +5,29 @@
  * See LICENSE file in the project root for full license information.
  */
 
-use crate::config::file_patterns;
+use crate::config::{file_patterns, regex_patterns};
 use crate::error::Result;
+use crate::settings::walk_build;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
-use walkdir::WalkDir;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct VersionCatalog {
+    /// Names the catalog is accessed by in build files, e.g. `libs`; every name settings declare it under
+    #[serde(skip)]
+    pub accessors: Vec<String>,
+    /// Directories whose build files resolve the accessor to this catalog: the build root for catalogs
+    /// in `gradle/`, the directory of each settings file declaring it, otherwise the catalog's own directory
+    #[serde(skip)]
+    pub scopes: Vec<PathBuf>,
     pub versions: Option<HashMap<String, String>>,
     pub libraries: Option<HashMap<String, LibraryDefinition>>,
     pub plugins: Option<HashMap<String, PluginDefinition>>,
+    /// Bundle alias to the library aliases it groups
+    pub bundles: Option<HashMap<String, Vec<String>>>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -45,10 +55,48 @@ pub struct PluginDefinition {
     pub version_ref: Option<String>,
 }
 
+impl LibraryDefinition {
+    /// group and artifact of the library; `module` overrides `group` and `name`
+    pub fn coordinate(&self) -> Option<(String, String)> {
+        if let Some(module) = &self.module {
+            let parts: Vec<&str> = module.split(':').collect();
+            if parts.len() == 2 {
+                Some((parts[0].to_string(), parts[1].to_string()))
+            } else {
+                None
+            }
+        } else if let (Some(g), Some(n)) = (&self.group, &self.name) {
+            Some((g.clone(), n.clone()))
+        } else {
+            None
+        }
+    }
+    
+    /// Key of the `[versions]` entry the library points at
+    pub fn version_reference(&self) -> Option<&str> {
+        version_reference(&self.version_ref, &self.version)
+    }
+}
+
+impl PluginDefinition {
+    /// Key of the `[versions]` entry the plugin points at
+    pub fn version_reference(&self) -> Option<&str> {
+        version_reference(&self.version_ref, &self.version)
+    }
+}
+
+fn version_reference<'a>(version_ref: &'a Option<String>, version: &'a Option<VersionRef>) -> Option<&'a str> {
+    match (version_ref, version) {
+        (Some(reference), _) => Some(reference),
+        (None, Some(VersionRef::Reference { r#ref })) => Some(r#ref),
+        _ => None,
+    }
+}
+
 pub fn find_version_catalog_files(root_path: &Path) -> Result<Vec<PathBuf>> {
     let mut catalog_files = Vec::new();
     
-    for entry in WalkDir::new(root_path) {
+    for entry in walk_build(root_path)? {
         let entry = entry?;
         let path = entry.path();
         
@@ -66,78 +114,126 @@ pub fn find_version_catalog_files(root_path: &Path) -> Result<Vec<PathBuf>> {
 
 pub fn parse_version_catalog(file_path: &Path) -> Result<VersionCatalog> {
     let content = fs::read_to_string(file_path)?;
-    let catalog: VersionCatalog = toml::from_str(&content)?;
+    let mut catalog: VersionCatalog = toml::from_str(&content)?;
+    catalog.accessors = vec![default_catalog_accessor(file_path)];
+    catalog.scopes = vec![default_catalog_scope(file_path)];
     Ok(catalog)
 }
 
+/// Build root of a catalog in a `gradle/` directory, otherwise the directory holding the catalog
+fn default_catalog_scope(file_path: &Path) -> PathBuf {
+    let catalog_dir = file_path.parent().unwrap_or(Path::new(""));
+    match catalog_dir.file_name().and_then(|name| name.to_str()) {
+        Some(file_patterns::CATALOG_DIR) => catalog_dir.parent().unwrap_or(catalog_dir).to_path_buf(),
+        _ => catalog_dir.to_path_buf(),
+    }
+}
+
+/// Accessor Gradle conventions give a catalog file: `androidx.versions.toml` -> `androidx`
+pub fn default_catalog_accessor(file_path: &Path) -> String {
+    file_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_suffix(file_patterns::VERSION_CATALOG_EXTENSION))
+        .filter(|name| !name.is_empty())
+        .unwrap_or(file_patterns::DEFAULT_CATALOG_ACCESSOR)
+        .to_string()
+}
+
+/// Line of each `(section, alias)` entry of a catalog, from `key = ...` lines or `[section.alias]` tables
+pub fn catalog_entry_lines(content: &str) -> Result<HashMap<(String, String), usize>> {
+    let header_regex = Regex::new(regex_patterns::TOML_TABLE_HEADER)?;
+    let key_regex = Regex::new(regex_patterns::TOML_KEY)?;
+    let mut entry_lines = HashMap::new();
+    let mut current_section: Option<String> = None;
+
+    for (line_number, line) in content.lines().enumerate() {
+        if let Some(captures) = header_regex.captures(line) {
+            let header = captures[1].replace('"', "");
+            current_section = match header.split_once('.') {
+                Some((section, alias)) => {
+                    entry_lines.entry((section.to_string(), alias.to_string())).or_insert(line_number + 1);
+                    None
+                }
+                None => Some(header),
+            };
+        } else if let (Some(section), Some(captures)) = (&current_section, key_regex.captures(line)) {
+            let alias = captures[1].trim_matches('"').to_string();
+            entry_lines.entry((section.clone(), alias)).or_insert(line_number + 1);
+        }
+    }
+
+    Ok(entry_lines)
+}
+
+/// Looks up a catalog alias the way Gradle does: `-`, `_` and `.` are interchangeable separators,
+/// e.g. libs.kotlinx.coroutines.core -> kotlinx-coroutines-core
+pub fn find_alias<'a, T>(entries: &'a HashMap<String, T>, alias: &str) -> Option<&'a T> {
+    // Try exact match first
+    if let Some(entry) = entries.get(alias) {
+        return Some(entry);
+    }
+    
+    let normalized = normalize_alias(alias);
+    entries
+        .iter()
+        .find(|(key, _)| normalize_alias(key) == normalized)
+        .map(|(_, entry)| entry)
+}
+
+pub fn normalize_alias(alias: &str) -> String {
+    alias.replace(['-', '_'], ".")
+}
+
 impl VersionCatalog {
+    pub fn is_accessed_by(&self, accessor: &str) -> bool {
+        self.accessors.iter().any(|name| name == accessor)
+    }
+    
+    /// Name to reference the catalog by in generated code
+    pub fn primary_accessor(&self) -> &str {
+        self.accessors.first().map(String::as_str).unwrap_or(file_patterns::DEFAULT_CATALOG_ACCESSOR)
+    }
+    
     pub fn resolve_library_version(&self, library_name: &str) -> Option<(String, String, String)> {
+        let (group, name, version) = self.resolve_library(library_name)?;
+        Some((group, name, version?))
+    }
+    
+    /// Coordinates of a library and its version, if it declares one that resolves
+    pub fn resolve_library(&self, library_name: &str) -> Option<(String, String, Option<String>)> {
         let libraries = self.libraries.as_ref()?;
         
-        // Try exact match first
-        let library_def = if let Some(def) = libraries.get(library_name) {
-            def
-        } else {
-            // Gradle converts dots to dashes in library names for lookup
-            // e.g. libs.kotlinx.coroutines.core -> kotlinx-coroutines-core
-            let dash_name = library_name.replace('.', "-");
-            if let Some(def) = libraries.get(&dash_name) {
-                def
-            } else {
-                return None;
-            }
-        };
+        let library_def = find_alias(libraries, library_name)?;
         
-        let (group, name) = if let Some(module) = &library_def.module {
-            // If module is specified, it overrides group and name
-            let parts: Vec<&str> = module.split(':').collect();
-            if parts.len() == 2 {
-                (parts[0].to_string(), parts[1].to_string())
-            } else {
-                return None;
-            }
-        } else if let (Some(g), Some(n)) = (&library_def.group, &library_def.name) {
-            (g.clone(), n.clone())
-        } else {
-            return None;
-        };
+        let (group, name) = library_def.coordinate()?;
         
         // Try to get version from version_ref first, then from version field
-        let version = if let Some(version_ref) = &library_def.version_ref {
-            if let Some(versions) = self.versions.as_ref() {
-                if let Some(v) = versions.get(version_ref) {
-                    v.clone()
-                } else {
-                    return None;
-                }
-            } else {
-                return None;
+        let version = match (&library_def.version_ref, &library_def.version) {
+            (Some(version_ref), _) | (None, Some(VersionRef::Reference { r#ref: version_ref })) => {
+                self.versions.as_ref().and_then(|versions| versions.get(version_ref)).cloned()
             }
-        } else if let Some(version) = &library_def.version {
-            match version {
-                VersionRef::Direct(v) => v.clone(),
-                VersionRef::Reference { r#ref } => {
-                    if let Some(versions) = self.versions.as_ref() {
-                        if let Some(v) = versions.get(r#ref) {
-                            v.clone()
-                        } else {
-                            return None;
-                        }
-                    } else {
-                        return None;
-                    }
-                }
-            }
-        } else {
-            return None;
+            (None, Some(VersionRef::Direct(version))) => Some(version.clone()),
+            (None, None) => None,
         };
         
         Some((group, name, version))
     }
     
+    /// Resolves every member library of a bundle, keeping members without a version (e.g. BOM-managed);
+    /// members missing from `[libraries]` are skipped and reported by the catalog lint
+    pub fn resolve_bundle(&self, bundle_name: &str) -> Option<Vec<(String, String, Option<String>)>> {
+        let members = find_alias(self.bundles.as_ref()?, bundle_name)?;
+        
+        Some(members
+            .iter()
+            .filter_map(|member| self.resolve_library(member))
+            .collect())
+    }
+    
     pub fn resolve_plugin_version(&self, plugin_name: &str) -> Option<(String, Option<String>)> {
         let plugins = self.plugins.as_ref()?;
-        let plugin_def = plugins.get(plugin_name)?;
+        let plugin_def = find_alias(plugins, plugin_name)?;
         
         let id = plugin_def.id.clone();
         
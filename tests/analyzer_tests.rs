@@ -25,4 +25,28 @@ dependencies {
     // Test basic analysis
     let result = perform_complete_analysis(temp_dir.path(), 2, 2);
     assert!(result.is_ok());
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_classifiers_do_not_cause_false_conflicts() {
+    let temp_dir = tempdir().unwrap();
+    
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+dependencies {
+    implementation 'com.foo:bar:1.0'
+    implementation 'com.foo:widget:2.0@aar'
+}
+"#);
+    create_test_build_gradle(temp_dir.path(), "lib", r#"
+dependencies {
+    implementation 'com.foo:bar:1.0:sources'
+    implementation 'com.foo:widget:2.0'
+}
+"#);
+    
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    
+    assert!(analysis.duplicate_analysis.version_conflicts.is_empty());
+    assert!(!analysis.duplicate_analysis.regular_duplicates.contains_key("com.foo:bar"));
+    assert_eq!(analysis.duplicate_analysis.regular_duplicates["com.foo:widget"].len(), 2);
+}
@@ -0,0 +1,209 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+mod common;
+
+use gradle_dependency_health_checker::analyzer::perform_complete_analysis;
+use gradle_dependency_health_checker::android_sdk::{AndroidFindingKind, AndroidSetting};
+use common::create_test_build_gradle;
+use tempfile::tempdir;
+use std::fs;
+
+#[test]
+fn test_android_settings_parsing() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_build_gradle(temp_dir.path(), "legacy", r#"apply plugin: 'com.android.library'
+
+android {
+    compileSdkVersion 33
+    buildToolsVersion "33.0.2"
+    namespace 'com.example.legacy'
+
+    defaultConfig {
+        minSdkVersion(21)
+        targetSdkVersion 33 // kept in line with compileSdk
+    }
+
+    productFlavors {
+        demo {
+            minSdkVersion 26
+        }
+    }
+}
+"#);
+
+    let app_dir = temp_dir.path().join("app");
+    fs::create_dir_all(&app_dir).unwrap();
+    fs::write(app_dir.join("build.gradle.kts"), r#"plugins {
+    id("com.android.application")
+}
+
+android {
+    namespace = "com.example.app"
+    compileSdk = libs.versions.compileSdk.get().toInt()
+
+    defaultConfig {
+        minSdk = 24
+    }
+}
+
+dependencies {
+    implementation(project(":legacy"))
+    implementation(projects.core.designSystem)
+}
+"#).unwrap();
+
+    // Plain JVM modules are not Android modules
+    create_test_build_gradle(temp_dir.path(), "jvm", "plugins {\n    id 'java-library'\n}\n");
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let modules = &analysis.android_analysis.modules;
+    assert_eq!(modules.len(), 2);
+
+    let app = &modules[0];
+    assert_eq!(app.project_path, ":app");
+    assert!(app.is_application);
+    assert_eq!(app.compile_sdk.as_ref().unwrap().value, "libs.versions.compileSdk.get().toInt()");
+    assert_eq!(app.min_sdk.as_ref().unwrap().value, "24");
+    assert_eq!(app.namespace.as_ref().unwrap().value, "com.example.app");
+    assert_eq!(app.project_dependencies, vec![":legacy", "core:designSystem"]);
+
+    let legacy = &modules[1];
+    assert_eq!(legacy.project_path, ":legacy");
+    assert!(!legacy.is_application);
+    assert_eq!(legacy.compile_sdk.as_ref().unwrap().value, "33");
+    assert_eq!(legacy.build_tools_version.as_ref().unwrap().value, "33.0.2");
+    assert_eq!(legacy.namespace.as_ref().unwrap().value, "com.example.legacy");
+    // Flavor overrides do not replace the defaultConfig value
+    assert_eq!(legacy.min_sdk.as_ref().unwrap().value, "21");
+    assert_eq!(legacy.min_sdk.as_ref().unwrap().line_number, 9);
+    assert_eq!(legacy.target_sdk.as_ref().unwrap().value, "33");
+}
+
+#[test]
+fn test_android_consistency_findings() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"plugins {
+    id 'com.android.application'
+}
+
+android {
+    namespace 'com.example.app'
+    compileSdk 34
+
+    defaultConfig {
+        minSdk 21
+        targetSdk 34
+    }
+}
+
+dependencies {
+    implementation project(':feature-home')
+}
+"#);
+
+    // Reached only through :feature-home, so its manifest is merged into the app as well
+    create_test_build_gradle(temp_dir.path(), "core", r#"plugins {
+    id 'com.android.library'
+}
+
+android {
+    namespace 'com.example.shared'
+    compileSdk 34
+
+    defaultConfig {
+        minSdk 26
+    }
+}
+"#);
+
+    create_test_build_gradle(temp_dir.path(), "feature-home", r#"plugins {
+    id 'com.android.library'
+}
+
+android {
+    namespace 'com.example.shared'
+    compileSdk 33
+
+    defaultConfig {
+        minSdk 21
+    }
+}
+
+dependencies {
+    implementation projects.core
+}
+"#);
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let findings = &analysis.android_analysis.findings;
+
+    let app_findings = findings.get(&temp_dir.path().join("app/build.gradle"));
+    assert!(app_findings.is_none());
+
+    let feature_findings = &findings[&temp_dir.path().join("feature-home/build.gradle")];
+    assert_eq!(feature_findings.len(), 2);
+    assert_eq!(feature_findings[0].kind, AndroidFindingKind::DuplicateNamespace);
+    assert!(feature_findings[0].message.contains(":core"));
+    assert_eq!(feature_findings[1].kind, AndroidFindingKind::InconsistentSetting);
+    assert_eq!(feature_findings[1].subject, AndroidSetting::CompileSdk);
+    assert_eq!(feature_findings[1].line_number, 7);
+    assert!(feature_findings[1].message.contains("compileSdk 33 differs from 34 used by 2 of 3 modules"));
+
+    let core_findings = &findings[&temp_dir.path().join("core/build.gradle")];
+    let kinds: Vec<&AndroidFindingKind> = core_findings.iter().map(|finding| &finding.kind).collect();
+    assert_eq!(kinds, vec![
+        &AndroidFindingKind::DuplicateNamespace,
+        &AndroidFindingKind::InconsistentSetting,
+        &AndroidFindingKind::LibraryMinSdkAboveApp,
+    ]);
+    assert!(core_findings[2].message.contains("minSdk 26 is higher than minSdk 21 of :app"));
+}
+
+#[test]
+fn test_android_consistency_requires_majority() {
+    let temp_dir = tempdir().unwrap();
+
+    // Settings in one-line blocks count like those on their own lines
+    create_test_build_gradle(temp_dir.path(), "a", r#"apply plugin: 'com.android.library'
+
+android { compileSdk 34 }
+android {
+    defaultConfig { minSdk = 21 }
+}
+"#);
+
+    create_test_build_gradle(temp_dir.path(), "b", r#"apply plugin: 'com.android.library'
+
+android {
+    compileSdk 34
+    defaultConfig { minSdk = 26 }
+}
+"#);
+
+    create_test_build_gradle(temp_dir.path(), "c", r#"apply plugin: 'com.android.library'
+
+android { compileSdk 33; defaultConfig { minSdk = 28 } }
+"#);
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let modules = &analysis.android_analysis.modules;
+    let min_sdks: Vec<&str> = modules.iter().map(|module| module.min_sdk.as_ref().unwrap().value.as_str()).collect();
+    assert_eq!(min_sdks, vec!["21", "26", "28"]);
+    assert_eq!(modules[0].min_sdk.as_ref().unwrap().line_number, 5);
+    assert_eq!(modules[0].compile_sdk.as_ref().unwrap().value, "34");
+
+    // No minSdk is shared by more than one module, so none of them is out of line
+    let findings = &analysis.android_analysis.findings;
+    assert_eq!(findings.len(), 1);
+    let c_findings = &findings[&temp_dir.path().join("c/build.gradle")];
+    assert_eq!(c_findings.len(), 1);
+    assert_eq!(c_findings[0].subject, AndroidSetting::CompileSdk);
+    assert!(c_findings[0].message.contains("compileSdk 33 differs from 34 used by 2 of 3 modules"));
+}
\ No newline at end of file
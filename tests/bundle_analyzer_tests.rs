@@ -0,0 +1,102 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use gradle_dependency_health_checker::bundle_analyzer::find_dependency_bundles;
+use gradle_dependency_health_checker::parser::{Dependency, DependencyLocation, DependencySourceType};
+use std::path::PathBuf;
+
+fn declare(module: &str, artifacts: &[&str]) -> Vec<DependencyLocation> {
+    declare_with(module, "implementation", artifacts)
+}
+
+fn declare_with(module: &str, configuration: &str, artifacts: &[&str]) -> Vec<DependencyLocation> {
+    artifacts
+        .iter()
+        .enumerate()
+        .map(|(i, artifact)| DependencyLocation {
+            dependency: Dependency {
+                group: "com.example".to_string(),
+                artifact: artifact.to_string(),
+                version: Some("1.0.0".to_string()),
+                classifier: None,
+                extension: None,
+            },
+            file_path: PathBuf::from(format!("{}/build.gradle", module)),
+            line_number: i + 1,
+            configuration: configuration.to_string(),
+            source_type: DependencySourceType::Direct,
+        })
+        .collect()
+}
+
+#[test]
+fn test_bundles_are_closed_itemsets_with_support() {
+    let mut dependencies = declare("app", &["a", "b", "c", "d"]);
+    dependencies.extend(declare("feature", &["a", "b", "c"]));
+    dependencies.extend(declare("core", &["a", "b", "x"]));
+
+    let analysis = find_dependency_bundles(&dependencies, 2, 2, false);
+
+    // {a, c} is not reported: every module declaring it also declares b
+    let mut bundles: Vec<_> = analysis
+        .recommended_bundles
+        .iter()
+        .map(|b| (b.dependencies.join(","), b.module_count))
+        .collect();
+    bundles.sort();
+    assert_eq!(bundles, vec![
+        ("com.example:a,com.example:b".to_string(), 3),
+        ("com.example:a,com.example:b,com.example:c".to_string(), 2),
+    ]);
+    assert_eq!(analysis.total_bundles_found, 2);
+}
+
+#[test]
+fn test_large_shared_dependency_sets() {
+    // Enumerating subsets of 70 shared dependencies would never finish
+    let artifacts: Vec<String> = (0..70).map(|i| format!("lib{}", i)).collect();
+    let artifacts: Vec<&str> = artifacts.iter().map(String::as_str).collect();
+    let mut dependencies = declare("app", &artifacts);
+    dependencies.extend(declare("feature", &artifacts));
+    dependencies.extend(declare("core", &artifacts[..40]));
+
+    let analysis = find_dependency_bundles(&dependencies, 2, 2, false);
+
+    assert_eq!(analysis.total_bundles_found, 2);
+    let top = &analysis.recommended_bundles[0];
+    assert_eq!(top.bundle_size, 70);
+    assert_eq!(top.module_count, 2);
+    assert_eq!(analysis.recommended_bundles[1].bundle_size, 40);
+    assert_eq!(analysis.recommended_bundles[1].module_count, 3);
+}
+
+#[test]
+fn test_bundles_are_configuration_aware() {
+    let mut dependencies = declare("app", &["a", "b"]);
+    dependencies.extend(declare_with("app", "testImplementation", &["junit"]));
+    dependencies.extend(declare("feature", &["a", "junit"]));
+    dependencies.extend(declare_with("feature", "api", &["b"]));
+    dependencies.extend(declare_with("other", "kapt", &["a", "b"]));
+    dependencies.extend(declare_with("other", "kapt", &["c"]));
+    dependencies.extend(declare_with("tool", "kapt", &["c"]));
+
+    // junit is test-only in app, and b is api in feature
+    let analysis = find_dependency_bundles(&dependencies, 2, 2, false);
+    assert_eq!(analysis.total_bundles_found, 0);
+
+    let analysis = find_dependency_bundles(&dependencies, 2, 2, true);
+    assert_eq!(analysis.total_bundles_found, 1);
+    let bundle = &analysis.recommended_bundles[0];
+    assert_eq!(bundle.dependencies, vec!["com.example:a", "com.example:b"]);
+    let members: Vec<_> = bundle.members.iter().map(|m| (m.dependency.as_str(), m.configuration.as_str())).collect();
+    assert_eq!(members, vec![("com.example:a", "implementation"), ("com.example:b", "api")]);
+
+    // Configurations come from the bundle's own modules, not from kapt declarations elsewhere
+    let mut configurations: Vec<_> = bundle.configurations.iter().cloned().collect();
+    configurations.sort();
+    assert_eq!(configurations, vec!["api", "implementation"]);
+}
\ No newline at end of file
@@ -0,0 +1,136 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+mod common;
+
+use gradle_dependency_health_checker::analyzer::perform_complete_analysis;
+use gradle_dependency_health_checker::bundle_catalog::{plan_catalog_bundles, CatalogBundlePlan};
+use gradle_dependency_health_checker::rewrite::apply_file_changes;
+use common::{create_test_build_gradle, create_test_version_catalog};
+use tempfile::tempdir;
+use std::fs;
+use std::path::Path;
+
+fn plan_bundles(root_path: &Path) -> CatalogBundlePlan {
+    let analysis = perform_complete_analysis(root_path, 2, 2).unwrap();
+    plan_catalog_bundles(root_path, &analysis.bundle_analysis.recommended_bundles).unwrap()
+}
+
+#[test]
+fn test_emit_catalog_bundle() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_version_catalog(temp_dir.path(), r#"[versions]
+core = "1.12.0"
+
+[libraries]
+core-ktx = { module = "androidx.core:core-ktx", version.ref = "core" }
+
+[plugins]
+"#);
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+dependencies {
+    implementation libs.core.ktx
+    implementation 'androidx.appcompat:appcompat:1.6.1'
+    implementation("androidx.activity:activity-ktx:1.8.0")
+    testImplementation 'junit:junit:4.13.2'
+}
+"#);
+    create_test_build_gradle(temp_dir.path(), "feature", r#"
+dependencies {
+    implementation libs.core.ktx
+    implementation("androidx.activity:activity-ktx:1.8.0") {
+        exclude group: 'androidx.lifecycle'
+    }
+    implementation 'androidx.appcompat:appcompat:1.6.1'
+}
+"#);
+    create_test_build_gradle(temp_dir.path(), "core", r#"
+dependencies {
+    implementation libs.core.ktx
+    implementation 'androidx.appcompat:appcompat:1.6.1'
+    implementation 'androidx.activity:activity-ktx:1.8.0'
+}
+"#);
+
+    let plan = plan_bundles(temp_dir.path());
+    assert_eq!(plan.bundles.len(), 1);
+    assert_eq!(plan.bundles[0].reference, "libs.bundles.androidx");
+    assert_eq!(plan.bundles[0].rewritten_modules.len(), 2);
+    // A declaration with a configuration block keeps its own line
+    assert_eq!(plan.skipped_modules.len(), 1);
+    assert!(plan.skipped_modules[0].module.ends_with("feature/build.gradle"));
+    assert_eq!(plan.bundle_entries, vec!["androidx = [\"activity-ktx\", \"appcompat\", \"core-ktx\"]".to_string()]);
+    // Existing catalog libraries keep their alias, missing ones are created with a [versions] entry
+    assert_eq!(plan.library_entries.len(), 2);
+    assert_eq!(plan.version_entries, vec!["activity-ktx = \"1.8.0\"".to_string(), "appcompat = \"1.6.1\"".to_string()]);
+
+    apply_file_changes(&plan.changes).unwrap();
+
+    let catalog = fs::read_to_string(temp_dir.path().join("gradle/libs.versions.toml")).unwrap();
+    assert!(catalog.contains("core = \"1.12.0\"\nactivity-ktx = \"1.8.0\"\nappcompat = \"1.6.1\"\n\n[libraries]"));
+    assert!(catalog.contains("appcompat = { module = \"androidx.appcompat:appcompat\", version.ref = \"appcompat\" }\n\n[bundles]\nandroidx = [\"activity-ktx\", \"appcompat\", \"core-ktx\"]\n\n[plugins]"));
+
+    let app = fs::read_to_string(temp_dir.path().join("app/build.gradle")).unwrap();
+    assert_eq!(app, "\ndependencies {\n    implementation libs.bundles.androidx\n    testImplementation 'junit:junit:4.13.2'\n}\n");
+}
+
+#[test]
+fn test_emit_catalog_bundle_skips_conflicting_versions() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+dependencies {
+    implementation 'io.ktor:ktor-client-core:2.3.5'
+    implementation 'io.ktor:ktor-client-cio:2.3.5'
+}
+"#);
+    create_test_build_gradle(temp_dir.path(), "lib", r#"
+dependencies {
+    implementation 'io.ktor:ktor-client-core:2.3.4'
+    implementation 'io.ktor:ktor-client-cio:2.3.5'
+}
+"#);
+
+    let plan = plan_bundles(temp_dir.path());
+    assert!(plan.bundles.is_empty());
+    assert!(plan.changes.is_empty());
+    assert_eq!(plan.skipped_bundles.len(), 1);
+    assert!(plan.skipped_bundles[0].reason.contains("different versions (2.3.4, 2.3.5)"));
+}
+
+#[test]
+fn test_emit_catalog_bundle_per_configuration() {
+    let temp_dir = tempdir().unwrap();
+
+    let dependencies = r#"
+dependencies {
+    implementation 'com.squareup.retrofit2:retrofit:2.9.0'
+    implementation 'com.google.code.gson:gson:2.10.1'
+    testImplementation 'junit:junit:4.13.2'
+    testImplementation 'org.mockito:mockito-core:5.6.0'
+}
+"#;
+    create_test_build_gradle(temp_dir.path(), "app", dependencies);
+    create_test_build_gradle(temp_dir.path(), "lib", dependencies);
+
+    // One recommendation spanning two configurations becomes one catalog bundle per configuration
+    let plan = plan_bundles(temp_dir.path());
+    assert!(plan.skipped_bundles.is_empty());
+    assert_eq!(plan.bundle_entries.len(), 2);
+    let references: Vec<&str> = plan.bundles.iter().map(|bundle| bundle.reference.as_str()).collect();
+    assert!(plan.bundles.iter().all(|bundle| bundle.rewritten_modules.len() == 2));
+
+    apply_file_changes(&plan.changes).unwrap();
+
+    let app = fs::read_to_string(temp_dir.path().join("app/build.gradle")).unwrap();
+    let implementation = references.iter().find(|reference| app.contains(&format!("    implementation {}\n", reference)));
+    let test_implementation = references.iter().find(|reference| app.contains(&format!("    testImplementation {}\n", reference)));
+    assert!(implementation.is_some() && test_implementation.is_some() && implementation != test_implementation);
+    assert_eq!(app.lines().count(), 5);
+}
\ No newline at end of file
@@ -0,0 +1,131 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+mod common;
+
+use gradle_dependency_health_checker::analyzer::{perform_complete_analysis, perform_complete_analysis_with_config, AnalysisParameters};
+use gradle_dependency_health_checker::config::Config;
+use gradle_dependency_health_checker::bundle_conventions::plan_convention_plugins;
+use common::{create_test_build_gradle, create_test_version_catalog};
+use tempfile::tempdir;
+use std::collections::HashSet;
+use std::fs;
+
+#[test]
+fn test_generate_convention_plugin() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_version_catalog(temp_dir.path(), r#"[libraries]
+retrofit = { module = "com.squareup.retrofit2:retrofit", version = "2.9.0" }
+"#);
+    for module in ["app", "feature"] {
+        create_test_build_gradle(temp_dir.path(), module, r#"
+dependencies {
+    implementation libs.retrofit
+    implementation 'com.squareup.okhttp3:okhttp:4.12.0'
+    testImplementation 'junit:junit:4.13.2'
+}
+"#);
+    }
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let plan = plan_convention_plugins(temp_dir.path(), &analysis.bundle_analysis.recommended_bundles).unwrap();
+
+    assert_eq!(plan.plugins.len(), 1);
+    let plugin = &plan.plugins[0];
+    assert_eq!(plugin.plugin_id, "networking-dependencies");
+    assert!(plugin.path.ends_with("build-logic/convention/src/main/kotlin/networking-dependencies.gradle.kts"));
+    assert_eq!(plugin.modules.len(), 2);
+    assert_eq!(plugin.modules[0].removable_lines, vec![3, 4, 5]);
+    assert!(plan.include_build_missing);
+
+    // Catalog members are looked up by alias, hardcoded ones keep their coordinates
+    let content = &plan.changes[0].updated;
+    assert!(content.contains("val libs = the<VersionCatalogsExtension>().named(\"libs\")"));
+    assert!(content.contains("    add(\"implementation\", libs.findLibrary(\"retrofit\").get())\n"));
+    assert!(content.contains("    add(\"implementation\", \"com.squareup.okhttp3:okhttp:4.12.0\")\n"));
+    assert!(content.contains("    add(\"testImplementation\", \"junit:junit:4.13.2\")\n"));
+
+    // The build-logic build is scaffolded with the catalog it looks members up in
+    let settings = plan.changes.iter().find(|change| change.path.ends_with("build-logic/settings.gradle.kts")).unwrap();
+    assert!(settings.updated.contains("from(files(\"../gradle/libs.versions.toml\"))"));
+    assert!(plan.changes.iter().any(|change| change.path.ends_with("build-logic/convention/build.gradle.kts")));
+}
+
+#[test]
+fn test_convention_plugin_merged_configurations() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+dependencies {
+    implementation 'com.google.code.gson:gson:2.10.1'
+    implementation 'com.squareup.moshi:moshi:1.15.0'
+}
+"#);
+    create_test_build_gradle(temp_dir.path(), "lib", r#"
+dependencies {
+    api 'com.google.code.gson:gson:2.10.1'
+    implementation 'com.squareup.moshi:moshi:1.15.0'
+}
+"#);
+    fs::create_dir_all(temp_dir.path().join("build-logic/convention")).unwrap();
+    fs::write(temp_dir.path().join("build-logic/convention/build.gradle.kts"), "plugins {\n    `kotlin-dsl`\n}\n").unwrap();
+
+    // api and implementation declarations of gson are only shared once configurations are merged
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    assert!(analysis.bundle_analysis.recommended_bundles.is_empty());
+
+    let analysis = perform_complete_analysis_with_config(temp_dir.path(), &AnalysisParameters { merge_bundle_configurations: true, ..AnalysisParameters::default() }, &Config::default()).unwrap();
+    let plan = plan_convention_plugins(temp_dir.path(), &analysis.bundle_analysis.recommended_bundles).unwrap();
+
+    assert_eq!(plan.plugins.len(), 1);
+    assert!(plan.plugins[0].modules.iter().all(|module| module.removable_lines == vec![3, 4]));
+    let content = &plan.changes[0].updated;
+    assert!(content.contains("    add(\"api\", \"com.google.code.gson:gson:2.10.1\")\n"));
+    assert!(content.contains("    add(\"implementation\", \"com.squareup.moshi:moshi:1.15.0\")\n"));
+}
+
+#[test]
+fn test_convention_plugins_do_not_overlap() {
+    let temp_dir = tempdir().unwrap();
+
+    for (module, extra) in [("app", "com.google.code.gson:gson:2.10.1"), ("feature", "com.google.code.gson:gson:2.10.1"), ("lib", "io.coil-kt:coil:2.5.0"), ("core", "io.coil-kt:coil:2.5.0")] {
+        create_test_build_gradle(temp_dir.path(), module, &format!(r#"
+dependencies {{
+    implementation 'com.squareup.retrofit2:retrofit:2.9.0'
+    implementation 'com.squareup.okhttp3:okhttp:4.12.0'
+    implementation '{}'
+}}
+"#, extra));
+    }
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let bundles = &analysis.bundle_analysis.recommended_bundles;
+    // retrofit and okhttp are recommended both on their own and together with gson or coil
+    assert!(bundles.len() > 2);
+    let plan = plan_convention_plugins(temp_dir.path(), bundles).unwrap();
+
+    // Every module gets each dependency from one plugin and each line is removed once
+    let mut provided = HashSet::new();
+    let mut removed = HashSet::new();
+    for plugin in &plan.plugins {
+        for module in &plugin.modules {
+            for dependency in &plugin.dependencies {
+                assert!(provided.insert((module.build_file.clone(), dependency.clone())), "{} provided twice to {:?}", dependency, module.build_file);
+            }
+            for line in &module.removable_lines {
+                assert!(removed.insert((module.build_file.clone(), *line)));
+            }
+        }
+    }
+    // The bundle with coil finds lib and core already served by the retrofit and okhttp plugin
+    assert_eq!(plan.plugins.len(), 2);
+    assert_eq!(provided.len(), 10);
+    assert_eq!(plan.skipped_bundles.len(), 1);
+    assert!(plan.skipped_bundles[0].dependencies.contains(&"io.coil-kt:coil".to_string()));
+    assert!(plan.skipped_bundles[0].reason.contains("already get members from"));
+}
\ No newline at end of file
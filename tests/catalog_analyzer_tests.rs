@@ -0,0 +1,270 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+mod common;
+
+use gradle_dependency_health_checker::analyzer::perform_complete_analysis;
+use gradle_dependency_health_checker::catalog_analyzer::CatalogFindingKind;
+use gradle_dependency_health_checker::findings::Finding;
+use common::{create_test_build_gradle, create_test_version_catalog};
+use tempfile::tempdir;
+use std::fs;
+
+fn findings_of(findings: &[Finding<CatalogFindingKind, String>], kind: CatalogFindingKind) -> Vec<&Finding<CatalogFindingKind, String>> {
+    findings.iter().filter(|finding| finding.kind == kind).collect()
+}
+
+#[test]
+fn test_catalog_lint_findings() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_version_catalog(temp_dir.path(), r#"[versions]
+okhttp = "4.12.0"
+retrofit = "2.9.0"
+orphan = "1.0.0"
+
+[libraries]
+okhttp = { module = "com.squareup.okhttp3:okhttp", version.ref = "okhttp" }
+okhttp-client = { group = "com.squareup.okhttp3", name = "okhttp", version.ref = "okhttp" }
+retrofit = { module = "com.squareup.retrofit2:retrofit", version.ref = "retrofit" }
+gson = { module = "com.google.code.gson:gson", version.ref = "gson" }
+unused-lib = { module = "org.example:unused", version = "1.0" }
+
+[bundles]
+network = ["retrofit", "missing-lib"]
+unused-bundle = ["gson"]
+
+[plugins]
+kotlin-android = { id = "org.jetbrains.kotlin.android", version = "1.9.20" }
+ksp = { id = "com.google.devtools.ksp", version.ref = "ksp" }
+"#);
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+plugins {
+    alias(libs.plugins.kotlin.android)
+}
+
+dependencies {
+    implementation libs.okhttp
+    implementation libs.okhttp.client
+    implementation libs.gson
+    implementation libs.bundles.network
+}
+"#);
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let catalog_path = temp_dir.path().join("gradle/libs.versions.toml");
+    let findings = &analysis.catalog_analysis.findings[&catalog_path];
+
+    // Libraries referenced directly or through a used bundle are not reported
+    let unused_libraries = findings_of(findings, CatalogFindingKind::UnusedLibrary);
+    assert_eq!(unused_libraries.len(), 1);
+    assert_eq!(unused_libraries[0].subject, "unused-lib");
+    assert_eq!(unused_libraries[0].line_number, 11);
+
+    let unused_bundles = findings_of(findings, CatalogFindingKind::UnusedBundle);
+    assert_eq!(unused_bundles.len(), 1);
+    assert_eq!(unused_bundles[0].subject, "unused-bundle");
+    assert_eq!(unused_bundles[0].line_number, 15);
+
+    let unused_plugins = findings_of(findings, CatalogFindingKind::UnusedPlugin);
+    assert_eq!(unused_plugins.len(), 1);
+    assert_eq!(unused_plugins[0].subject, "ksp");
+
+    let unused_versions = findings_of(findings, CatalogFindingKind::UnusedVersion);
+    assert_eq!(unused_versions.len(), 1);
+    assert_eq!(unused_versions[0].subject, "orphan");
+    assert_eq!(unused_versions[0].line_number, 4);
+
+    let undefined_versions = findings_of(findings, CatalogFindingKind::UndefinedVersionReference);
+    assert_eq!(undefined_versions.len(), 2);
+    assert!(undefined_versions.iter().any(|finding| finding.subject == "gson" && finding.line_number == 10));
+    assert!(undefined_versions.iter().any(|finding| finding.subject == "ksp" && finding.line_number == 19));
+
+    let undefined_members = findings_of(findings, CatalogFindingKind::UndefinedBundleMember);
+    assert_eq!(undefined_members.len(), 1);
+    assert_eq!(undefined_members[0].subject, "network");
+    assert!(undefined_members[0].message.contains("missing-lib"));
+
+    let duplicates = findings_of(findings, CatalogFindingKind::DuplicateCoordinate);
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].subject, "okhttp-client");
+    assert_eq!(duplicates[0].line_number, 8);
+    assert!(duplicates[0].message.contains("`okhttp`"));
+
+    // Findings are ordered by their position in the catalog
+    assert!(findings.windows(2).all(|pair| pair[0].line_number <= pair[1].line_number));
+}
+
+#[test]
+fn test_catalog_usage_from_convention_plugins_and_version_accessors() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_version_catalog(temp_dir.path(), r#"[versions]
+compose-compiler = "1.5.4"
+
+[libraries]
+androidx-core-ktx = { module = "androidx.core:core-ktx", version = "1.12.0" }
+timber = { module = "com.jakewharton.timber:timber", version = "5.0.1" }
+"#);
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+android {
+    composeOptions {
+        kotlinCompilerExtensionVersion = libs.versions.compose.compiler.get()
+    }
+}
+
+dependencies {
+    implementation(libs.androidx.core.ktx)
+}
+"#);
+
+    let convention_dir = temp_dir.path().join("build-logic/convention/src/main/kotlin");
+    fs::create_dir_all(&convention_dir).unwrap();
+    fs::write(convention_dir.join("AndroidConventionPlugin.kt"), r#"
+class AndroidConventionPlugin : Plugin<Project> {
+    override fun apply(target: Project) {
+        val catalog = target.extensions.getByType<VersionCatalogsExtension>().named("libs")
+        target.dependencies.add("implementation", catalog.findLibrary("timber").get())
+    }
+}
+"#).unwrap();
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+
+    assert!(analysis.catalog_analysis.findings.is_empty());
+}
+
+#[test]
+fn test_hardcoded_dependencies_already_in_catalog() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_version_catalog(temp_dir.path(), r#"[versions]
+ktor = "2.3.5"
+
+[libraries]
+ktor-client-core = { module = "io.ktor:ktor-client-core", version.ref = "ktor" }
+okhttp = { module = "com.squareup.okhttp3:okhttp", version = "4.12.0" }
+"#);
+
+    create_test_build_gradle(temp_dir.path(), "network", r#"
+dependencies {
+    implementation libs.ktor.client.core
+    implementation libs.okhttp
+}
+"#);
+
+    create_test_build_gradle(temp_dir.path(), "feature", r#"
+dependencies {
+    implementation 'io.ktor:ktor-client-core:2.3.5'
+    implementation 'com.squareup.okhttp3:okhttp:4.11.0'
+    implementation 'com.google.code.gson:gson:2.10.1'
+}
+"#);
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let hardcoded = &analysis.catalog_analysis.hardcoded_dependencies;
+
+    // Catalog references themselves and coordinates missing from the catalog are not reported
+    assert_eq!(hardcoded.len(), 2);
+
+    let ktor = &hardcoded["io.ktor:ktor-client-core"];
+    assert_eq!(ktor.len(), 1);
+    assert_eq!(ktor[0].catalog_reference, "libs.ktor.client.core");
+    assert_eq!(ktor[0].catalog_version.as_deref(), Some("2.3.5"));
+    assert!(!ktor[0].version_differs);
+    assert_eq!(ktor[0].location.line_number, 3);
+
+    let okhttp = &hardcoded["com.squareup.okhttp3:okhttp"];
+    assert_eq!(okhttp[0].catalog_reference, "libs.okhttp");
+    assert!(okhttp[0].version_differs);
+}
+#[test]
+fn test_catalog_shared_with_included_build_is_linted_once() {
+    let temp_dir = tempdir().unwrap();
+    let logic_root = temp_dir.path().join("build-logic");
+
+    fs::write(temp_dir.path().join("settings.gradle.kts"), r#"
+rootProject.name = "my-app"
+includeBuild("build-logic")
+include(":app")
+"#).unwrap();
+    create_test_version_catalog(temp_dir.path(), r#"[versions]
+kotlin = "2.0.0"
+
+[libraries]
+okhttp = { module = "com.squareup.okhttp3:okhttp", version = "4.12.0" }
+kgp = { module = "org.jetbrains.kotlin:kotlin-gradle-plugin", version.ref = "kotlin" }
+unused = { module = "com.example:unused", version = "1.0.0" }
+"#);
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+dependencies {
+    implementation libs.okhttp
+}
+"#);
+
+    // build-logic reads the root catalog and references entries the root build does not
+    fs::create_dir_all(&logic_root).unwrap();
+    fs::write(logic_root.join("settings.gradle.kts"), r#"
+dependencyResolutionManagement {
+    versionCatalogs {
+        create("libs") {
+            from(files("../gradle/libs.versions.toml"))
+        }
+    }
+}
+"#).unwrap();
+    fs::write(logic_root.join("build.gradle.kts"), r#"
+dependencies {
+    implementation(libs.kgp)
+}
+"#).unwrap();
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let findings = &analysis.catalog_analysis.findings;
+    assert_eq!(findings.len(), 1);
+
+    let catalog_findings = &findings[&temp_dir.path().join("gradle/libs.versions.toml")];
+    assert_eq!(catalog_findings.len(), 1);
+    assert_eq!(catalog_findings[0].kind, CatalogFindingKind::UnusedLibrary);
+    assert_eq!(catalog_findings[0].subject, "unused");
+}
+
+#[test]
+fn test_catalog_declared_under_two_accessors() {
+    let temp_dir = tempdir().unwrap();
+
+    fs::write(temp_dir.path().join("settings.gradle"), r#"
+dependencyResolutionManagement {
+    versionCatalogs {
+        create("deps") {
+            from(files("gradle/shared.versions.toml"))
+        }
+        create("tools") {
+            from(files("gradle/shared.versions.toml"))
+        }
+    }
+}
+"#).unwrap();
+    fs::create_dir_all(temp_dir.path().join("gradle")).unwrap();
+    fs::write(temp_dir.path().join("gradle/shared.versions.toml"), r#"[libraries]
+okhttp = { module = "com.squareup.okhttp3:okhttp", version = "4.12.0" }
+detekt = { module = "io.gitlab.arturbosch.detekt:detekt-cli", version = "1.23.6" }
+"#).unwrap();
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+dependencies {
+    implementation deps.okhttp
+    implementation tools.detekt
+}
+"#);
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+
+    // Neither accessor replaces the other
+    assert!(analysis.catalog_analysis.findings.is_empty());
+}
\ No newline at end of file
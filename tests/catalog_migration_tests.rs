@@ -0,0 +1,132 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+mod common;
+
+use gradle_dependency_health_checker::catalog_migration::{
+    AliasAllocator, insert_toml_entries, library_alias_candidates, plan_catalog_migration, sanitize_alias,
+};
+use gradle_dependency_health_checker::rewrite::{apply_file_changes, unified_diff};
+use gradle_dependency_health_checker::version_catalog::parse_version_catalog;
+use common::{create_test_build_gradle, create_test_version_catalog};
+use tempfile::tempdir;
+use std::fs;
+
+#[test]
+fn test_alias_generation() {
+    assert_eq!(sanitize_alias("Kotlinx.Coroutines_Core"), "kotlinx-coroutines-core");
+    assert_eq!(sanitize_alias("3d-engine"), "lib-3d-engine");
+    assert_eq!(library_alias_candidates("com.squareup.okhttp3", "okhttp"), vec![
+        "okhttp".to_string(),
+        "okhttp3-okhttp".to_string(),
+        "com-squareup-okhttp3-okhttp".to_string(),
+    ]);
+
+    let existing = vec!["okhttp-bom".to_string()];
+    let mut allocator = AliasAllocator::new(&existing);
+    // `okhttp` would nest under the existing `okhttp-bom` accessor
+    assert_eq!(allocator.allocate(&library_alias_candidates("com.squareup.okhttp3", "okhttp")), "okhttp3-okhttp");
+    assert_eq!(allocator.allocate(&["core".to_string()]), "core");
+    assert_eq!(allocator.allocate(&["core".to_string()]), "core2");
+    assert!(!allocator.is_available("plugins-core"));
+}
+
+#[test]
+fn test_insert_toml_entries() {
+    let content = "[libraries]\nokhttp = { module = \"com.squareup.okhttp3:okhttp\", version = \"4.12.0\" }\n\n[plugins]\n";
+
+    let with_versions = insert_toml_entries(content, "versions", &["gson = \"2.10.1\"".to_string()], &["libraries"]).unwrap();
+    assert!(with_versions.starts_with("[versions]\ngson = \"2.10.1\"\n\n[libraries]\n"));
+
+    let with_library = insert_toml_entries(&with_versions, "libraries", &["gson = { module = \"com.google.code.gson:gson\" }".to_string()], &["plugins"]).unwrap();
+    assert!(with_library.contains("version = \"4.12.0\" }\ngson = { module = \"com.google.code.gson:gson\" }\n\n[plugins]"));
+
+    let created = insert_toml_entries("", "libraries", &["gson = { module = \"com.google.code.gson:gson\" }".to_string()], &[]).unwrap();
+    assert_eq!(created, "[libraries]\ngson = { module = \"com.google.code.gson:gson\" }\n");
+}
+
+#[test]
+fn test_unified_diff() {
+    let original = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+    let updated = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\n";
+
+    let diff = unified_diff(original, updated, "a/file", "b/file");
+    assert_eq!(diff, "--- a/file\n+++ b/file\n@@ -1,5 +1,5 @@\n a\n-b\n+B\n c\n d\n e\n@@ -8,3 +8,4 @@\n h\n i\n j\n+k\n");
+    assert!(unified_diff(original, original, "a/file", "b/file").is_empty());
+}
+
+#[test]
+fn test_migrate_to_catalog() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_version_catalog(temp_dir.path(), r#"[libraries]
+okhttp = { module = "com.squareup.okhttp3:okhttp", version = "4.12.0" }
+"#);
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+dependencies {
+    implementation 'io.ktor:ktor-client-core:2.3.5'
+    implementation 'io.ktor:ktor-client-cio:2.3.5'
+    implementation 'com.squareup.okhttp3:okhttp:4.12.0'
+    implementation group: 'com.google.code.gson', name: 'gson', version: '2.10.1'
+    testImplementation 'junit:junit:4.13.2'
+    implementation "androidx.core:core-ktx:$coreVersion"
+}
+"#);
+
+    fs::create_dir_all(temp_dir.path().join("lib")).unwrap();
+    fs::write(temp_dir.path().join("lib/build.gradle.kts"), r#"
+dependencies {
+    testImplementation("junit:junit:4.12")
+    testFixturesImplementation(testFixtures("com.google.code.gson:gson:2.10.1"))
+}
+"#).unwrap();
+
+    // buildSrc cannot use the libs accessor of the main build
+    create_test_build_gradle(temp_dir.path(), "buildSrc", r#"
+dependencies {
+    implementation 'com.android.tools.build:gradle:8.1.0'
+}
+"#);
+
+    let migration = plan_catalog_migration(temp_dir.path()).unwrap();
+    let catalog_path = temp_dir.path().join("gradle/libs.versions.toml");
+
+    assert_eq!(migration.catalog_path, catalog_path);
+    assert_eq!(migration.migrated.len(), 5);
+    assert!(migration.migrated.iter().any(|migrated| migrated.catalog_reference == "libs.okhttp"));
+    assert!(migration.migrated.iter().all(|migrated| !migrated.location.file_path.starts_with(temp_dir.path().join("buildSrc"))));
+
+    // Conflicting versions and interpolated versions stay as they are
+    assert_eq!(migration.skipped.len(), 3);
+    assert_eq!(migration.skipped.iter().filter(|skipped| skipped.location.dependency.artifact == "junit").count(), 2);
+
+    // Planning does not touch the files
+    assert!(!fs::read_to_string(&catalog_path).unwrap().contains("ktor"));
+
+    apply_file_changes(&migration.changes).unwrap();
+
+    let catalog = parse_version_catalog(&catalog_path).unwrap();
+    assert_eq!(catalog.resolve_library_version("ktor-client-core"), Some(("io.ktor".to_string(), "ktor-client-core".to_string(), "2.3.5".to_string())));
+    // Artifacts of one group at one version share a version key
+    assert_eq!(catalog.versions.as_ref().unwrap()["ktor"], "2.3.5");
+    assert_eq!(catalog.resolve_library_version("gson").unwrap().2, "2.10.1");
+
+    let app = fs::read_to_string(temp_dir.path().join("app/build.gradle")).unwrap();
+    assert!(app.contains("    implementation libs.ktor.client.core\n"));
+    assert!(app.contains("    implementation libs.okhttp\n"));
+    assert!(app.contains("    implementation libs.gson\n"));
+    assert!(app.contains("testImplementation 'junit:junit:4.13.2'"));
+
+    let lib = fs::read_to_string(temp_dir.path().join("lib/build.gradle.kts")).unwrap();
+    assert!(lib.contains("testFixturesImplementation(testFixtures(libs.gson))"));
+
+    // A second run has nothing left to migrate
+    let rerun = plan_catalog_migration(temp_dir.path()).unwrap();
+    assert!(rerun.migrated.is_empty());
+    assert!(rerun.changes.is_empty());
+}
\ No newline at end of file
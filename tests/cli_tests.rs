@@ -5,7 +5,8 @@
  * See LICENSE file in the project root for full license information.
  */
 
-use gradle_dependency_health_checker::cli::{Args, Commands, validate_args};
+use gradle_dependency_health_checker::cli::{Args, BundleEmitFormat, Commands, FixTarget, validate_args};
+use gradle_dependency_health_checker::conflict_fix::VersionStrategy;
 use gradle_dependency_health_checker::config::Config;
 use clap::Parser;
 
@@ -16,6 +17,7 @@ fn test_valid_all_command() {
         path: std::path::PathBuf::from("."),
         output: None,
         silent: false,
+        matrix: None,
         command: Some(Commands::All {
             min_version_conflicts: Some(2),
             min_duplicate_dependencies: Some(3),
@@ -23,6 +25,7 @@ fn test_valid_all_command() {
             min_bundle_size: Some(2),
             min_bundle_modules: Some(2),
             max_bundle_recommendations: Some(5),
+            merge_configurations: false,
         }),
     };
     
@@ -36,6 +39,7 @@ fn test_invalid_version_conflicts_threshold() {
         path: std::path::PathBuf::from("."),
         output: None,
         silent: false,
+        matrix: None,
         command: Some(Commands::Conflicts {
             min_version_conflicts: Some(1),
         }),
@@ -53,6 +57,7 @@ fn test_invalid_duplicate_dependencies_threshold() {
         path: std::path::PathBuf::from("."),
         output: None,
         silent: false,
+        matrix: None,
         command: Some(Commands::Dependencies {
             min_duplicate_dependencies: Some(0),
         }),
@@ -70,6 +75,7 @@ fn test_invalid_duplicate_plugins_threshold() {
         path: std::path::PathBuf::from("."),
         output: None,
         silent: false,
+        matrix: None,
         command: Some(Commands::Plugins {
             min_duplicate_plugins: Some(1),
         }),
@@ -82,7 +88,7 @@ fn test_invalid_duplicate_plugins_threshold() {
 
 #[test]
 fn test_parse_args_defaults() {
-    let args = Args::try_parse_from(&["program"]).unwrap();
+    let args = Args::try_parse_from(["program"]).unwrap();
     
     assert_eq!(args.path, std::path::PathBuf::from("."));
     assert!(args.command.is_none());
@@ -92,7 +98,7 @@ fn test_parse_args_defaults() {
 
 #[test]
 fn test_parse_subcommand_conflicts() {
-    let args = Args::try_parse_from(&[
+    let args = Args::try_parse_from([
         "program",
         "--path", "/custom/path",
         "conflicts",
@@ -110,7 +116,7 @@ fn test_parse_subcommand_conflicts() {
 
 #[test]
 fn test_parse_subcommand_dependencies() {
-    let args = Args::try_parse_from(&[
+    let args = Args::try_parse_from([
         "program",
         "dependencies",
         "--min-duplicate-dependencies", "3"
@@ -126,7 +132,7 @@ fn test_parse_subcommand_dependencies() {
 
 #[test]
 fn test_parse_subcommand_plugins() {
-    let args = Args::try_parse_from(&[
+    let args = Args::try_parse_from([
         "program",
         "plugins",
         "--min-duplicate-plugins", "4"
@@ -140,9 +146,109 @@ fn test_parse_subcommand_plugins() {
     }
 }
 
+#[test]
+fn test_parse_subcommand_catalog() {
+    let config = Config::default();
+    let args = Args::try_parse_from([
+        "program",
+        "catalog"
+    ]).unwrap();
+    
+    assert!(matches!(args.command, Some(Commands::Catalog)));
+    assert!(validate_args(&args, &config).is_ok());
+    
+    let options = args.get_analysis_options(&config);
+    assert!(options.show_catalog_findings);
+    assert!(!options.show_cross_build_conflicts);
+    assert_eq!(options.max_bundle_recommendations, 0);
+}
+
+#[test]
+fn test_parse_subcommand_similarity() {
+    let config = Config::default();
+    let args = Args::try_parse_from(["program", "similarity"]).unwrap();
+    
+    assert!(validate_args(&args, &config).is_ok());
+    let options = args.get_analysis_options(&config);
+    assert_eq!(options.parameters.min_module_similarity, Some(0.8));
+    assert_eq!(options.max_bundle_recommendations, 0);
+    
+    let args = Args::try_parse_from(["program", "similarity", "--min-similarity", "0.65"]).unwrap();
+    assert_eq!(args.get_analysis_options(&config).parameters.min_module_similarity, Some(0.65));
+    
+    let args = Args::try_parse_from(["program", "similarity", "--min-similarity", "1.5"]).unwrap();
+    assert!(validate_args(&args, &config).is_err());
+}
+
+#[test]
+fn test_parse_subcommand_bundles_emit() {
+    let args = Args::try_parse_from([
+        "program",
+        "bundles",
+        "--emit", "catalog",
+        "--dry-run"
+    ]).unwrap();
+    
+    match args.command {
+        Some(Commands::Bundles { emit, apply, dry_run, .. }) => {
+            assert_eq!(emit, Some(BundleEmitFormat::Catalog));
+            assert!(!apply);
+            assert!(dry_run);
+        }
+        _ => panic!("Expected Bundles command"),
+    }
+    
+    let args = Args::try_parse_from(["program", "bundles", "--merge-configurations"]).unwrap();
+    assert!(args.get_analysis_options(&Config::default()).parameters.merge_bundle_configurations);
+    
+    let args = Args::try_parse_from(["program", "bundles", "--emit", "convention-plugin", "--apply"]).unwrap();
+    assert!(matches!(args.command, Some(Commands::Bundles { emit: Some(BundleEmitFormat::ConventionPlugin), apply: true, .. })));
+    
+    // Writing files needs to know what to emit, and cannot be combined with a dry run
+    assert!(Args::try_parse_from(["program", "bundles", "--apply"]).is_err());
+    assert!(Args::try_parse_from(["program", "bundles", "--emit", "catalog", "--apply", "--dry-run"]).is_err());
+}
+
+#[test]
+fn test_parse_subcommand_fix_conflicts() {
+    let config = Config::default();
+    let args = Args::try_parse_from([
+        "program",
+        "fix", "conflicts",
+        "--strategy", "lowest",
+        "--dry-run"
+    ]).unwrap();
+    
+    match &args.command {
+        Some(Commands::Fix { target: FixTarget::Conflicts { strategy, dependency, dry_run } }) => {
+            assert_eq!(*strategy, VersionStrategy::Lowest);
+            assert!(dependency.is_none());
+            assert!(*dry_run);
+        }
+        _ => panic!("Expected Fix Conflicts command"),
+    }
+    assert!(validate_args(&args, &config).is_ok());
+    
+    // An explicit version only makes sense for a single dependency
+    let args = Args::try_parse_from([
+        "program",
+        "fix", "conflicts",
+        "--strategy", "4.12.0"
+    ]).unwrap();
+    assert!(validate_args(&args, &config).is_err());
+    
+    let args = Args::try_parse_from([
+        "program",
+        "fix", "conflicts",
+        "--strategy", "4.12.0",
+        "--dependency", "com.squareup.okhttp3:okhttp"
+    ]).unwrap();
+    assert!(validate_args(&args, &config).is_ok());
+}
+
 #[test]
 fn test_parse_args_short_path() {
-    let args = Args::try_parse_from(&[
+    let args = Args::try_parse_from([
         "program",
         "-p", "/short/path"
     ]).unwrap();
@@ -157,6 +263,7 @@ fn test_default_behavior_validation() {
         path: std::path::PathBuf::from("."),
         output: None,
         silent: false,
+        matrix: None,
         command: None,
     };
     
@@ -166,7 +273,7 @@ fn test_default_behavior_validation() {
 
 #[test]
 fn test_parse_output_option() {
-    let args = Args::try_parse_from(&[
+    let args = Args::try_parse_from([
         "program",
         "--output", "results.json"
     ]).unwrap();
@@ -176,7 +283,7 @@ fn test_parse_output_option() {
 
 #[test]
 fn test_parse_output_with_subcommand() {
-    let args = Args::try_parse_from(&[
+    let args = Args::try_parse_from([
         "program",
         "--path", "/test/path",
         "--output", "analysis.json",
@@ -197,7 +304,7 @@ fn test_parse_output_with_subcommand() {
 
 #[test]
 fn test_parse_silent_option() {
-    let args = Args::try_parse_from(&[
+    let args = Args::try_parse_from([
         "program",
         "--silent"
     ]).unwrap();
@@ -207,7 +314,7 @@ fn test_parse_silent_option() {
 
 #[test]
 fn test_parse_silent_with_output() {
-    let args = Args::try_parse_from(&[
+    let args = Args::try_parse_from([
         "program",
         "--output", "results.json",
         "--silent"
@@ -215,4 +322,13 @@ fn test_parse_silent_with_output() {
     
     assert_eq!(args.output, Some(std::path::PathBuf::from("results.json")));
     assert!(args.silent);
+}
+
+#[test]
+fn test_parse_global_matrix() {
+    // The compatibility check runs as part of `all` and the default command too
+    for command in [vec!["program", "--matrix", "matrix.toml"], vec!["program", "all", "--matrix", "matrix.toml"], vec!["program", "compatibility", "--matrix", "matrix.toml"]] {
+        let args = Args::try_parse_from(command).unwrap();
+        assert_eq!(args.matrix, Some(std::path::PathBuf::from("matrix.toml")));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,113 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use gradle_dependency_health_checker::analyzer::{perform_complete_analysis, perform_complete_analysis_with_config, AnalysisParameters};
+use gradle_dependency_health_checker::compatibility::CompatibilityFindingKind;
+use gradle_dependency_health_checker::config::{CompatibilityMatrix, Config};
+use tempfile::tempdir;
+use std::fs;
+use std::path::Path;
+
+fn create_wrapper_properties(root: &Path, gradle_version: &str) {
+    let wrapper_dir = root.join("gradle/wrapper");
+    fs::create_dir_all(&wrapper_dir).unwrap();
+    fs::write(wrapper_dir.join("gradle-wrapper.properties"), format!(r#"distributionBase=GRADLE_USER_HOME
+distributionPath=wrapper/dists
+distributionUrl=https\://services.gradle.org/distributions/gradle-{}-bin.zip
+zipStoreBase=GRADLE_USER_HOME
+"#, gradle_version)).unwrap();
+}
+
+#[test]
+fn test_incompatible_gradle_agp_kotlin_and_jdk() {
+    let temp_dir = tempdir().unwrap();
+    create_wrapper_properties(temp_dir.path(), "7.5");
+    fs::write(temp_dir.path().join(".java-version"), "11.0.21\n").unwrap();
+
+    fs::write(temp_dir.path().join("build.gradle.kts"), r#"plugins {
+    id("com.android.application") version "8.1.0" apply false
+    kotlin("android") version "2.3.0" apply false
+}
+"#).unwrap();
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let compatibility = &analysis.compatibility_analysis;
+    assert_eq!(compatibility.builds.len(), 1);
+
+    let build = &compatibility.builds[0];
+    assert_eq!(build.gradle.as_ref().unwrap().version, "7.5");
+    assert_eq!(build.gradle.as_ref().unwrap().line_number, 3);
+    assert_eq!(build.jdk.as_ref().unwrap().version, "11");
+    assert_eq!(build.agp.len(), 1);
+    assert_eq!(build.agp[0].version, "8.1.0");
+    assert_eq!(build.kotlin.len(), 1);
+    assert_eq!(build.kotlin[0].version, "2.3.0");
+
+    // AGP 8.1 needs Gradle 8.0 and JDK 17, Kotlin 2.3 needs Gradle 7.6.3 and AGP 8.2.2
+    let wrapper_findings = &compatibility.findings[&temp_dir.path().join("gradle/wrapper/gradle-wrapper.properties")];
+    assert_eq!(wrapper_findings.len(), 2);
+    assert_eq!(wrapper_findings[0].kind, CompatibilityFindingKind::GradleTooOldForAgp);
+    assert_eq!(wrapper_findings[0].subject.found_version, "7.5");
+    assert_eq!(wrapper_findings[0].subject.required_version, "8.0");
+    assert_eq!(wrapper_findings[1].kind, CompatibilityFindingKind::GradleTooOldForKotlin);
+    assert_eq!(wrapper_findings[1].subject.required_version, "7.6.3");
+
+    let jdk_findings = &compatibility.findings[&temp_dir.path().join(".java-version")];
+    assert_eq!(jdk_findings[0].kind, CompatibilityFindingKind::JdkTooOldForAgp);
+    assert_eq!(jdk_findings[0].subject.required_version, "17");
+
+    let agp_findings = &compatibility.findings[&temp_dir.path().join("build.gradle.kts")];
+    assert_eq!(agp_findings.len(), 1);
+    assert_eq!(agp_findings[0].kind, CompatibilityFindingKind::AgpTooOldForKotlin);
+    assert_eq!(agp_findings[0].line_number, 2);
+    assert_eq!(agp_findings[0].subject.required_version, "8.2.2");
+    assert!(agp_findings[0].message.contains("Kotlin Gradle plugin 2.3.0"));
+}
+
+#[test]
+fn test_compatible_versions_and_matrix_override() {
+    let temp_dir = tempdir().unwrap();
+    create_wrapper_properties(temp_dir.path(), "8.9");
+
+    let gradle_dir = temp_dir.path().join("gradle");
+    fs::write(gradle_dir.join("gradle-daemon-jvm.properties"), "toolchainVersion=17\n").unwrap();
+    fs::write(gradle_dir.join("libs.versions.toml"), r#"[versions]
+agp = "8.7.3"
+kotlin = "2.1.0"
+
+[plugins]
+android-application = { id = "com.android.application", version.ref = "agp" }
+kotlin-android = { id = "org.jetbrains.kotlin.android", version.ref = "kotlin" }
+"#).unwrap();
+    fs::write(temp_dir.path().join("build.gradle.kts"), r#"plugins {
+    alias(libs.plugins.android.application) apply false
+    alias(libs.plugins.kotlin.android) apply false
+}
+"#).unwrap();
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let build = &analysis.compatibility_analysis.builds[0];
+    assert_eq!(build.jdk.as_ref().unwrap().version, "17");
+    assert_eq!(build.agp[0].version, "8.7.3");
+    assert_eq!(build.kotlin[0].version, "2.1.0");
+    assert!(analysis.compatibility_analysis.findings.is_empty());
+
+    // A matrix passed through the config replaces the bundled one
+    let config = Config {
+        compatibility_matrix: CompatibilityMatrix::from_toml(r#"
+[[agp]]
+version = "8.7"
+min_gradle = "8.10"
+"#).unwrap(),
+        ..Config::default()
+    };
+    let analysis = perform_complete_analysis_with_config(temp_dir.path(), &AnalysisParameters::default(), &config).unwrap();
+    let findings: Vec<_> = analysis.compatibility_analysis.findings.values().flatten().collect();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].kind, CompatibilityFindingKind::GradleTooOldForAgp);
+    assert_eq!(findings[0].subject.required_version, "8.10");
+}
\ No newline at end of file
@@ -0,0 +1,158 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+mod common;
+
+use gradle_dependency_health_checker::analyzer::perform_complete_analysis;
+use gradle_dependency_health_checker::composite::discover_builds;
+use common::{create_test_build_gradle, create_test_version_catalog};
+use tempfile::tempdir;
+use std::fs;
+
+#[test]
+fn test_included_build_discovery() {
+    let temp_dir = tempdir().unwrap();
+    let app_root = temp_dir.path().join("app");
+    let shared_root = temp_dir.path().join("shared-lib");
+    fs::create_dir_all(&app_root).unwrap();
+    fs::create_dir_all(&shared_root).unwrap();
+
+    fs::write(app_root.join("settings.gradle.kts"), r#"
+rootProject.name = "my-app"
+includeBuild("../shared-lib")
+includeBuild("missing-build")
+"#).unwrap();
+
+    // Cycles between builds are followed only once
+    fs::write(shared_root.join("settings.gradle"), r#"
+includeBuild '../app'
+"#).unwrap();
+
+    let builds = discover_builds(&app_root).unwrap();
+
+    assert_eq!(builds.len(), 2);
+    assert_eq!(builds[0].name, "my-app");
+    assert_eq!(builds[1].name, "shared-lib");
+}
+
+#[test]
+fn test_cross_build_version_conflicts() {
+    let temp_dir = tempdir().unwrap();
+    let app_root = temp_dir.path().join("app");
+    let shared_root = temp_dir.path().join("shared-lib");
+
+    create_test_build_gradle(&app_root, "feature", r#"
+dependencies {
+    implementation 'com.squareup.okhttp3:okhttp:4.12.0'
+    implementation 'com.google.code.gson:gson:2.10.1'
+}
+"#);
+    fs::write(app_root.join("settings.gradle"), r#"
+includeBuild '../shared-lib'
+"#).unwrap();
+
+    // The included build resolves against its own catalog
+    create_test_version_catalog(&shared_root, r#"
+[versions]
+okhttp = "4.11.0"
+
+[libraries]
+okhttp = { module = "com.squareup.okhttp3:okhttp", version.ref = "okhttp" }
+"#);
+    create_test_build_gradle(&shared_root, "network", r#"
+dependencies {
+    implementation libs.okhttp
+    implementation 'com.google.code.gson:gson:2.10.1'
+}
+"#);
+
+    let analysis = perform_complete_analysis(&app_root, 2, 2).unwrap();
+    let composite = &analysis.composite_analysis;
+
+    assert_eq!(composite.builds.len(), 2);
+    assert_eq!(composite.cross_build_conflicts.len(), 1);
+
+    let okhttp = &composite.cross_build_conflicts["com.squareup.okhttp3:okhttp"];
+    assert_eq!(okhttp.len(), 2);
+    assert!(okhttp.iter().any(|loc| loc.build == "app" && loc.location.dependency.version.as_deref() == Some("4.12.0")));
+    assert!(okhttp.iter().any(|loc| loc.build == "shared-lib" && loc.location.dependency.version.as_deref() == Some("4.11.0")));
+
+    // Included build dependencies take part in the regular analyses too
+    assert!(analysis.duplicate_analysis.regular_duplicates.contains_key("com.google.code.gson:gson"));
+}
+
+#[test]
+fn test_single_build_has_no_cross_build_conflicts() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+dependencies {
+    implementation 'com.squareup.okhttp3:okhttp:4.12.0'
+}
+"#);
+    create_test_build_gradle(temp_dir.path(), "lib", r#"
+dependencies {
+    implementation 'com.squareup.okhttp3:okhttp:4.11.0'
+}
+"#);
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+
+    assert_eq!(analysis.composite_analysis.builds.len(), 1);
+    assert!(analysis.composite_analysis.cross_build_conflicts.is_empty());
+    assert_eq!(analysis.duplicate_analysis.version_conflicts.len(), 1);
+}
+
+#[test]
+fn test_nested_included_build_is_scanned_on_its_own() {
+    let temp_dir = tempdir().unwrap();
+    let app_root = temp_dir.path();
+    let logic_root = app_root.join("build-logic");
+
+    fs::write(app_root.join("settings.gradle.kts"), r#"
+rootProject.name = "my-app"
+includeBuild("build-logic")
+"#).unwrap();
+    create_test_version_catalog(app_root, r#"
+[versions]
+okhttp = "4.12.0"
+
+[libraries]
+okhttp = { module = "com.squareup.okhttp3:okhttp", version.ref = "okhttp" }
+"#);
+    create_test_build_gradle(app_root, "feature", r#"
+dependencies {
+    implementation libs.okhttp
+}
+"#);
+
+    // The nested build resolves against its own catalog, not the parent's
+    fs::create_dir_all(&logic_root).unwrap();
+    fs::write(logic_root.join("settings.gradle.kts"), "").unwrap();
+    create_test_version_catalog(&logic_root, r#"
+[versions]
+okhttp = "4.11.0"
+
+[libraries]
+okhttp = { module = "com.squareup.okhttp3:okhttp", version.ref = "okhttp" }
+"#);
+    create_test_build_gradle(&logic_root, "convention", r#"
+dependencies {
+    implementation libs.okhttp
+}
+"#);
+
+    let analysis = perform_complete_analysis(app_root, 2, 2).unwrap();
+    let composite = &analysis.composite_analysis;
+
+    assert_eq!(composite.builds.len(), 2);
+    let okhttp = &composite.cross_build_conflicts["com.squareup.okhttp3:okhttp"];
+    // Each declaration is scanned once, by the build it belongs to
+    assert_eq!(okhttp.len(), 2);
+    assert!(okhttp.iter().any(|loc| loc.build == "my-app" && loc.location.dependency.version.as_deref() == Some("4.12.0")));
+    assert!(okhttp.iter().any(|loc| loc.build == "build-logic" && loc.location.dependency.version.as_deref() == Some("4.11.0")));
+}
\ No newline at end of file
@@ -0,0 +1,117 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+mod common;
+
+use gradle_dependency_health_checker::analyzer::perform_complete_analysis;
+use gradle_dependency_health_checker::conflict_fix::{plan_conflict_fixes, ConflictFixPlan, VersionStrategy};
+use gradle_dependency_health_checker::rewrite::apply_file_changes;
+use common::{create_test_build_gradle, create_test_version_catalog};
+use tempfile::tempdir;
+use std::fs;
+use std::path::Path;
+
+fn plan_fixes(root_path: &Path, strategy: VersionStrategy, dependency: Option<&str>) -> ConflictFixPlan {
+    let analysis = perform_complete_analysis(root_path, 2, 2).unwrap();
+    plan_conflict_fixes(root_path, &analysis.duplicate_analysis.version_conflicts, &strategy, dependency).unwrap()
+}
+
+fn create_conflicting_project(root_path: &Path) {
+    create_test_version_catalog(root_path, r#"[versions]
+okhttp = "4.10.0"
+
+[libraries]
+okhttp = { module = "com.squareup.okhttp3:okhttp", version.ref = "okhttp" }
+gson = { module = "com.google.code.gson:gson", version = "2.9.0" }
+"#);
+
+    create_test_build_gradle(root_path, "app", r#"
+dependencies {
+    implementation "com.squareup.okhttp3:okhttp:4.12.0"
+    implementation libs.gson
+}
+"#);
+
+    create_test_build_gradle(root_path, "lib", r#"
+dependencies {
+    implementation group: 'com.squareup.okhttp3', name: 'okhttp', version: '4.9.0'
+    implementation 'com.google.code.gson:gson:2.10.1'
+}
+"#);
+
+    create_test_build_gradle(root_path, "core", r#"
+dependencies {
+    implementation libs.okhttp
+}
+"#);
+}
+
+#[test]
+fn test_fix_conflicts_highest() {
+    let temp_dir = tempdir().unwrap();
+    create_conflicting_project(temp_dir.path());
+
+    let plan = plan_fixes(temp_dir.path(), VersionStrategy::Highest, None);
+    assert_eq!(plan.resolutions.len(), 2);
+    assert!(plan.skipped.is_empty());
+    apply_file_changes(&plan.changes).unwrap();
+
+    // Quoting style of every notation is preserved
+    let catalog = fs::read_to_string(temp_dir.path().join("gradle/libs.versions.toml")).unwrap();
+    assert!(catalog.contains("okhttp = \"4.12.0\"\n"));
+    assert!(catalog.contains("gson = { module = \"com.google.code.gson:gson\", version = \"2.10.1\" }"));
+    let lib = fs::read_to_string(temp_dir.path().join("lib/build.gradle")).unwrap();
+    assert!(lib.contains("implementation group: 'com.squareup.okhttp3', name: 'okhttp', version: '4.12.0'"));
+    let app = fs::read_to_string(temp_dir.path().join("app/build.gradle")).unwrap();
+    assert!(app.contains("implementation \"com.squareup.okhttp3:okhttp:4.12.0\""));
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    assert!(analysis.duplicate_analysis.version_conflicts.is_empty());
+}
+
+#[test]
+fn test_fix_conflicts_catalog_and_explicit() {
+    let temp_dir = tempdir().unwrap();
+    create_conflicting_project(temp_dir.path());
+
+    let plan = plan_fixes(temp_dir.path(), VersionStrategy::Catalog, None);
+    let okhttp = plan.resolutions.iter().find(|r| r.dependency_key == "com.squareup.okhttp3:okhttp").unwrap();
+    assert_eq!(okhttp.version, "4.10.0");
+    assert_eq!(okhttp.rewritten.len(), 2);
+    // The catalog itself is already at the chosen versions
+    assert!(plan.changes.iter().all(|change| !change.path.ends_with("libs.versions.toml")));
+
+    let plan = plan_fixes(temp_dir.path(), VersionStrategy::Explicit("4.11.0".to_string()), Some("com.squareup.okhttp3:okhttp"));
+    assert_eq!(plan.resolutions.len(), 1);
+    assert_eq!(plan.changes.len(), 3);
+    let diff = plan.changes.iter().map(|change| change.unified_diff(temp_dir.path())).collect::<String>();
+    assert!(diff.contains("-okhttp = \"4.10.0\"\n+okhttp = \"4.11.0\"\n"));
+    assert!(diff.contains("+    implementation \"com.squareup.okhttp3:okhttp:4.11.0\"\n"));
+}
+
+#[test]
+fn test_fix_conflicts_skips_interpolated_versions() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+dependencies {
+    implementation "androidx.core:core-ktx:$coreVersion"
+    implementation 'androidx.core:core-ktx:1.12.0'
+}
+"#);
+    create_test_build_gradle(temp_dir.path(), "lib", r#"
+dependencies {
+    implementation 'androidx.core:core-ktx:1.10.0'
+}
+"#);
+
+    let plan = plan_fixes(temp_dir.path(), VersionStrategy::Highest, None);
+    assert_eq!(plan.resolutions[0].version, "1.12.0");
+    assert_eq!(plan.resolutions[0].rewritten.len(), 1);
+    assert_eq!(plan.skipped.len(), 1);
+    assert_eq!(plan.skipped[0].reason, "version is interpolated from a variable");
+}
\ No newline at end of file
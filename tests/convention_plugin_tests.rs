@@ -0,0 +1,157 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+mod common;
+
+use gradle_dependency_health_checker::analyzer::perform_complete_analysis;
+use gradle_dependency_health_checker::config::Config;
+use gradle_dependency_health_checker::convention_plugins::load_convention_plugins;
+use gradle_dependency_health_checker::parser::{load_version_catalogs, DependencySourceType, PluginSourceType};
+use common::create_test_build_gradle;
+use tempfile::tempdir;
+use std::fs;
+
+fn create_precompiled_script(root: &std::path::Path, relative_path: &str, content: &str) {
+    let script_path = root.join(relative_path);
+    fs::create_dir_all(script_path.parent().unwrap()).unwrap();
+    fs::write(script_path, content).unwrap();
+}
+
+#[test]
+fn test_convention_plugin_loading() {
+    let temp_dir = tempdir().unwrap();
+
+    create_precompiled_script(temp_dir.path(), "buildSrc/src/main/kotlin/myorg.android-library.gradle.kts", r#"
+plugins {
+    id("com.android.library")
+}
+
+dependencies {
+    implementation("androidx.core:core-ktx:1.12.0")
+}
+"#);
+
+    create_precompiled_script(temp_dir.path(), "build-logic/convention/src/main/kotlin/myorg/KotlinConvention.gradle.kts", r#"
+package myorg.conventions
+
+dependencies {
+    implementation("org.jetbrains.kotlinx:kotlinx-coroutines-core:1.7.3")
+}
+"#);
+
+    let version_catalogs = load_version_catalogs(temp_dir.path()).unwrap();
+    let conventions = load_convention_plugins(temp_dir.path(), &version_catalogs, &Config::default()).unwrap();
+
+    assert_eq!(conventions.len(), 2);
+
+    let android_library = &conventions["myorg.android-library"];
+    assert_eq!(android_library.dependencies.len(), 1);
+    assert_eq!(android_library.plugins.len(), 1);
+
+    // Package declarations prefix the plugin id
+    assert!(conventions.contains_key("myorg.conventions.KotlinConvention"));
+}
+
+#[test]
+fn test_convention_plugin_dependencies_are_inherited() {
+    let temp_dir = tempdir().unwrap();
+
+    create_precompiled_script(temp_dir.path(), "buildSrc/src/main/kotlin/myorg.android-library.gradle.kts", r#"
+plugins {
+    id("com.android.library")
+    id("myorg.kotlin")
+}
+
+dependencies {
+    implementation("androidx.core:core-ktx:1.12.0")
+}
+"#);
+
+    create_precompiled_script(temp_dir.path(), "buildSrc/src/main/kotlin/myorg.kotlin.gradle.kts", r#"
+dependencies {
+    implementation("org.jetbrains.kotlinx:kotlinx-coroutines-core:1.7.3")
+}
+"#);
+
+    create_test_build_gradle(temp_dir.path(), "feature", r#"
+plugins {
+    id 'myorg.android-library'
+}
+
+dependencies {
+    implementation 'androidx.core:core-ktx:1.10.0'
+}
+"#);
+
+    create_test_build_gradle(temp_dir.path(), "data", r#"
+plugins {
+    id 'myorg.android-library'
+}
+
+dependencies {
+    implementation 'org.jetbrains.kotlinx:kotlinx-coroutines-core:1.7.3'
+}
+"#);
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+
+    // The redeclared dependency conflicts with the inherited one
+    let conflicts = &analysis.duplicate_analysis.version_conflicts;
+    let core_ktx = &conflicts["androidx.core:core-ktx"];
+    assert_eq!(core_ktx.len(), 3);
+
+    let inherited: Vec<_> = core_ktx
+        .iter()
+        .filter(|loc| matches!(loc.source_type, DependencySourceType::Inherited(_)))
+        .collect();
+    assert_eq!(inherited.len(), 2);
+    for location in inherited {
+        // Attributed to the applying line of the module
+        assert!(location.file_path.ends_with("build.gradle"));
+        assert_eq!(location.line_number, 3);
+        if let DependencySourceType::Inherited(origin) = &location.source_type {
+            assert_eq!(origin.from, "myorg.android-library");
+            assert!(origin.file_path.ends_with("myorg.android-library.gradle.kts"));
+        }
+    }
+
+    // Transitively applied conventions are inherited too
+    let coroutines = &analysis.duplicate_analysis.regular_duplicates["org.jetbrains.kotlinx:kotlinx-coroutines-core"];
+    assert_eq!(coroutines.len(), 3);
+    assert_eq!(coroutines.iter().filter(|loc| matches!(
+        &loc.source_type,
+        DependencySourceType::Inherited(origin) if origin.from == "myorg.kotlin"
+    )).count(), 2);
+
+    // A plugin declared once in the convention is not a duplicate across the applying modules
+    assert!(!analysis.plugin_analysis.duplicate_plugins.contains_key("com.android.library"));
+}
+
+#[test]
+fn test_convention_plugins_are_attributed_to_applying_module() {
+    let temp_dir = tempdir().unwrap();
+
+    create_precompiled_script(temp_dir.path(), "buildSrc/src/main/kotlin/myorg.android-library.gradle.kts", r#"
+plugins {
+    id("com.android.library")
+}
+"#);
+
+    create_test_build_gradle(temp_dir.path(), "feature", r#"
+plugins {
+    id 'myorg.android-library'
+    id 'com.android.library'
+}
+"#);
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+
+    let android_plugins = &analysis.plugin_analysis.duplicate_plugins["com.android.library"];
+    assert_eq!(android_plugins.len(), 2);
+    assert!(android_plugins.iter().all(|loc| loc.file_path.ends_with("feature/build.gradle")));
+    assert_eq!(android_plugins.iter().filter(|loc| matches!(loc.source_type, PluginSourceType::Inherited(_))).count(), 1);
+}
\ No newline at end of file
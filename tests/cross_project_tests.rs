@@ -0,0 +1,151 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+mod common;
+
+use gradle_dependency_health_checker::analyzer::perform_complete_analysis;
+use gradle_dependency_health_checker::cross_project::{find_cross_project_blocks, ProjectScope};
+use gradle_dependency_health_checker::parser::{DependencySourceType, PluginSourceType};
+use gradle_dependency_health_checker::settings::parse_settings_file;
+use common::create_test_build_gradle;
+use tempfile::tempdir;
+use std::fs;
+
+#[test]
+fn test_settings_include_parsing() {
+    let temp_dir = tempdir().unwrap();
+    let settings_file = temp_dir.path().join("settings.gradle");
+
+    fs::write(&settings_file, r#"
+rootProject.name = 'sample'
+include ':app', ':feature:login'
+include(":core")
+includeBuild("build-logic")
+"#).unwrap();
+
+    let settings = parse_settings_file(&settings_file).unwrap();
+
+    assert_eq!(settings.root_project_name, Some("sample".to_string()));
+    assert_eq!(settings.included_projects, vec![":app", ":feature:login", ":core"]);
+    assert_eq!(settings.included_builds, vec!["build-logic"]);
+}
+
+#[test]
+fn test_cross_project_block_detection() {
+    let content = r#"
+allprojects {
+    repositories {
+        mavenCentral()
+    }
+}
+
+subprojects { apply plugin: 'java' }
+
+configure(project(':app'), project(':core')) {
+    dependencies {
+        implementation 'com.google.guava:guava:32.1.2-jre'
+    }
+}
+
+configure(subprojects.filter { it.name.startsWith("lib") }) {
+}
+"#;
+
+    let blocks = find_cross_project_blocks(content).unwrap();
+
+    assert_eq!(blocks.len(), 3);
+    assert_eq!(blocks[0].scope, ProjectScope::AllProjects);
+    assert_eq!((blocks[0].start_line, blocks[0].end_line), (2, 6));
+    assert_eq!(blocks[1].scope, ProjectScope::Subprojects);
+    assert_eq!((blocks[1].start_line, blocks[1].end_line), (8, 8));
+    assert_eq!(blocks[2].scope, ProjectScope::Projects(vec!["app".to_string(), "core".to_string()]));
+}
+
+#[test]
+fn test_subprojects_declarations_are_inherited() {
+    let temp_dir = tempdir().unwrap();
+
+    fs::write(temp_dir.path().join("settings.gradle"), r#"
+include ':app', ':lib'
+"#).unwrap();
+
+    fs::write(temp_dir.path().join("build.gradle"), r#"
+allprojects {
+    apply plugin: 'idea'
+}
+
+subprojects {
+    apply plugin: 'java'
+
+    dependencies {
+        testImplementation 'junit:junit:4.13.2'
+    }
+}
+"#).unwrap();
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+dependencies {
+    testImplementation 'junit:junit:4.12'
+}
+"#);
+
+    create_test_build_gradle(temp_dir.path(), "lib", r#"
+dependencies {
+    implementation 'com.google.code.gson:gson:2.10.1'
+}
+"#);
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+
+    // The module redeclaring junit conflicts with the subprojects declaration
+    let junit = &analysis.duplicate_analysis.version_conflicts["junit:junit"];
+    assert_eq!(junit.len(), 3);
+
+    let inherited: Vec<_> = junit
+        .iter()
+        .filter(|loc| matches!(&loc.source_type, DependencySourceType::Inherited(origin) if origin.from == "subprojects"))
+        .collect();
+    assert_eq!(inherited.len(), 2);
+    assert!(inherited.iter().any(|loc| loc.file_path.ends_with("app/build.gradle")));
+    assert!(inherited.iter().any(|loc| loc.file_path.ends_with("lib/build.gradle")));
+
+    // The root build file no longer claims the subprojects declaration
+    assert!(junit.iter().all(|loc| loc.file_path != temp_dir.path().join("build.gradle")));
+
+    // Plugins declared once for every module are not duplicates
+    assert!(analysis.plugin_analysis.duplicate_plugins.is_empty());
+}
+
+#[test]
+fn test_subprojects_plugin_redeclared_in_module() {
+    let temp_dir = tempdir().unwrap();
+
+    fs::write(temp_dir.path().join("settings.gradle.kts"), r#"
+include(":app")
+"#).unwrap();
+
+    fs::write(temp_dir.path().join("build.gradle.kts"), r#"
+subprojects {
+    apply(plugin = "java")
+}
+"#).unwrap();
+
+    let app_dir = temp_dir.path().join("app");
+    fs::create_dir_all(&app_dir).unwrap();
+    fs::write(app_dir.join("build.gradle.kts"), r#"
+plugins {
+    java
+}
+"#).unwrap();
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+
+    let java = &analysis.plugin_analysis.duplicate_plugins["java"];
+    assert_eq!(java.len(), 2);
+    assert!(java.iter().all(|loc| loc.file_path.ends_with("app/build.gradle.kts")));
+    assert!(java.iter().any(|loc| matches!(&loc.source_type, PluginSourceType::Inherited(origin) if origin.from == "subprojects")));
+}
\ No newline at end of file
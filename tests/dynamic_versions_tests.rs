@@ -0,0 +1,150 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+mod common;
+
+use gradle_dependency_health_checker::analyzer::{perform_complete_analysis, perform_complete_analysis_with_config, AnalysisParameters};
+use gradle_dependency_health_checker::config::Config;
+use gradle_dependency_health_checker::findings::Severity;
+use gradle_dependency_health_checker::version::DynamicVersionKind;
+use common::{create_test_build_gradle, create_test_version_catalog};
+use tempfile::tempdir;
+use std::fs;
+
+#[test]
+fn test_dynamic_versions_in_build_files_and_catalogs() {
+    let temp_dir = tempdir().unwrap();
+
+    let gradle_dir = temp_dir.path().join("gradle");
+    fs::create_dir_all(&gradle_dir).unwrap();
+    fs::write(gradle_dir.join("libs.versions.toml"), r#"[versions]
+okhttp = "4.+"
+kotlin = "2.0.0"
+
+[libraries]
+okhttp = { module = "com.squareup.okhttp3:okhttp", version.ref = "okhttp" }
+internal = { module = "com.example:internal", version = "1.0-SNAPSHOT" }
+"#).unwrap();
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"plugins {
+    id 'com.example.plugin' version 'latest.release'
+}
+
+dependencies {
+    implementation libs.okhttp
+    implementation 'com.google.guava:guava:[31.0,33.0)'
+    api "com.example:core:2.0-SNAPSHOT"
+    testImplementation "com.example:testing:2.0-SNAPSHOT"
+    runtimeOnly 'org.slf4j:slf4j-api:+'
+    implementation 'com.squareup.retrofit2:retrofit:2.11.0'
+}
+"#);
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let findings = &analysis.dynamic_version_analysis.findings;
+
+    // libs.okhttp is reported at its [versions] entry rather than where the module uses it
+    let catalog_findings = &findings[&gradle_dir.join("libs.versions.toml")];
+    assert_eq!(catalog_findings.len(), 2);
+    assert_eq!(catalog_findings[0].subject.declaration, "[versions] okhttp");
+    assert_eq!(catalog_findings[0].kind, DynamicVersionKind::Plus);
+    assert_eq!(catalog_findings[0].subject.severity, Severity::High);
+    assert_eq!(catalog_findings[0].line_number, 2);
+    assert_eq!(catalog_findings[1].subject.declaration, "[libraries] internal");
+    assert_eq!(catalog_findings[1].kind, DynamicVersionKind::Snapshot);
+    assert_eq!(catalog_findings[1].line_number, 7);
+
+    // The testImplementation snapshot is allowed by default
+    let build_findings = &findings[&temp_dir.path().join("app/build.gradle")];
+    let reported: Vec<(usize, DynamicVersionKind, Severity, Option<&str>)> = build_findings
+        .iter()
+        .map(|finding| (finding.line_number, finding.kind, finding.subject.severity, finding.subject.configuration.as_deref()))
+        .collect();
+    assert_eq!(reported, vec![
+        (2, DynamicVersionKind::LatestRelease, Severity::High, None),
+        (7, DynamicVersionKind::Range, Severity::Medium, Some("implementation")),
+        (8, DynamicVersionKind::Snapshot, Severity::Medium, Some("api")),
+        (10, DynamicVersionKind::Plus, Severity::High, Some("runtimeOnly")),
+    ]);
+}
+
+#[test]
+fn test_dynamic_version_severity_overrides() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"dependencies {
+    implementation "com.example:core:2.0-SNAPSHOT"
+    testImplementation "com.example:testing:2.0-SNAPSHOT"
+    androidTestImplementation "com.example:ui-testing:2.0-SNAPSHOT"
+    implementation 'com.google.guava:guava:[31.0,33.0)'
+}
+"#);
+
+    // Snapshots are fine in implementation, ranges only informational, and test snapshots reported again
+    let mut config = Config::default();
+    config.dynamic_version_severities.patterns.push((DynamicVersionKind::Range, Severity::Low));
+    config.dynamic_version_severities.configuration_overrides.push(("implementation".to_string(), DynamicVersionKind::Snapshot, None));
+    config.dynamic_version_severities.configuration_overrides.push(("androidTest*".to_string(), DynamicVersionKind::Snapshot, Some(Severity::High)));
+
+    let analysis = perform_complete_analysis_with_config(temp_dir.path(), &AnalysisParameters::default(), &config).unwrap();
+    let build_findings = &analysis.dynamic_version_analysis.findings[&temp_dir.path().join("app/build.gradle")];
+    let reported: Vec<(usize, Severity)> = build_findings
+        .iter()
+        .map(|finding| (finding.line_number, finding.subject.severity))
+        .collect();
+    assert_eq!(reported, vec![(4, Severity::High), (5, Severity::Low)]);
+
+    // Raising the minimum drops the informational range
+    config.dynamic_version_severities.min_severity = Severity::Medium;
+    let analysis = perform_complete_analysis_with_config(temp_dir.path(), &AnalysisParameters::default(), &config).unwrap();
+    let build_findings = &analysis.dynamic_version_analysis.findings[&temp_dir.path().join("app/build.gradle")];
+    assert_eq!(build_findings.len(), 1);
+    assert_eq!(build_findings[0].line_number, 4);
+}
+#[test]
+fn test_dynamic_catalog_versions_use_referencing_configurations() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_version_catalog(temp_dir.path(), r#"[versions]
+testing = "2.0-SNAPSHOT"
+
+[libraries]
+testing = { module = "com.example:testing", version.ref = "testing" }
+core = { module = "com.example:core", version = "2.0-SNAPSHOT" }
+mockserver = { module = "com.example:mockserver", version = "1.0-SNAPSHOT" }
+unused = { module = "com.example:unused", version = "1.0-SNAPSHOT" }
+
+[bundles]
+test-support = ["mockserver"]
+"#);
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"dependencies {
+    testImplementation libs.testing
+    testImplementation libs.core
+    implementation libs.core
+    androidTestImplementation libs.bundles.test.support
+}
+"#);
+
+    // Snapshots only test configurations use are allowed, as they are when declared in the build file
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let catalog_findings = &analysis.dynamic_version_analysis.findings[&temp_dir.path().join("gradle/libs.versions.toml")];
+    let reported: Vec<(&str, Option<&str>)> = catalog_findings
+        .iter()
+        .map(|finding| (finding.subject.declaration.as_str(), finding.subject.configuration.as_deref()))
+        .collect();
+    assert_eq!(reported, vec![("[libraries] core", Some("implementation")), ("[libraries] unused", None)]);
+
+    // The most serious referencing configuration decides
+    let mut config = Config::default();
+    config.dynamic_version_severities.configuration_overrides.push(("androidTest*".to_string(), DynamicVersionKind::Snapshot, Some(Severity::High)));
+    let analysis = perform_complete_analysis_with_config(temp_dir.path(), &AnalysisParameters::default(), &config).unwrap();
+    let catalog_findings = &analysis.dynamic_version_analysis.findings[&temp_dir.path().join("gradle/libs.versions.toml")];
+    let mockserver = catalog_findings.iter().find(|finding| finding.subject.declaration == "[libraries] mockserver").unwrap();
+    assert_eq!(mockserver.subject.severity, Severity::High);
+    assert_eq!(mockserver.subject.configuration.as_deref(), Some("androidTestImplementation"));
+}
\ No newline at end of file
@@ -0,0 +1,151 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+mod common;
+
+use gradle_dependency_health_checker::analyzer::perform_complete_analysis;
+use gradle_dependency_health_checker::jvm_target::{JvmSetting, JvmTargetFindingKind};
+use common::create_test_build_gradle;
+use tempfile::tempdir;
+use std::fs;
+
+#[test]
+fn test_jvm_settings_parsing() {
+    let temp_dir = tempdir().unwrap();
+
+    let app_dir = temp_dir.path().join("app");
+    fs::create_dir_all(&app_dir).unwrap();
+    fs::write(app_dir.join("build.gradle.kts"), r#"plugins {
+    id("com.android.application")
+    kotlin("android")
+}
+
+android {
+    compileOptions {
+        sourceCompatibility = JavaVersion.VERSION_1_8
+        targetCompatibility(JavaVersion.VERSION_1_8)
+    }
+}
+
+kotlin {
+    compilerOptions { jvmTarget.set(JvmTarget.JVM_1_8) }
+}
+"#).unwrap();
+
+    create_test_build_gradle(temp_dir.path(), "lib", r#"plugins {
+    id 'java-library'
+}
+
+java {
+    toolchain {
+        languageVersion = JavaLanguageVersion.of(libs.versions.jdk.get().toInteger())
+    }
+}
+
+tasks.withType(org.jetbrains.kotlin.gradle.tasks.KotlinCompile).configureEach {
+    kotlinOptions.jvmTarget = '1.8' // same as :app
+}
+"#);
+
+    // Modules without JVM settings are not listed
+    create_test_build_gradle(temp_dir.path(), "plain", "plugins {\n    id 'java'\n}\n");
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let modules = &analysis.jvm_target_analysis.modules;
+    assert_eq!(modules.len(), 2);
+
+    let app = &modules[0];
+    assert!(app.is_kotlin);
+    let source = app.source_compatibility.as_ref().unwrap();
+    assert_eq!(source.value, "JavaVersion.VERSION_1_8");
+    assert_eq!(source.java_version.as_deref(), Some("8"));
+    assert_eq!(source.line_number, 8);
+    assert_eq!(app.target_compatibility.as_ref().unwrap().value, "JavaVersion.VERSION_1_8");
+    assert_eq!(app.kotlin_jvm_target.as_ref().unwrap().value, "JvmTarget.JVM_1_8");
+    assert_eq!(app.kotlin_jvm_target.as_ref().unwrap().java_version.as_deref(), Some("8"));
+    assert!(app.toolchain.is_none());
+
+    let lib = &modules[1];
+    assert!(!lib.is_kotlin);
+    let toolchain = lib.toolchain.as_ref().unwrap();
+    assert_eq!(toolchain.value, "libs.versions.jdk.get().toInteger()");
+    assert_eq!(toolchain.java_version, None);
+    assert_eq!(lib.kotlin_jvm_target.as_ref().unwrap().value, "1.8");
+
+    assert!(analysis.jvm_target_analysis.findings.is_empty());
+}
+
+#[test]
+fn test_jvm_target_findings() {
+    let temp_dir = tempdir().unwrap();
+
+    for module in ["core", "data"] {
+        create_test_build_gradle(temp_dir.path(), module, r#"plugins {
+    id 'org.jetbrains.kotlin.jvm'
+}
+
+kotlin {
+    jvmToolchain(17)
+}
+"#);
+    }
+
+    create_test_build_gradle(temp_dir.path(), "legacy", r#"plugins {
+    id 'org.jetbrains.kotlin.jvm'
+}
+
+kotlin {
+    jvmToolchain(11)
+}
+
+java {
+    targetCompatibility = JavaVersion.VERSION_1_8
+}
+"#);
+
+    // A Java-only module may target an older release than its toolchain
+    create_test_build_gradle(temp_dir.path(), "java-only", r#"plugins {
+    id 'java-library'
+}
+
+java {
+    toolchain {
+        languageVersion.set(JavaLanguageVersion.of(17))
+    }
+    sourceCompatibility = '1.8'
+}
+"#);
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let findings = &analysis.jvm_target_analysis.findings;
+    assert_eq!(findings.len(), 1);
+
+    let legacy_findings = &findings[&temp_dir.path().join("legacy/build.gradle")];
+    assert_eq!(legacy_findings.len(), 2);
+    assert_eq!(legacy_findings[0].kind, JvmTargetFindingKind::InconsistentSetting);
+    assert_eq!(legacy_findings[0].subject, JvmSetting::Toolchain);
+    assert_eq!(legacy_findings[0].line_number, 6);
+    assert!(legacy_findings[0].message.contains("jvmToolchain 11 differs from 17 used by 3 of 4 modules"));
+    assert_eq!(legacy_findings[1].kind, JvmTargetFindingKind::JavaKotlinMismatch);
+    assert_eq!(legacy_findings[1].subject, JvmSetting::TargetCompatibility);
+    assert_eq!(legacy_findings[1].line_number, 10);
+    assert!(legacy_findings[1].message.contains("Kotlin targets JVM 11 (jvmToolchain) but Java targets JVM 8 (targetCompatibility)"));
+}
+
+#[test]
+fn test_jvm_consistency_requires_majority() {
+    let temp_dir = tempdir().unwrap();
+
+    for (module, release) in [("api", 11), ("server", 17)] {
+        create_test_build_gradle(temp_dir.path(), module, &format!("plugins {{\n    id 'java-library'\n}}\n\njava {{\n    toolchain {{\n        languageVersion = JavaLanguageVersion.of({})\n    }}\n}}\n", release));
+    }
+
+    // Two releases used once each leave no majority to be out of line with
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    assert_eq!(analysis.jvm_target_analysis.modules.len(), 2);
+    assert!(analysis.jvm_target_analysis.findings.is_empty());
+}
\ No newline at end of file
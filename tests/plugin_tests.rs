@@ -7,8 +7,11 @@
 
 mod common;
 
-use gradle_dependency_health_checker::parser::{parse_plugins_from_file, load_version_catalogs};
-use gradle_dependency_health_checker::analyzer::perform_complete_analysis;
+use gradle_dependency_health_checker::config::Config;
+use gradle_dependency_health_checker::parser::{parse_plugins_from_file, parse_plugins_from_file_with_config, parse_dependencies_from_file, load_version_catalogs, PluginSourceType};
+use gradle_dependency_health_checker::analyzer::{perform_complete_analysis, perform_complete_analysis_with_config, AnalysisParameters};
+use gradle_dependency_health_checker::findings::Severity;
+use gradle_dependency_health_checker::plugin_applications::PluginApplicationFindingKind;
 use common::create_test_build_gradle;
 use tempfile::tempdir;
 use std::fs;
@@ -162,14 +165,318 @@ plugins {
     id 'java'
     id 'java-library'
 }
+
+apply plugin: 'java-library'
 "#);
     
     let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
     
-    // Should detect java plugin as duplicate across modules
+    // Every module applies java on its own; only lib applies java-library twice
     assert_eq!(analysis.plugin_analysis.duplicate_plugins.len(), 1);
-    assert!(analysis.plugin_analysis.duplicate_plugins.contains_key("java"));
+    assert!(analysis.plugin_analysis.duplicate_plugins.contains_key("java-library"));
+    
+    let java_library_duplicates = &analysis.plugin_analysis.duplicate_plugins["java-library"];
+    assert_eq!(java_library_duplicates.len(), 2);
+    assert!(java_library_duplicates.iter().all(|loc| loc.file_path.ends_with("lib/build.gradle")));
+    assert!(analysis.plugin_analysis.version_conflicts.is_empty());
+}
+
+#[test]
+fn test_plugin_version_conflict_analysis() {
+    let temp_dir = tempdir().unwrap();
+    
+    fs::write(temp_dir.path().join("build.gradle"), r#"
+buildscript {
+    dependencies {
+        classpath "com.android.tools.build:gradle:8.1.0"
+    }
+}
+"#).unwrap();
+    
+    fs::write(temp_dir.path().join("libs.versions.toml"), r#"
+[versions]
+kotlin = "1.9.10"
+
+[plugins]
+kotlinAndroid = { id = "org.jetbrains.kotlin.android", version.ref = "kotlin" }
+"#).unwrap();
+    
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+plugins {
+    id 'com.android.application' version '7.4.2'
+    alias(libs.plugins.kotlinAndroid)
+}
+"#);
+    
+    create_test_build_gradle(temp_dir.path(), "lib", r#"
+plugins {
+    id 'com.android.library' version '8.1.0'
+    id 'org.jetbrains.kotlin.android' version '1.8.22'
+    id 'org.springframework.boot' version '3.1.0'
+}
+"#);
+    
+    create_test_build_gradle(temp_dir.path(), "server", r#"
+plugins {
+    id 'org.springframework.boot' version '3.1.5'
+}
+"#);
+    
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let conflicts = &analysis.plugin_analysis.version_conflicts;
+    
+    // Android plugins and the classpath entry share the Android Gradle Plugin version, which the classpath pins
+    assert_eq!(conflicts.len(), 3);
+    let agp = &conflicts["com.android.tools.build:gradle"];
+    assert_eq!(agp.severity, Severity::High);
+    assert_eq!(agp.versions, vec!["7.4.2", "8.1.0"]);
+    assert_eq!(agp.locations.len(), 3);
+    assert!(agp.classpath_pinned);
+    let mismatches = &analysis.plugin_analysis.classpath_version_mismatches;
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches["com.android.tools.build:gradle"].len(), 3);
+    
+    // The catalog version counts like a literal one
+    let kotlin = &conflicts["org.jetbrains.kotlin:kotlin-gradle-plugin"];
+    assert_eq!(kotlin.severity, Severity::Medium);
+    assert_eq!(kotlin.versions, vec!["1.8.22", "1.9.10"]);
+    
+    assert_eq!(conflicts["org.springframework.boot"].severity, Severity::Low);
+    
+    // None of these is applied twice by the same module
+    assert!(analysis.plugin_analysis.duplicate_plugins.is_empty());
+}
+
+#[test]
+fn test_buildscript_classpath_parsing() {
+    let temp_dir = tempdir().unwrap();
+    
+    fs::write(temp_dir.path().join("build.gradle"), r#"
+buildscript {
+    repositories {
+        google()
+    }
+    dependencies {
+        classpath "com.android.tools.build:gradle:8.1.0"
+        classpath("org.jetbrains.kotlin:kotlin-gradle-plugin:1.9.10")
+    }
+}
+
+dependencies {
+    implementation 'com.google.code.gson:gson:2.10.1'
+}
+"#).unwrap();
+    
+    let version_catalogs = load_version_catalogs(temp_dir.path()).unwrap();
+    let gradle_file = temp_dir.path().join("build.gradle");
+    let plugins = parse_plugins_from_file(&gradle_file, &version_catalogs).unwrap();
+    
+    assert_eq!(plugins.len(), 2);
+    assert_eq!(plugins[0].plugin.id, "com.android.tools.build:gradle");
+    assert_eq!(plugins[0].plugin.version, Some("8.1.0".to_string()));
+    assert!(matches!(plugins[0].source_type, PluginSourceType::BuildscriptClasspath(_)));
+    assert_eq!(plugins[1].plugin.version, Some("1.9.10".to_string()));
+    
+    // Classpath entries are not module dependencies
+    let dependencies = parse_dependencies_from_file(&gradle_file, &version_catalogs).unwrap();
+    assert_eq!(dependencies.len(), 1);
+    assert_eq!(dependencies[0].dependency.artifact, "gson");
+}
+
+#[test]
+fn test_plugin_management_parsing() {
+    let temp_dir = tempdir().unwrap();
+    
+    fs::write(temp_dir.path().join("settings.gradle.kts"), r#"
+pluginManagement {
+    repositories {
+        gradlePluginPortal()
+    }
+    plugins {
+        id("com.android.application") version "8.1.0"
+        kotlin("android") version "1.9.10"
+    }
+}
+
+rootProject.name = "sample"
+"#).unwrap();
+    
+    let version_catalogs = load_version_catalogs(temp_dir.path()).unwrap();
+    let settings_file = temp_dir.path().join("settings.gradle.kts");
+    let plugins = parse_plugins_from_file(&settings_file, &version_catalogs).unwrap();
+    
+    assert_eq!(plugins.len(), 2);
+    assert!(plugins.iter().all(|p| matches!(p.source_type, PluginSourceType::PluginManagement)));
+    assert_eq!(plugins[1].plugin.id, "org.jetbrains.kotlin.android");
+}
+
+#[test]
+fn test_classpath_version_mismatch_analysis() {
+    let temp_dir = tempdir().unwrap();
+    
+    fs::write(temp_dir.path().join("build.gradle"), r#"
+buildscript {
+    dependencies {
+        classpath "com.android.tools.build:gradle:8.1.0"
+        classpath "org.jetbrains.kotlin:kotlin-gradle-plugin:1.9.10"
+    }
+}
+"#).unwrap();
+    
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+plugins {
+    id 'com.android.application' version '8.2.0'
+    id 'org.jetbrains.kotlin.android' version '1.9.10'
+}
+"#);
+    
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let mismatches = &analysis.plugin_analysis.classpath_version_mismatches;
+    
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches["com.android.tools.build:gradle"].len(), 2);
+}
+
+#[test]
+fn test_apply_false_parsing() {
+    let temp_dir = tempdir().unwrap();
+    
+    fs::write(temp_dir.path().join("build.gradle.kts"), r#"
+plugins {
+    id("com.android.application") version "8.1.0" apply false
+    id("org.jetbrains.kotlin.android") apply false
+    id("com.diffplug.spotless")
+}
+"#).unwrap();
+    
+    let version_catalogs = load_version_catalogs(temp_dir.path()).unwrap();
+    let plugins = parse_plugins_from_file(&temp_dir.path().join("build.gradle.kts"), &version_catalogs).unwrap();
+    
+    assert_eq!(plugins.len(), 3);
+    assert!(!plugins[0].apply);
+    assert_eq!(plugins[0].plugin.version, Some("8.1.0".to_string()));
+    assert!(!plugins[1].apply);
+    assert_eq!(plugins[1].plugin.id, "org.jetbrains.kotlin.android");
+    assert!(plugins[2].apply);
+}
+
+#[test]
+fn test_plugin_application_findings() {
+    let temp_dir = tempdir().unwrap();
+    
+    fs::write(temp_dir.path().join("build.gradle"), r#"
+plugins {
+    id 'com.android.application' version '8.1.0' apply false
+    id 'com.google.dagger.hilt.android' version '2.48' apply false
+}
+"#).unwrap();
+    
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+plugins {
+    id 'com.android.application' version '8.1.0'
+    id 'jacoco'
+}
+
+apply plugin: 'jacoco'
+apply plugin: 'maven-publish'
+"#);
+    
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let findings = &analysis.plugin_analysis.application_findings;
+    
+    let root = &findings[&temp_dir.path().join("build.gradle")];
+    assert_eq!(root.len(), 1);
+    assert_eq!(root[0].kind, PluginApplicationFindingKind::UnusedApplyFalse);
+    assert_eq!(root[0].subject, "com.google.dagger.hilt.android");
+    
+    let app: Vec<_> = findings[&temp_dir.path().join("app/build.gradle")]
+        .iter()
+        .map(|finding| (finding.kind.clone(), finding.subject.as_str(), finding.line_number))
+        .collect();
+    assert_eq!(app, vec![
+        (PluginApplicationFindingKind::RedundantVersion, "com.android.application", 3),
+        (PluginApplicationFindingKind::MixedApplication, "jacoco", 7),
+        (PluginApplicationFindingKind::LegacyApplyPlugin, "maven-publish", 8),
+    ]);
+    
+    // `apply false` only declares the version, so the module applying it is not a duplicate
+    assert!(!analysis.plugin_analysis.duplicate_plugins.contains_key("com.android.application"));
+}
+
+#[test]
+fn test_configured_plugin_id_aliases_in_analysis() {
+    let temp_dir = tempdir().unwrap();
+    
+    create_test_build_gradle(temp_dir.path(), "feature", r#"
+plugins {
+    id 'com.myorg.android'
+}
+
+apply plugin: 'myorg-android'
+"#);
+    
+    // Without the alias the two ids name different plugins
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    assert!(analysis.plugin_analysis.duplicate_plugins.is_empty());
+    
+    let mut config = Config::default();
+    config.plugin_id_aliases.aliases.push(("myorg-android".to_string(), "com.myorg.android".to_string()));
+    let analysis = perform_complete_analysis_with_config(temp_dir.path(), &AnalysisParameters::default(), &config).unwrap();
+    
+    let duplicates = &analysis.plugin_analysis.duplicate_plugins["com.myorg.android"];
+    assert_eq!(duplicates.len(), 2);
+    let findings = &analysis.plugin_analysis.application_findings[&temp_dir.path().join("feature/build.gradle")];
+    assert!(findings.iter().any(|finding| finding.kind == PluginApplicationFindingKind::MixedApplication));
+}
+
+#[test]
+fn test_plugin_id_canonicalization() {
+    let temp_dir = tempdir().unwrap();
+    
+    fs::write(temp_dir.path().join("libs.versions.toml"), r#"
+[plugins]
+kotlin-android = { id = "org.jetbrains.kotlin.android", version = "1.8.22" }
+"#).unwrap();
+    
+    let app_dir = temp_dir.path().join("app");
+    fs::create_dir_all(&app_dir).unwrap();
+    fs::write(app_dir.join("build.gradle.kts"), r#"
+plugins {
+    kotlin("android") version "1.9.10"
+    `kotlin-dsl`
+    `java-library`
+}
+
+apply(plugin = "kotlin-android")
+"#).unwrap();
+    
+    create_test_build_gradle(temp_dir.path(), "lib", r#"
+plugins {
+    alias(libs.plugins.kotlin.android)
+    id 'org.gradle.java-library'
+}
+"#);
+    
+    let version_catalogs = load_version_catalogs(temp_dir.path()).unwrap();
+    let plugins = parse_plugins_from_file(&app_dir.join("build.gradle.kts"), &version_catalogs).unwrap();
+    let ids: Vec<&str> = plugins.iter().map(|p| p.plugin.id.as_str()).collect();
+    assert_eq!(ids, vec!["org.jetbrains.kotlin.android", "org.gradle.kotlin.kotlin-dsl", "java-library", "org.jetbrains.kotlin.android"]);
+    
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    
+    // Shorthand and legacy ids of one plugin in the same file are a duplicate
+    let kotlin = &analysis.plugin_analysis.duplicate_plugins["org.jetbrains.kotlin.android"];
+    assert_eq!(kotlin.len(), 2);
+    assert!(kotlin.iter().all(|loc| loc.file_path.ends_with("app/build.gradle.kts")));
+    
+    // The catalog alias conflicts with the shorthand version
+    let conflict = &analysis.plugin_analysis.version_conflicts["org.jetbrains.kotlin:kotlin-gradle-plugin"];
+    assert_eq!(conflict.versions, vec!["1.8.22", "1.9.10"]);
     
-    let java_duplicates = &analysis.plugin_analysis.duplicate_plugins["java"];
-    assert_eq!(java_duplicates.len(), 2);
+    // Aliases can be extended through the configuration
+    let mut config = Config::default();
+    config.plugin_id_aliases.aliases.push(("myorg-android".to_string(), "com.myorg.android".to_string()));
+    create_test_build_gradle(temp_dir.path(), "feature", "apply plugin: 'myorg-android'\n");
+    let plugins = parse_plugins_from_file_with_config(&temp_dir.path().join("feature/build.gradle"), &version_catalogs, &config).unwrap();
+    assert_eq!(plugins[0].plugin.id, "com.myorg.android");
 }
\ No newline at end of file
@@ -0,0 +1,112 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+mod common;
+
+use gradle_dependency_health_checker::config::Config;
+use gradle_dependency_health_checker::plugins_dsl_migration::plan_plugins_dsl_migration;
+use gradle_dependency_health_checker::rewrite::apply_file_changes;
+use common::create_test_build_gradle;
+use tempfile::tempdir;
+use std::fs;
+
+#[test]
+fn test_migrate_into_existing_plugins_block() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"plugins {
+    id 'com.android.application'
+    id 'org.jetbrains.kotlin.android'
+}
+
+apply plugin: 'kotlin-android'
+apply plugin: 'kotlin-kapt'
+
+android {
+    namespace 'com.example.app'
+}
+"#);
+
+    let migration = plan_plugins_dsl_migration(temp_dir.path(), &Config::default()).unwrap();
+
+    assert_eq!(migration.migrated.len(), 2);
+    assert!(migration.migrated[0].already_declared);
+    assert_eq!(migration.migrated[1].statement, "apply plugin: 'kotlin-kapt'");
+    assert_eq!(migration.migrated[1].location.plugin.id, "org.jetbrains.kotlin.kapt");
+    assert!(migration.skipped.is_empty());
+
+    let diff = migration.changes[0].unified_diff(temp_dir.path());
+    assert!(diff.contains("-apply plugin: 'kotlin-kapt'"));
+    assert!(diff.contains("+    id 'org.jetbrains.kotlin.kapt'"));
+
+    apply_file_changes(&migration.changes).unwrap();
+    assert_eq!(fs::read_to_string(temp_dir.path().join("app/build.gradle")).unwrap(), r#"plugins {
+    id 'com.android.application'
+    id 'org.jetbrains.kotlin.android'
+    id 'org.jetbrains.kotlin.kapt'
+}
+
+android {
+    namespace 'com.example.app'
+}
+"#);
+
+    // A second run has nothing left to migrate
+    let rerun = plan_plugins_dsl_migration(temp_dir.path(), &Config::default()).unwrap();
+    assert!(rerun.migrated.is_empty());
+    assert!(rerun.changes.is_empty());
+}
+
+#[test]
+fn test_migrate_creates_plugins_block() {
+    let temp_dir = tempdir().unwrap();
+
+    let app_dir = temp_dir.path().join("app");
+    fs::create_dir_all(&app_dir).unwrap();
+    fs::write(app_dir.join("build.gradle.kts"), r#"buildscript {
+    repositories {
+        google()
+    }
+}
+apply(plugin = "com.android.application")
+apply(plugin = "kotlin-android")
+
+android {
+    namespace = "com.example.app"
+}
+"#).unwrap();
+
+    // Statements inside a block configure other projects and stay
+    fs::write(temp_dir.path().join("build.gradle"), r#"subprojects {
+    apply plugin: 'java'
+}
+"#).unwrap();
+
+    let migration = plan_plugins_dsl_migration(temp_dir.path(), &Config::default()).unwrap();
+
+    assert_eq!(migration.migrated.len(), 2);
+    assert_eq!(migration.skipped.len(), 1);
+    assert_eq!(migration.skipped[0].location.plugin.id, "java");
+
+    apply_file_changes(&migration.changes).unwrap();
+    assert_eq!(fs::read_to_string(app_dir.join("build.gradle.kts")).unwrap(), r#"buildscript {
+    repositories {
+        google()
+    }
+}
+
+plugins {
+    id("com.android.application")
+    id("org.jetbrains.kotlin.android")
+}
+
+android {
+    namespace = "com.example.app"
+}
+"#);
+    assert!(fs::read_to_string(temp_dir.path().join("build.gradle")).unwrap().contains("apply plugin: 'java'"));
+}
\ No newline at end of file
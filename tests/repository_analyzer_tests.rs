@@ -0,0 +1,182 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+mod common;
+
+use gradle_dependency_health_checker::analyzer::perform_complete_analysis;
+use gradle_dependency_health_checker::parser::{parse_dependencies_from_file, parse_repositories_from_file, RepositoryKind, RepositoryScope};
+use gradle_dependency_health_checker::repository_analyzer::RepositoryFindingKind;
+use common::create_test_build_gradle;
+use tempfile::tempdir;
+use std::collections::HashMap;
+use std::fs;
+
+#[test]
+fn test_repository_parsing() {
+    let temp_dir = tempdir().unwrap();
+
+    create_test_build_gradle(temp_dir.path(), "lib", r#"buildscript {
+    repositories { google(); mavenCentral() }
+    dependencies {
+        classpath 'com.android.tools.build:gradle:8.1.0'
+    }
+}
+
+repositories {
+    // jcenter()
+    maven {
+        name = "internal"
+        url = uri("https://repo.example.com/releases") // release builds only
+    }
+    maven("https://jitpack.io")
+    ivy { url 'https://ivy.example.com' }
+}
+
+dependencies {
+    implementation 'com.squareup.okhttp3:okhttp:4.12.0'
+}
+
+publishing {
+    repositories {
+        mavenLocal()
+    }
+}
+"#);
+
+    let build_file = temp_dir.path().join("lib/build.gradle");
+    let repositories = parse_repositories_from_file(&build_file).unwrap();
+    let declared: Vec<(RepositoryKind, Option<&str>, usize, RepositoryScope)> = repositories
+        .iter()
+        .map(|location| (location.repository.kind, location.repository.url.as_deref(), location.line_number, location.scope))
+        .collect();
+    assert_eq!(declared, vec![
+        (RepositoryKind::Google, None, 2, RepositoryScope::Buildscript),
+        (RepositoryKind::MavenCentral, None, 2, RepositoryScope::Buildscript),
+        (RepositoryKind::Maven, Some("https://repo.example.com/releases"), 10, RepositoryScope::Project),
+        (RepositoryKind::Maven, Some("https://jitpack.io"), 14, RepositoryScope::Project),
+        (RepositoryKind::Ivy, Some("https://ivy.example.com"), 15, RepositoryScope::Project),
+    ]);
+
+    // Tracking repositories does not disturb dependency parsing
+    let dependencies = parse_dependencies_from_file(&build_file, &HashMap::new()).unwrap();
+    assert_eq!(dependencies.len(), 1);
+    assert_eq!(dependencies[0].line_number, 19);
+
+    fs::write(temp_dir.path().join("settings.gradle.kts"), r#"pluginManagement {
+    repositories {
+        gradlePluginPortal()
+    }
+}
+
+dependencyResolutionManagement {
+    repositoriesMode.set(RepositoriesMode.FAIL_ON_PROJECT_REPOS)
+    repositories {
+        google()
+    }
+}
+"#).unwrap();
+
+    let settings_repositories = parse_repositories_from_file(&temp_dir.path().join("settings.gradle.kts")).unwrap();
+    assert_eq!(settings_repositories.len(), 2);
+    assert_eq!(settings_repositories[0].repository.kind, RepositoryKind::GradlePluginPortal);
+    assert_eq!(settings_repositories[0].scope, RepositoryScope::PluginManagement);
+    assert_eq!(settings_repositories[1].repository.kind, RepositoryKind::Google);
+    assert_eq!(settings_repositories[1].scope, RepositoryScope::DependencyResolutionManagement);
+}
+
+#[test]
+fn test_repository_findings() {
+    let temp_dir = tempdir().unwrap();
+
+    fs::write(temp_dir.path().join("settings.gradle"), r#"dependencyResolutionManagement {
+    repositoriesMode.set(RepositoriesMode.FAIL_ON_PROJECT_REPOS)
+    repositories {
+        mavenCentral()
+        jcenter()
+    }
+}
+include ':app'
+"#).unwrap();
+
+    // Buildscript repositories are not project repositories, so the settings mode allows them
+    fs::write(temp_dir.path().join("build.gradle"), r#"buildscript {
+    repositories {
+        mavenCentral()
+        maven { url 'http://repo.example.com/maven/' }
+        maven { url 'http://repo.example.com/maven' }
+    }
+}
+"#).unwrap();
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"repositories {
+    mavenLocal()
+}
+"#);
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let findings = &analysis.repository_analysis.findings;
+    assert_eq!(analysis.repository_analysis.repositories.len(), 6);
+
+    let settings_findings = &findings[&temp_dir.path().join("settings.gradle")];
+    assert_eq!(settings_findings.len(), 1);
+    assert_eq!(settings_findings[0].kind, RepositoryFindingKind::DeprecatedJcenter);
+    assert_eq!(settings_findings[0].line_number, 5);
+
+    let root_findings = &findings[&temp_dir.path().join("build.gradle")];
+    let kinds: Vec<(&RepositoryFindingKind, usize)> = root_findings.iter().map(|finding| (&finding.kind, finding.line_number)).collect();
+    assert_eq!(kinds, vec![
+        (&RepositoryFindingKind::InsecureUrl, 4),
+        (&RepositoryFindingKind::InsecureUrl, 5),
+        (&RepositoryFindingKind::DuplicateRepository, 5),
+    ]);
+    assert_eq!(root_findings[2].subject, "maven(http://repo.example.com/maven)");
+    assert!(root_findings[2].message.contains("already declared at line 4"));
+
+    let app_findings = &findings[&temp_dir.path().join("app/build.gradle")];
+    assert_eq!(app_findings.len(), 2);
+    assert_eq!(app_findings[0].kind, RepositoryFindingKind::MavenLocal);
+    assert_eq!(app_findings[1].kind, RepositoryFindingKind::ProjectRepositoryRejected);
+    assert!(app_findings[1].message.contains("FAIL_ON_PROJECT_REPOS"));
+}
+#[test]
+fn test_repository_provided_by_settings() {
+    let temp_dir = tempdir().unwrap();
+
+    fs::write(temp_dir.path().join("settings.gradle"), r#"dependencyResolutionManagement {
+    repositories {
+        google()
+        mavenCentral()
+        maven { url 'https://jitpack.io/' }
+    }
+}
+include ':app'
+"#).unwrap();
+
+    create_test_build_gradle(temp_dir.path(), "app", r#"buildscript {
+    repositories { mavenCentral() }
+}
+
+repositories {
+    mavenCentral()
+    maven { url 'https://jitpack.io' }
+    maven { url 'https://repo.example.com/releases' }
+}
+"#);
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+    let findings = &analysis.repository_analysis.findings;
+    assert!(!findings.contains_key(&temp_dir.path().join("settings.gradle")));
+
+    let app_findings = &findings[&temp_dir.path().join("app/build.gradle")];
+    let provided: Vec<(&str, usize)> = app_findings
+        .iter()
+        .filter(|finding| finding.kind == RepositoryFindingKind::ProvidedBySettings)
+        .map(|finding| (finding.subject.as_str(), finding.line_number))
+        .collect();
+    assert_eq!(provided, vec![("mavenCentral()", 6), ("maven(https://jitpack.io)", 7)]);
+    assert!(app_findings[0].message.contains("settings.gradle:4"));
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+mod common;
+
+use gradle_dependency_health_checker::analyzer::{perform_complete_analysis, perform_complete_analysis_with_config, AnalysisParameters};
+use gradle_dependency_health_checker::config::Config;
+use common::create_test_build_gradle;
+use tempfile::tempdir;
+
+fn create_module(root: &std::path::Path, module: &str, artifacts: &[&str]) {
+    let declarations: Vec<String> = artifacts
+        .iter()
+        .map(|artifact| format!("    implementation 'com.example:{}:1.0.0'", artifact))
+        .collect();
+    create_test_build_gradle(root, module, &format!("dependencies {{\n{}\n}}\n", declarations.join("\n")));
+}
+
+#[test]
+fn test_cluster_similar_modules() {
+    let temp_dir = tempdir().unwrap();
+    create_module(temp_dir.path(), "feature-home", &["a", "b", "c", "d", "e"]);
+    create_module(temp_dir.path(), "feature-search", &["a", "b", "c", "d", "e", "f"]);
+    create_module(temp_dir.path(), "feature-settings", &["a", "b", "c", "d", "e", "g"]);
+    create_module(temp_dir.path(), "core", &["a", "x", "y"]);
+
+    let analysis = perform_complete_analysis_with_config(temp_dir.path(), &AnalysisParameters { min_module_similarity: Some(0.8), ..AnalysisParameters::default() }, &Config::default()).unwrap();
+    let similarity = analysis.similarity_analysis;
+
+    // The matrix is symmetric with every module fully similar to itself
+    assert_eq!(similarity.modules.len(), 4);
+    for i in 0..4 {
+        assert_eq!(similarity.matrix[i][i], 1.0);
+        for j in 0..4 {
+            assert_eq!(similarity.matrix[i][j], similarity.matrix[j][i]);
+        }
+    }
+
+    // search and settings are only 5/7 alike, so complete linkage keeps them apart at 0.8
+    assert_eq!(similarity.clusters.len(), 1);
+    let cluster = &similarity.clusters[0];
+    assert_eq!(cluster.modules.len(), 2);
+    assert!(cluster.modules[0].ends_with("feature-home/build.gradle"));
+    assert!(cluster.modules[1].ends_with("feature-search/build.gradle"));
+    assert!((cluster.similarity - 5.0 / 6.0).abs() < 1e-9);
+    assert_eq!(cluster.core_dependencies, vec!["com.example:a", "com.example:b", "com.example:c", "com.example:d", "com.example:e"]);
+
+    // A lower threshold groups all three feature modules around their shared core
+    let analysis = perform_complete_analysis_with_config(temp_dir.path(), &AnalysisParameters { min_module_similarity: Some(0.7), ..AnalysisParameters::default() }, &Config::default()).unwrap();
+    let cluster = &analysis.similarity_analysis.clusters[0];
+    assert_eq!(cluster.modules.len(), 3);
+    assert_eq!(cluster.core_dependencies.len(), 5);
+    assert!(analysis.similarity_analysis.clusters.iter().all(|c| !c.modules.iter().any(|m| m.ends_with("core/build.gradle"))));
+}
+
+#[test]
+fn test_similarity_skipped_unless_requested() {
+    let temp_dir = tempdir().unwrap();
+    create_module(temp_dir.path(), "app", &["a", "b"]);
+    create_module(temp_dir.path(), "lib", &["a", "b"]);
+
+    let analysis = perform_complete_analysis(temp_dir.path(), 2, 2).unwrap();
+
+    assert!(analysis.similarity_analysis.modules.is_empty());
+    assert!(analysis.similarity_analysis.clusters.is_empty());
+}
\ No newline at end of file
@@ -8,8 +8,10 @@
 mod common;
 
 use gradle_dependency_health_checker::version_catalog::{parse_version_catalog, find_version_catalog_files};
-use common::create_test_version_catalog;
+use gradle_dependency_health_checker::parser::{load_version_catalogs, parse_dependencies_from_file, parse_plugins_from_file, DependencySourceType, PluginSourceType};
+use common::{create_test_build_gradle, create_test_version_catalog};
 use tempfile::tempdir;
+use std::fs;
 
 #[test]
 fn test_find_version_catalog_files() {
@@ -121,4 +123,201 @@ application = { id = "application" }
     let (id, version) = catalog.resolve_plugin_version("application").unwrap();
     assert_eq!(id, "application");
     assert_eq!(version, None);
+}
+
+#[test]
+fn test_custom_catalog_accessors() {
+    let temp_dir = tempdir().unwrap();
+    
+    // Both catalogs define `core`; each accessor must resolve against its own file
+    create_test_version_catalog(temp_dir.path(), r#"
+[libraries]
+core = { module = "com.example:core", version = "1.0.0" }
+"#);
+    fs::write(temp_dir.path().join("gradle/androidx.toml"), r#"
+[libraries]
+core = { module = "androidx.core:core-ktx", version = "1.12.0" }
+activity_compose = { module = "androidx.activity:activity-compose", version = "1.8.0" }
+
+[plugins]
+baseline-profile = { id = "androidx.baselineprofile", version = "1.2.0" }
+"#).unwrap();
+    fs::write(temp_dir.path().join("settings.gradle.kts"), r#"
+dependencyResolutionManagement {
+    versionCatalogs {
+        create("androidx") {
+            from(files("gradle/androidx.toml"))
+        }
+    }
+}
+"#).unwrap();
+    
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+plugins {
+    alias(androidx.plugins.baseline.profile)
+}
+
+dependencies {
+    implementation libs.core
+    implementation androidx.core
+    implementation(androidx.activity.compose)
+}
+"#);
+    
+    let version_catalogs = load_version_catalogs(temp_dir.path()).unwrap();
+    let mut accessors: Vec<_> = version_catalogs.values().flat_map(|catalog| catalog.accessors.iter().map(String::as_str)).collect();
+    accessors.sort();
+    assert_eq!(accessors, vec!["androidx", "libs"]);
+    
+    let gradle_file = temp_dir.path().join("app/build.gradle");
+    let dependencies = parse_dependencies_from_file(&gradle_file, &version_catalogs).unwrap();
+    assert_eq!(dependencies.len(), 3);
+    
+    assert_eq!(dependencies[0].dependency.group, "com.example");
+    assert!(matches!(&dependencies[0].source_type, DependencySourceType::VersionCatalog(r) if r == "libs.core"));
+    assert_eq!(dependencies[1].dependency.group, "androidx.core");
+    assert!(matches!(&dependencies[1].source_type, DependencySourceType::VersionCatalog(r) if r == "androidx.core"));
+    
+    // Underscores and dots are interchangeable alias separators
+    assert_eq!(dependencies[2].dependency.artifact, "activity-compose");
+    
+    let plugins = parse_plugins_from_file(&gradle_file, &version_catalogs).unwrap();
+    assert_eq!(plugins.len(), 1);
+    assert_eq!(plugins[0].plugin.id, "androidx.baselineprofile");
+    assert!(matches!(&plugins[0].source_type, PluginSourceType::VersionCatalog(r) if r == "androidx.plugins.baseline.profile"));
+}
+
+#[test]
+fn test_unknown_catalog_accessor_is_not_resolved() {
+    let temp_dir = tempdir().unwrap();
+    
+    create_test_version_catalog(temp_dir.path(), r#"
+[libraries]
+okhttp = { module = "com.squareup.okhttp3:okhttp", version = "4.12.0" }
+"#);
+    
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+dependencies {
+    implementation other.okhttp
+}
+"#);
+    
+    let version_catalogs = load_version_catalogs(temp_dir.path()).unwrap();
+    let gradle_file = temp_dir.path().join("app/build.gradle");
+    let dependencies = parse_dependencies_from_file(&gradle_file, &version_catalogs).unwrap();
+    
+    assert!(dependencies.is_empty());
+}
+
+#[test]
+fn test_catalog_accessor_resolves_per_build() {
+    let temp_dir = tempdir().unwrap();
+    let build_src = temp_dir.path().join("buildSrc");
+    
+    create_test_version_catalog(temp_dir.path(), r#"
+[libraries]
+okhttp = { module = "com.squareup.okhttp3:okhttp", version = "4.12.0" }
+"#);
+    // buildSrc is a build of its own; its catalog must not shadow the root one
+    create_test_version_catalog(&build_src, r#"
+[libraries]
+okhttp = { module = "com.squareup.okhttp3:okhttp", version = "4.11.0" }
+"#);
+    
+    let dependencies_block = r#"
+dependencies {
+    implementation libs.okhttp
+}
+"#;
+    create_test_build_gradle(temp_dir.path(), "app", dependencies_block);
+    create_test_build_gradle(&build_src, "plugins", dependencies_block);
+    
+    let version_catalogs = load_version_catalogs(temp_dir.path()).unwrap();
+    
+    let app_dependencies = parse_dependencies_from_file(&temp_dir.path().join("app/build.gradle"), &version_catalogs).unwrap();
+    assert_eq!(app_dependencies[0].dependency.version.as_deref(), Some("4.12.0"));
+    
+    let build_src_dependencies = parse_dependencies_from_file(&build_src.join("plugins/build.gradle"), &version_catalogs).unwrap();
+    assert_eq!(build_src_dependencies[0].dependency.version.as_deref(), Some("4.11.0"));
+}
+
+#[test]
+fn test_catalog_bundle_expansion() {
+    let temp_dir = tempdir().unwrap();
+    
+    create_test_version_catalog(temp_dir.path(), r#"
+[versions]
+compose = "1.5.4"
+
+[libraries]
+compose-ui = { module = "androidx.compose.ui:ui", version.ref = "compose" }
+compose-material = { module = "androidx.compose.material:material", version.ref = "compose" }
+
+[bundles]
+compose = ["compose-ui", "compose-material"]
+"#);
+    
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+dependencies {
+    implementation(libs.bundles.compose)
+    implementation 'androidx.compose.ui:ui:1.4.0'
+}
+"#);
+    
+    let version_catalogs = load_version_catalogs(temp_dir.path()).unwrap();
+    let catalog = version_catalogs.values().next().unwrap();
+    assert_eq!(catalog.resolve_bundle("compose").unwrap().len(), 2);
+    
+    let gradle_file = temp_dir.path().join("app/build.gradle");
+    let dependencies = parse_dependencies_from_file(&gradle_file, &version_catalogs).unwrap();
+    assert_eq!(dependencies.len(), 3);
+    
+    // Bundle members are attributed to the declaring line
+    let bundle_members: Vec<_> = dependencies
+        .iter()
+        .filter(|d| matches!(&d.source_type, DependencySourceType::CatalogBundle(r) if r == "libs.bundles.compose"))
+        .collect();
+    assert_eq!(bundle_members.len(), 2);
+    assert!(bundle_members.iter().all(|d| d.line_number == 3 && d.configuration == "implementation"));
+    assert!(bundle_members.iter().any(|d| d.dependency.artifact == "material"));
+    
+    let ui_versions: Vec<_> = dependencies
+        .iter()
+        .filter(|d| d.dependency.artifact == "ui")
+        .map(|d| d.dependency.version.clone().unwrap())
+        .collect();
+    assert_eq!(ui_versions, vec!["1.5.4", "1.4.0"]);
+}
+
+#[test]
+fn test_catalog_bundle_keeps_unversioned_members() {
+    let temp_dir = tempdir().unwrap();
+    
+    create_test_version_catalog(temp_dir.path(), r#"
+[versions]
+compose-bom = "2024.02.00"
+
+[libraries]
+compose-bom = { module = "androidx.compose:compose-bom", version.ref = "compose-bom" }
+compose-ui = { module = "androidx.compose.ui:ui" }
+
+[bundles]
+compose = ["compose-bom", "compose-ui", "missing-lib"]
+"#);
+    
+    create_test_build_gradle(temp_dir.path(), "app", r#"
+dependencies {
+    implementation(libs.bundles.compose)
+}
+"#);
+    
+    let version_catalogs = load_version_catalogs(temp_dir.path()).unwrap();
+    let gradle_file = temp_dir.path().join("app/build.gradle");
+    let dependencies = parse_dependencies_from_file(&gradle_file, &version_catalogs).unwrap();
+    
+    // Members versioned by a BOM are kept; aliases missing from [libraries] are left to the catalog lint
+    assert_eq!(dependencies.len(), 2);
+    let ui = dependencies.iter().find(|d| d.dependency.artifact == "ui").unwrap();
+    assert_eq!(ui.dependency.version, None);
+    assert!(matches!(&ui.source_type, DependencySourceType::CatalogBundle(r) if r == "libs.bundles.compose"));
 }
\ No newline at end of file
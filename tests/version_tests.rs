@@ -0,0 +1,70 @@
+/*
+ * Copyright (c) 2025 ParkJong-Hun
+ *
+ * Licensed under the MIT License.
+ * See LICENSE file in the project root for full license information.
+ */
+
+use gradle_dependency_health_checker::version::{compare_versions, first_differing_part, highest_version, lowest_version, majority_version, most_common_version, DynamicVersionKind};
+use std::cmp::Ordering;
+
+#[test]
+fn test_compare_versions() {
+    assert_eq!(compare_versions("1.10", "1.9"), Ordering::Greater);
+    assert_eq!(compare_versions("1.0", "1.0.1"), Ordering::Less);
+    assert_eq!(compare_versions("1.0-rc1", "1.0"), Ordering::Less);
+    assert_eq!(compare_versions("1.0-alpha", "1.0-beta"), Ordering::Less);
+    assert_eq!(compare_versions("1.0-beta", "1.0-rc1"), Ordering::Less);
+    assert_eq!(compare_versions("1.0-dev", "1.0-alpha"), Ordering::Less);
+    assert_eq!(compare_versions("1.0.RELEASE", "1.0.Final"), Ordering::Greater);
+    assert_eq!(compare_versions("2.3.05", "2.3.5"), Ordering::Equal);
+    assert_eq!(compare_versions("20240101", "9"), Ordering::Greater);
+}
+
+#[test]
+fn test_highest_and_lowest_version() {
+    let versions = ["4.9.0", "4.12.0", "4.12.0-alpha01", "4.10.0"];
+
+    assert_eq!(highest_version(versions), Some("4.12.0"));
+    assert_eq!(lowest_version(versions), Some("4.9.0"));
+    assert_eq!(highest_version(Vec::<&str>::new()), None);
+}
+
+#[test]
+fn test_first_differing_part() {
+    assert_eq!(first_differing_part("8.1.0", "7.4.2"), Some(0));
+    assert_eq!(first_differing_part("1.9.0", "1.8.22"), Some(1));
+    assert_eq!(first_differing_part("1.9.0", "1.9.10"), Some(2));
+    assert_eq!(first_differing_part("1.0", "1.0.1"), Some(2));
+    assert_eq!(first_differing_part("2.3.05", "2.3.5"), None);
+}
+
+#[test]
+fn test_most_common_version() {
+    assert_eq!(most_common_version(["17", "11", "17"]), Some(("17", 2)));
+    // Ties go to the highest version
+    assert_eq!(most_common_version(["33", "34"]), Some(("34", 1)));
+    assert_eq!(most_common_version(Vec::<&str>::new()), None);
+}
+
+#[test]
+fn test_majority_version() {
+    assert_eq!(majority_version(["17", "11", "17"]), Some(("17", 2)));
+    // A version declared once or tied with another is no majority
+    assert_eq!(majority_version(["21", "26", "28"]), None);
+    assert_eq!(majority_version(["33", "33", "34", "34"]), None);
+    assert_eq!(majority_version(["34"]), None);
+}
+
+#[test]
+fn test_dynamic_version_kind() {
+    assert_eq!(DynamicVersionKind::of("+"), Some(DynamicVersionKind::Plus));
+    assert_eq!(DynamicVersionKind::of("1.+"), Some(DynamicVersionKind::Plus));
+    assert_eq!(DynamicVersionKind::of("latest.release"), Some(DynamicVersionKind::LatestRelease));
+    assert_eq!(DynamicVersionKind::of("latest.integration"), Some(DynamicVersionKind::LatestIntegration));
+    assert_eq!(DynamicVersionKind::of("[1.0,2.0)"), Some(DynamicVersionKind::Range));
+    assert_eq!(DynamicVersionKind::of("]1.0,)"), Some(DynamicVersionKind::Range));
+    assert_eq!(DynamicVersionKind::of("1.0-SNAPSHOT"), Some(DynamicVersionKind::Snapshot));
+    assert_eq!(DynamicVersionKind::of("1.0.0"), None);
+    assert_eq!(DynamicVersionKind::of("1.0-rc1"), None);
+}
\ No newline at end of file